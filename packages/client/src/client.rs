@@ -1,11 +1,18 @@
+use crate::clock::{ClockDrift, ClockSync, ClockSyncConfig};
+use crate::negotiation::{NegotiationError, RevisionCache, RevisionNegotiator};
 use crate::network::Network;
+use crate::state::ControllerState;
 use bytes::{BufMut, BytesMut};
+use chrono::{DateTime, NaiveDateTime, Utc};
 use flume::{bounded, Receiver, Sender};
+use open_protocol::messages::audi::AudiMessage;
 use open_protocol::messages::communication::MID0001rev7;
 use open_protocol::messages::keep_alive::MID9999rev1;
+use open_protocol::types::timestamp::{ControllerTimezone, TimestampError};
 use open_protocol::{Header, Message};
 use open_protocol::{decode, encode::{self, Encode, Encoder}};
 use std::collections::VecDeque;
+use std::future;
 use std::io;
 use std::net::SocketAddr;
 use std::pin::Pin;
@@ -13,6 +20,7 @@ use std::str::FromStr;
 use std::time::Duration;
 use thiserror;
 use tokio::net::TcpStream;
+use tokio::sync::watch;
 use tokio::time::{Instant, Sleep};
 use tokio::{select, time};
 
@@ -31,7 +39,14 @@ pub enum ConnectionError {
 #[derive(Debug)]
 pub enum Event {
     Incoming(Message),
+    /// MID 0500-0503 from an Audi controller, when enabled with [`EventLoop::with_audi_messages`].
+    IncomingAudi(AudiMessage),
     Outgoing(Message),
+    /// The controller rejected the revision of a message, and it could not be resent at a lower
+    /// revision. Only sent when revision negotiation is enabled.
+    NegotiationFailed(NegotiationError),
+    /// The drift of the controller clock, measured when clock synchronisation is enabled.
+    ClockDrift(ClockDrift),
 }
 
 pub struct EventLoop {
@@ -42,6 +57,10 @@ pub struct EventLoop {
     events: VecDeque<Event>,
     write_buf: BytesMut,
     keepalive_timeout: Option<Pin<Box<Sleep>>>,
+    state: watch::Sender<ControllerState>,
+    negotiator: Option<RevisionNegotiator>,
+    timezone: ControllerTimezone,
+    clock_sync: Option<ClockSync>,
 }
 
 impl EventLoop {
@@ -58,9 +77,58 @@ impl EventLoop {
             write_buf: BytesMut::with_capacity(10 * 1024),
             network: Some(Network::new(socket)),
             keepalive_timeout: None,
+            state: watch::Sender::new(ControllerState::default()),
+            negotiator: None,
+            timezone: ControllerTimezone::default(),
+            clock_sync: None,
         }
     }
 
+    /// Enables revision negotiation: outgoing messages are converted to the highest revision the
+    /// controller accepts, see [`RevisionNegotiator`]. Without it, messages are sent with the
+    /// revision they were built with. The cache can be shared with other event loops, so
+    /// reconnecting to a controller does not negotiate again.
+    pub fn with_revision_cache(mut self, cache: RevisionCache) -> io::Result<Self> {
+        let address = self.network.as_ref().unwrap().socket.peer_addr()?;
+        self.negotiator = Some(RevisionNegotiator::new(cache, address));
+        Ok(self)
+    }
+
+    /// Sets the timezone of the controller clock, UTC by default.
+    pub fn with_timezone(mut self, timezone: ControllerTimezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Decodes incoming MID 0500-0503 as the Audi emergency status instead of motor tuning, for
+    /// controllers configured for Audi. The Audi subscription, acknowledge and unsubscribe have
+    /// no data, so they are sent as the motor tuning messages with the same MID.
+    pub fn with_audi_messages(mut self) -> Self {
+        if let Some(network) = self.network.as_mut() {
+            network.audi_messages = true;
+        }
+        self
+    }
+
+    /// Reads the controller clock when the connection starts and on the configured schedule,
+    /// reports its drift as [`Event::ClockDrift`], and sets it with MID 0082 when the drift
+    /// reaches the threshold. Uses the timezone set with [`EventLoop::with_timezone`].
+    pub fn with_clock_sync(mut self, config: ClockSyncConfig) -> Self {
+        self.clock_sync = Some(ClockSync::new(config));
+        self
+    }
+
+    /// The timezone of the controller clock, to convert the timestamps of its messages to UTC.
+    pub fn timezone(&self) -> &ControllerTimezone {
+        &self.timezone
+    }
+
+    /// Converts a timestamp received from the controller to UTC, with the timezone set with
+    /// [`EventLoop::with_timezone`].
+    pub fn to_utc(&self, timestamp: NaiveDateTime) -> Result<DateTime<Utc>, TimestampError> {
+        self.timezone.to_utc(timestamp)
+    }
+
     async fn select(&mut self) -> Result<Event, ConnectionError> {
         // let network = self.network.as_mut().unwrap();
         // let await_acks = self.state.await_acks;
@@ -74,6 +142,9 @@ impl EventLoop {
             return Ok(event);
         }
 
+        let next_clock_check = self.clock_sync.as_ref().and_then(ClockSync::next_check);
+        let clock_correction_due = self.clock_sync.as_ref().is_some_and(ClockSync::correction_due);
+
         select! {
             o = next_request(
                 &mut self.pending,
@@ -103,10 +174,30 @@ impl EventLoop {
                 self.network.as_mut().unwrap().flush(&mut self.write_buf).await?;
                 Ok(self.events.pop_front().unwrap())
             }
+
+            _ = time::sleep_until(next_clock_check.unwrap_or_else(Instant::now)), if next_clock_check.is_some() => {
+                let check = self.clock_sync.as_mut().unwrap().check(Instant::now());
+
+                self.handle_outgoing_packet(check)?;
+                self.network.as_mut().unwrap().flush(&mut self.write_buf).await?;
+                Ok(self.events.pop_front().unwrap())
+            }
+
+            _ = future::ready(()), if clock_correction_due => {
+                let correction = self.clock_sync.as_mut().unwrap().correction(Utc::now(), &self.timezone).unwrap();
+
+                self.handle_outgoing_packet(correction)?;
+                self.network.as_mut().unwrap().flush(&mut self.write_buf).await?;
+                Ok(self.events.pop_front().unwrap())
+            }
         }
     }
 
     fn handle_outgoing_packet(&mut self, request: Message) -> Result<(), ConnectionError> {
+        let request = match self.negotiator.as_mut() {
+            Some(negotiator) => negotiator.prepare(request),
+            None => request,
+        };
         let mut payload_encoder = Encoder::new();
         request.encode_payload(&mut payload_encoder)?;
 
@@ -128,12 +219,49 @@ impl EventLoop {
         Ok(())
     }
 
+    /// The current state of the controller.
+    pub fn state(&self) -> ControllerState {
+        self.state.borrow().clone()
+    }
+
+    /// A receiver that is notified each time the state of the controller changes, for use
+    /// outside the task that polls the event loop.
+    pub fn subscribe_state(&self) -> watch::Receiver<ControllerState> {
+        self.state.subscribe()
+    }
+
+    fn handle_clock_sync(&mut self, message: &Message) {
+        let Some(clock_sync) = self.clock_sync.as_mut() else {
+            return;
+        };
+
+        if message.mid_revision().0 == 2 {
+            self.pending.push_back(clock_sync.check(Instant::now()));
+        }
+
+        if let Some(drift) = clock_sync.handle_incoming(message, Utc::now(), &self.timezone) {
+            self.events.push_back(Event::ClockDrift(drift));
+        }
+    }
+
     pub async fn poll(&mut self) -> Result<Event, ConnectionError> {
         if self.keepalive_timeout.is_none() {
             self.keepalive_timeout = Some(Box::pin(time::sleep(Duration::from_secs(5))));
         }
 
         match self.select().await {
+            Ok(Event::Incoming(message)) => {
+                self.state.send_if_modified(|state| state.update(&message));
+                if let Some(negotiator) = self.negotiator.as_mut() {
+                    match negotiator.handle_incoming(&message) {
+                        Ok(Some(retry)) => self.pending.push_back(retry),
+                        Ok(None) => {}
+                        Err(error) => self.events.push_back(Event::NegotiationFailed(error)),
+                    }
+                }
+                self.handle_clock_sync(&message);
+                Ok(Event::Incoming(message))
+            }
             Ok(v) => Ok(v),
             Err(e) => {
                 // self.clean();
@@ -0,0 +1,172 @@
+use std::time::Duration;
+use chrono::{DateTime, TimeDelta, Utc};
+use open_protocol::messages::time::{MID0080rev1, MID0082rev1};
+use open_protocol::types::timestamp::ControllerTimezone;
+use open_protocol::Message;
+use tokio::time::Instant;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ClockSyncConfig {
+    /// The drift at or above which the controller clock is set to the host clock. A threshold of
+    /// zero sets the clock on every check.
+    pub threshold: TimeDelta,
+    /// The time between checks after the first one on connect, or `None` to only check on connect.
+    pub interval: Option<Duration>,
+}
+
+impl Default for ClockSyncConfig {
+    fn default() -> Self {
+        Self { threshold: TimeDelta::seconds(2), interval: None }
+    }
+}
+
+/// The drift of the controller clock, measured with MID 0081.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ClockDrift {
+    pub controller_time: DateTime<Utc>,
+    pub host_time: DateTime<Utc>,
+    /// How far the controller clock is ahead of the host clock. Negative when it is behind.
+    /// MID 0081 has a resolution of one second, so this does too.
+    pub drift: TimeDelta,
+    /// Whether the drift is at or above the threshold, so the controller clock is set.
+    pub exceeds_threshold: bool,
+}
+
+/// Reads the controller clock with MID 0080 when the connection starts and on a schedule, and
+/// measures its drift against the host clock.
+#[derive(Debug)]
+pub struct ClockSync {
+    config: ClockSyncConfig,
+    next_check: Option<Instant>,
+    correction_due: bool,
+}
+
+impl ClockSync {
+    pub fn new(config: ClockSyncConfig) -> Self {
+        Self { config, next_check: None, correction_due: false }
+    }
+
+    /// When the next scheduled check is due, if any.
+    pub fn next_check(&self) -> Option<Instant> {
+        self.next_check
+    }
+
+    /// The MID 0080 that reads the controller clock. Schedules the next check.
+    pub fn check(&mut self, now: Instant) -> Message {
+        self.next_check = self.config.interval.map(|interval| now + interval);
+        Message::MID0080rev1(MID0080rev1 {})
+    }
+
+    /// Whether the last measured drift reached the threshold, and the controller clock has not
+    /// been set since.
+    pub fn correction_due(&self) -> bool {
+        self.correction_due
+    }
+
+    /// The MID 0082 that sets the controller clock to `now`, if a correction is due. The time is
+    /// taken when the message is sent, as it may wait behind other messages until then.
+    pub fn correction(&mut self, now: DateTime<Utc>, timezone: &ControllerTimezone) -> Option<Message> {
+        std::mem::take(&mut self.correction_due).then(|| Message::MID0082rev1(MID0082rev1 {
+            time: timezone.from_utc(now),
+        }))
+    }
+
+    /// Measures the drift from a MID 0081, and makes a correction due when it reaches the
+    /// threshold. Returns `None` for other messages, and for times the timezone rejects as
+    /// ambiguous or nonexistent.
+    pub fn handle_incoming(
+        &mut self,
+        message: &Message,
+        host_time: DateTime<Utc>,
+        timezone: &ControllerTimezone,
+    ) -> Option<ClockDrift> {
+        let Message::MID0081rev1(message) = message else {
+            return None;
+        };
+
+        let controller_time = timezone.to_utc(message.time).ok()?;
+        // Truncate the host time to the resolution of the controller time.
+        let host_time = host_time - TimeDelta::nanoseconds(host_time.timestamp_subsec_nanos() as i64);
+        let drift = controller_time - host_time;
+        let exceeds_threshold = drift.abs() >= self.config.threshold;
+        self.correction_due = exceeds_threshold;
+
+        Some(ClockDrift { controller_time, host_time, drift, exceeds_threshold })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use open_protocol::messages::time::MID0081rev1;
+    use open_protocol::types::timestamp::Tz;
+
+    #[test]
+    fn measure_drift() {
+        let mut sync = ClockSync::new(ClockSyncConfig::default());
+        let timezone = ControllerTimezone::new(Tz::Europe__Amsterdam);
+        let controller_time = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap().and_hms_opt(12, 0, 5).unwrap();
+        let host_time = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap().and_hms_milli_opt(10, 0, 0, 400).unwrap().and_utc();
+        let message = Message::MID0081rev1(MID0081rev1 { time: controller_time });
+
+        let drift = sync.handle_incoming(&message, host_time, &timezone).unwrap();
+
+        assert_eq!(drift.drift, TimeDelta::seconds(5));
+        assert!(drift.exceeds_threshold);
+        assert!(sync.correction_due());
+
+        let drift = sync.handle_incoming(&message, host_time + TimeDelta::seconds(4), &timezone).unwrap();
+
+        assert_eq!(drift.drift, TimeDelta::seconds(1));
+        assert!(!drift.exceeds_threshold);
+        assert!(!sync.correction_due());
+    }
+
+    #[test]
+    fn threshold_is_inclusive() {
+        let mut sync = ClockSync::new(ClockSyncConfig::default());
+        let timezone = ControllerTimezone::default();
+        let controller_time = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap().and_hms_opt(10, 0, 2).unwrap();
+        let host_time = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap().and_hms_opt(10, 0, 0).unwrap().and_utc();
+        let message = Message::MID0081rev1(MID0081rev1 { time: controller_time });
+
+        let drift = sync.handle_incoming(&message, host_time, &timezone).unwrap();
+
+        assert_eq!(drift.drift, TimeDelta::seconds(2));
+        assert!(drift.exceeds_threshold);
+    }
+
+    #[test]
+    fn correction_takes_time_when_sent() {
+        let mut sync = ClockSync::new(ClockSyncConfig::default());
+        let timezone = ControllerTimezone::new(Tz::Europe__Amsterdam);
+        let controller_time = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap().and_hms_opt(12, 0, 5).unwrap();
+        let host_time = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap().and_hms_opt(10, 0, 0).unwrap().and_utc();
+        let message = Message::MID0081rev1(MID0081rev1 { time: controller_time });
+
+        assert_eq!(sync.correction(host_time, &timezone), None);
+        sync.handle_incoming(&message, host_time, &timezone).unwrap();
+
+        let sent_at = host_time + TimeDelta::seconds(3);
+        assert_eq!(
+            sync.correction(sent_at, &timezone),
+            Some(Message::MID0082rev1(MID0082rev1 { time: timezone.from_utc(sent_at) }))
+        );
+        assert_eq!(sync.correction(sent_at, &timezone), None);
+    }
+
+    #[test]
+    fn schedule_checks() {
+        let now = Instant::now();
+        let mut sync = ClockSync::new(ClockSyncConfig { interval: Some(Duration::from_secs(60)), ..Default::default() });
+
+        assert_eq!(sync.next_check(), None);
+        assert_eq!(sync.check(now), Message::MID0080rev1(MID0080rev1 {}));
+        assert_eq!(sync.next_check(), Some(now + Duration::from_secs(60)));
+
+        let mut sync = ClockSync::new(ClockSyncConfig::default());
+        sync.check(now);
+        assert_eq!(sync.next_check(), None);
+    }
+}
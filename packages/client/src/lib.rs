@@ -3,7 +3,10 @@ use thiserror;
 use open_protocol::{decode, encode};
 
 pub mod client;
+pub mod clock;
+pub mod negotiation;
 pub mod network;
+pub mod state;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
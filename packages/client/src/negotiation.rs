@@ -0,0 +1,529 @@
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use open_protocol::enums::error::ErrorCode;
+use open_protocol::message::EncodedField;
+use open_protocol::messages::communication::MID0008rev1;
+use open_protocol::Message;
+
+/// Revisions at or above this number are vendor specific, and are only sent when asked for.
+const VENDOR_REVISION: u16 = 998;
+
+#[derive(Debug, Clone, Eq, PartialEq, thiserror::Error)]
+pub enum NegotiationError {
+    #[error("MID {mid} revision {revision} is unsupported, and no lower revision holds the same data")]
+    RevisionUnsupported { mid: u16, revision: u16 },
+}
+
+/// Identifies a controller in the [`RevisionCache`]: by the serial number from MID 0002 when it
+/// reports one, by its address otherwise. The Open Protocol version is part of it, as a firmware
+/// update can change the supported revisions.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ControllerId {
+    pub controller: String,
+    pub open_protocol_version: String,
+}
+
+impl ControllerId {
+    pub fn new(address: SocketAddr, serial_number: &str, open_protocol_version: &str) -> Self {
+        let controller = match serial_number.trim() {
+            "" => address.to_string(),
+            serial_number => serial_number.to_string(),
+        };
+
+        Self { controller, open_protocol_version: open_protocol_version.trim().to_string() }
+    }
+
+    /// The numbers of the Open Protocol version, `None` when it has none.
+    fn version(&self) -> Option<Vec<u32>> {
+        let numbers = self.open_protocol_version
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|number| !number.is_empty())
+            .map(|number| number.parse().ok())
+            .collect::<Option<Vec<u32>>>()?;
+
+        (!numbers.is_empty()).then_some(numbers)
+    }
+}
+
+/// The revisions each controller accepted, kept across connections so a reconnect does not
+/// have to negotiate again.
+#[derive(Debug, Clone, Default)]
+pub struct RevisionCache {
+    controllers: Arc<Mutex<HashMap<ControllerId, HashMap<u16, u16>>>>,
+}
+
+impl RevisionCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The revision negotiated for `mid` with `controller`.
+    pub fn get(&self, controller: &ControllerId, mid: u16) -> Option<u16> {
+        self.lock().get(controller)?.get(&mid).copied()
+    }
+
+    fn revisions(&self, controller: &ControllerId) -> HashMap<u16, u16> {
+        self.lock().get(controller).cloned().unwrap_or_default()
+    }
+
+    /// The revisions of the same controller with the closest older Open Protocol version.
+    fn older_version_revisions(&self, controller: &ControllerId) -> HashMap<u16, u16> {
+        let Some(version) = controller.version() else {
+            return HashMap::new();
+        };
+
+        self.lock()
+            .iter()
+            .filter(|(cached, _)| cached.controller == controller.controller)
+            .filter_map(|(cached, revisions)| Some((cached.version()?, revisions)))
+            .filter(|(cached_version, _)| *cached_version < version)
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_, revisions)| revisions.clone())
+            .unwrap_or_default()
+    }
+
+    fn insert(&self, controller: &ControllerId, mid: u16, revision: u16) {
+        self.lock()
+            .entry(controller.clone())
+            .or_default()
+            .insert(mid, revision);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<ControllerId, HashMap<u16, u16>>> {
+        self.controllers.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// A message waiting for the controller to accept or reject its revision.
+#[derive(Debug)]
+struct Inflight {
+    /// The MID the revision is negotiated for, the MID of the data for subscriptions.
+    negotiated_mid: u16,
+    revision: u16,
+    reply_mid: Option<u16>,
+    fields: Vec<EncodedField>,
+}
+
+/// Picks the revision of each outgoing message, and retries with a lower revision when the
+/// controller answers with MID 0004 and a "revision unsupported" error.
+///
+/// Messages are converted between revisions field by field, see [`Message::convert`]. A
+/// conversion that would drop a field that is set is not done: the message is sent as it is,
+/// or rejected with an error. Subscriptions are negotiated for the MID of their data, as their
+/// revision is the revision of the data: MID 0060 for MID 0061, and the wanted revision of
+/// MID 0008 for its subscribed MID.
+///
+/// A controller whose Open Protocol version is not cached yet starts from the revisions of its
+/// closest older version in the cache, which a firmware update is expected to keep supporting.
+#[derive(Debug)]
+pub struct RevisionNegotiator {
+    cache: RevisionCache,
+    address: SocketAddr,
+    controller: Option<ControllerId>,
+    revisions: HashMap<u16, u16>,
+    inflight: HashMap<u16, VecDeque<Inflight>>,
+}
+
+impl RevisionNegotiator {
+    /// Negotiates with the controller at `address`, which identifies it in the cache when its
+    /// MID 0002 has no serial number.
+    pub fn new(cache: RevisionCache, address: SocketAddr) -> Self {
+        Self {
+            cache,
+            address,
+            controller: None,
+            revisions: HashMap::new(),
+            inflight: HashMap::new(),
+        }
+    }
+
+    /// The revision `mid` is sent or subscribed with: the negotiated one, or the highest this
+    /// library supports.
+    pub fn revision(&self, mid: u16) -> Option<u16> {
+        self.revisions.get(&mid).copied().or_else(|| {
+            Message::revisions(mid)
+                .into_iter()
+                .filter(|revision| *revision < VENDOR_REVISION)
+                .max()
+        })
+    }
+
+    /// Converts an outgoing message to the revision for its MID, and remembers it in case the
+    /// controller rejects that revision.
+    pub fn prepare(&mut self, message: Message) -> Message {
+        let message = match message {
+            Message::MID0008rev1(subscription) => Message::MID0008rev1(self.prepare_subscription(subscription)),
+            message => self.prepare_request(message),
+        };
+
+        if message.reply_mid().is_none() {
+            return message;
+        }
+
+        let (mid, _) = message.mid_revision();
+        let (negotiated_mid, revision) = negotiated_revision(&message);
+        if let Ok(fields) = message.encode_set_fields() {
+            self.inflight.entry(mid).or_default().push_back(Inflight {
+                negotiated_mid,
+                revision,
+                reply_mid: message.reply_mid(),
+                fields,
+            });
+        }
+
+        message
+    }
+
+    /// Handles an incoming message. Returns the rejected message at a lower revision when the
+    /// controller did not support the revision it was sent with, or an error when no lower
+    /// revision holds the same data.
+    pub fn handle_incoming(&mut self, message: &Message) -> Result<Option<Message>, NegotiationError> {
+        match message {
+            Message::MID0002rev1(_) | Message::MID0002rev2(_) => self.set_controller("", ""),
+            Message::MID0002rev3(m) => self.set_controller("", &m.open_protocol_version),
+            Message::MID0002rev4(m) => self.set_controller(&m.controller_serial_number, &m.open_protocol_version),
+            Message::MID0002rev5(m) => self.set_controller(&m.controller_serial_number, &m.open_protocol_version),
+            Message::MID0002rev6(m) => self.set_controller(&m.controller_serial_number, &m.open_protocol_version),
+            Message::MID0002rev7(m) => self.set_controller(&m.controller_serial_number, &m.open_protocol_version),
+            Message::MID0005rev1(m) => self.accepted(m.mid),
+            Message::MID0004rev1(m) => return self.rejected(m.mid, &m.error_code),
+            Message::MID0004rev2(m) => return self.rejected(m.mid, &m.error_code),
+            _ => {}
+        }
+
+        // Requests answered with data instead of MID 0005.
+        let (reply_mid, _) = message.mid_revision();
+        if reply_mid != 5 {
+            let answered: Vec<u16> = self.inflight.iter()
+                .filter(|(_, queue)| queue.front().is_some_and(|inflight| inflight.reply_mid == Some(reply_mid)))
+                .map(|(mid, _)| *mid)
+                .collect();
+            for mid in answered {
+                self.accepted(mid);
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn prepare_request(&self, message: Message) -> Message {
+        let (mid, revision) = message.mid_revision();
+        let negotiated_mid = message.subscribed_mid().unwrap_or(mid);
+
+        match self.revision(negotiated_mid) {
+            Some(target) if target != revision && revision < VENDOR_REVISION => {
+                // Upgrade as far as the fields allow, or downgrade to the highest allowed.
+                Message::revisions(mid)
+                    .into_iter()
+                    .rev()
+                    .filter(|candidate| *candidate <= target && (target < revision || *candidate > revision))
+                    .find_map(|candidate| message.convert(candidate).ok())
+                    .unwrap_or(message)
+            }
+            _ => message,
+        }
+    }
+
+    /// The wanted revision is asked for explicitly, so it is only lowered to the negotiated one.
+    fn prepare_subscription(&self, mut subscription: MID0008rev1) -> MID0008rev1 {
+        if let Some(negotiated) = self.revisions.get(&subscription.subscription_mid) {
+            subscription.wanted_revision = subscription.wanted_revision.min(*negotiated);
+        }
+
+        subscription
+    }
+
+    fn set_controller(&mut self, serial_number: &str, open_protocol_version: &str) {
+        let controller = ControllerId::new(self.address, serial_number, open_protocol_version);
+
+        let mut known = self.cache.revisions(&controller);
+        if known.is_empty() {
+            known = self.cache.older_version_revisions(&controller);
+        }
+
+        for (mid, revision) in &self.revisions {
+            self.cache.insert(&controller, *mid, *revision);
+        }
+        for (mid, revision) in known {
+            self.revisions.entry(mid).or_insert(revision);
+        }
+        self.controller = Some(controller);
+    }
+
+    fn accepted(&mut self, mid: u16) {
+        if let Some(inflight) = self.take_inflight(mid) {
+            self.negotiated(inflight.negotiated_mid, inflight.revision);
+        }
+    }
+
+    fn rejected(&mut self, mid: u16, error_code: &ErrorCode) -> Result<Option<Message>, NegotiationError> {
+        let Some(inflight) = self.take_inflight(mid) else {
+            return Ok(None);
+        };
+
+        if !is_revision_unsupported(error_code) {
+            return Ok(None);
+        }
+
+        let Inflight { negotiated_mid, revision, fields, .. } = inflight;
+        let unsupported = || NegotiationError::RevisionUnsupported { mid: negotiated_mid, revision };
+
+        let message = if mid == 8 {
+            let lower = Message::revisions(negotiated_mid)
+                .into_iter()
+                .filter(|lower| *lower < revision)
+                .max()
+                .ok_or_else(unsupported)?;
+
+            match Message::decode_fields(mid, 1, &fields) {
+                Ok(Message::MID0008rev1(subscription)) => {
+                    Message::MID0008rev1(MID0008rev1 { wanted_revision: lower, ..subscription })
+                }
+                _ => return Err(unsupported()),
+            }
+        } else {
+            Message::revisions(mid)
+                .into_iter()
+                .rev()
+                .filter(|lower| *lower < revision)
+                .find_map(|lower| Message::decode_fields(mid, lower, &fields).ok())
+                .ok_or_else(unsupported)?
+        };
+
+        let (_, lower) = negotiated_revision(&message);
+        self.negotiated(negotiated_mid, lower);
+        Ok(Some(message))
+    }
+
+    /// The oldest message of `mid` the controller has not answered yet, which it answers first.
+    fn take_inflight(&mut self, mid: u16) -> Option<Inflight> {
+        let queue = self.inflight.get_mut(&mid)?;
+        let inflight = queue.pop_front();
+
+        if queue.is_empty() {
+            self.inflight.remove(&mid);
+        }
+
+        inflight
+    }
+
+    fn negotiated(&mut self, mid: u16, revision: u16) {
+        self.revisions.insert(mid, revision);
+
+        if let Some(controller) = &self.controller {
+            self.cache.insert(controller, mid, revision);
+        }
+    }
+}
+
+/// The MID whose revision a message negotiates, with that revision.
+fn negotiated_revision(message: &Message) -> (u16, u16) {
+    match message {
+        Message::MID0008rev1(subscription) => (subscription.subscription_mid, subscription.wanted_revision),
+        message => {
+            let (mid, revision) = message.mid_revision();
+            (message.subscribed_mid().unwrap_or(mid), revision)
+        }
+    }
+}
+
+fn is_revision_unsupported(error_code: &ErrorCode) -> bool {
+    matches!(
+        error_code,
+        ErrorCode::MIDRevisionUnsupported
+            | ErrorCode::RequestedMIDRevisionUnsupported
+            | ErrorCode::SubscribedMIDRevisionUnsupported
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use open_protocol::messages::communication::{MID0002rev1, MID0002rev4, MID0004rev1, MID0005rev1};
+    use open_protocol::messages::tightening_result::{MID0060rev1, MID0060rev6, MID0060rev7, MID0064rev2, MID0064rev3};
+    use open_protocol::messages::tool::{MID0040rev1, MID0040rev5, MID0040rev6};
+
+    const ADDRESS: &str = "192.168.0.10:4545";
+
+    fn negotiator(cache: &RevisionCache) -> RevisionNegotiator {
+        RevisionNegotiator::new(cache.clone(), ADDRESS.parse().unwrap())
+    }
+
+    fn handshake(open_protocol_version: &str) -> Message {
+        Message::MID0002rev4(MID0002rev4 {
+            controller_serial_number: "A123".into(),
+            open_protocol_version: open_protocol_version.into(),
+            ..Default::default()
+        })
+    }
+
+    fn controller(open_protocol_version: &str) -> ControllerId {
+        ControllerId { controller: "A123".into(), open_protocol_version: open_protocol_version.into() }
+    }
+
+    fn unsupported(mid: u16) -> Message {
+        Message::MID0004rev1(MID0004rev1 { mid, error_code: ErrorCode::RequestedMIDRevisionUnsupported })
+    }
+
+    fn tightening_result_request(tightening_id: u32) -> Message {
+        Message::MID0064rev3(MID0064rev3 { tightening_id })
+    }
+
+    #[test]
+    fn picks_highest_convertible_revision() {
+        let mut negotiator = negotiator(&RevisionCache::new());
+
+        let message = negotiator.prepare(Message::MID0040rev1(MID0040rev1 {}));
+
+        assert_eq!(message, Message::MID0040rev6(MID0040rev6 { tool_number: 0 }));
+    }
+
+    #[test]
+    fn falls_back_and_caches_per_controller() {
+        let cache = RevisionCache::new();
+
+        let mut negotiator = negotiator(&cache);
+        negotiator.handle_incoming(&handshake("2.8.0")).unwrap();
+        negotiator.prepare(Message::MID0040rev1(MID0040rev1 {}));
+        let retry = negotiator.handle_incoming(&unsupported(40));
+
+        assert_eq!(retry, Ok(Some(Message::MID0040rev5(MID0040rev5 {}))));
+        assert_eq!(cache.get(&controller("2.8.0"), 40), Some(5));
+
+        let mut reconnected = RevisionNegotiator::new(cache.clone(), "192.168.0.11:4545".parse().unwrap());
+        assert_eq!(reconnected.revision(40), Some(6));
+        reconnected.handle_incoming(&handshake("2.8.0")).unwrap();
+        assert_eq!(reconnected.revision(40), Some(5));
+    }
+
+    #[test]
+    fn controllers_without_serial_number_are_cached_by_address() {
+        let cache = RevisionCache::new();
+        let mut negotiator = negotiator(&cache);
+
+        negotiator.handle_incoming(&Message::MID0002rev1(MID0002rev1 { controller_name: "Default".into(), ..Default::default() })).unwrap();
+        negotiator.prepare(Message::MID0040rev1(MID0040rev1 {}));
+        negotiator.handle_incoming(&Message::MID0041rev1(Default::default())).unwrap();
+
+        let controller = ControllerId { controller: ADDRESS.into(), open_protocol_version: "".into() };
+        assert_eq!(cache.get(&controller, 40), Some(6));
+    }
+
+    #[test]
+    fn resends_rejected_request_with_its_fields() {
+        let cache = RevisionCache::new();
+        cache.insert(&controller("2.8.0"), 64, 3);
+        let mut negotiator = negotiator(&cache);
+        negotiator.handle_incoming(&handshake("2.8.0")).unwrap();
+
+        let message = negotiator.prepare(tightening_result_request(1234));
+        assert_eq!(message, tightening_result_request(1234));
+
+        let retry = negotiator.handle_incoming(&unsupported(64));
+        assert_eq!(retry, Ok(Some(Message::MID0064rev2(MID0064rev2 { tightening_id: 1234 }))));
+        assert_eq!(cache.get(&controller("2.8.0"), 64), Some(2));
+    }
+
+    #[test]
+    fn queues_inflight_requests_per_mid() {
+        let cache = RevisionCache::new();
+        cache.insert(&controller("2.8.0"), 64, 3);
+        let mut negotiator = negotiator(&cache);
+        negotiator.handle_incoming(&handshake("2.8.0")).unwrap();
+
+        negotiator.prepare(tightening_result_request(1));
+        negotiator.prepare(tightening_result_request(2));
+
+        assert_eq!(
+            negotiator.handle_incoming(&unsupported(64)),
+            Ok(Some(Message::MID0064rev2(MID0064rev2 { tightening_id: 1 })))
+        );
+        assert_eq!(
+            negotiator.handle_incoming(&unsupported(64)),
+            Ok(Some(Message::MID0064rev2(MID0064rev2 { tightening_id: 2 })))
+        );
+        assert_eq!(negotiator.handle_incoming(&unsupported(64)), Ok(None));
+    }
+
+    #[test]
+    fn negotiates_subscription_for_the_data_mid() {
+        let cache = RevisionCache::new();
+        let mut negotiator = negotiator(&cache);
+        negotiator.handle_incoming(&handshake("2.8.0")).unwrap();
+
+        let message = negotiator.prepare(Message::MID0060rev1(MID0060rev1 {}));
+        assert_eq!(message, Message::MID0060rev7(MID0060rev7 {}));
+
+        let retry = negotiator.handle_incoming(&Message::MID0004rev1(MID0004rev1 {
+            mid: 60,
+            error_code: ErrorCode::SubscribedMIDRevisionUnsupported,
+        }));
+        assert_eq!(retry, Ok(Some(Message::MID0060rev6(MID0060rev6 {}))));
+        assert_eq!(negotiator.revision(61), Some(6));
+
+        negotiator.prepare(retry.unwrap().unwrap());
+        negotiator.handle_incoming(&Message::MID0005rev1(MID0005rev1 { mid: 60 })).unwrap();
+        assert_eq!(cache.get(&controller("2.8.0"), 61), Some(6));
+    }
+
+    #[test]
+    fn lowers_wanted_revision_of_generic_subscription() {
+        let mut negotiator = negotiator(&RevisionCache::new());
+
+        let message = negotiator.prepare(Message::MID0008rev1(MID0008rev1::new(1201, 3)));
+        assert_eq!(message, Message::MID0008rev1(MID0008rev1::new(1201, 3)));
+
+        let retry = negotiator.handle_incoming(&Message::MID0004rev1(MID0004rev1 {
+            mid: 8,
+            error_code: ErrorCode::SubscribedMIDRevisionUnsupported,
+        }));
+        assert_eq!(retry, Ok(Some(Message::MID0008rev1(MID0008rev1::new(1201, 2)))));
+
+        let message = negotiator.prepare(Message::MID0008rev1(MID0008rev1::new(1201, 3)));
+        assert_eq!(message, Message::MID0008rev1(MID0008rev1::new(1201, 2)));
+    }
+
+    #[test]
+    fn newer_open_protocol_version_starts_from_older_revisions() {
+        let cache = RevisionCache::new();
+        cache.insert(&controller("2.8.0"), 40, 4);
+        cache.insert(&controller("2.10.1"), 40, 5);
+
+        let mut updated = negotiator(&cache);
+        updated.handle_incoming(&handshake("2.12")).unwrap();
+        assert_eq!(updated.revision(40), Some(5));
+
+        let mut downgraded = negotiator(&cache);
+        downgraded.handle_incoming(&handshake("2.7.3")).unwrap();
+        assert_eq!(downgraded.revision(40), Some(6));
+    }
+
+    #[test]
+    fn refuses_lossy_downgrade() {
+        let mut negotiator = negotiator(&RevisionCache::new());
+
+        let message = negotiator.prepare(Message::MID0040rev6(MID0040rev6 { tool_number: 2 }));
+        assert_eq!(message.mid_revision(), (40, 6));
+
+        assert_eq!(
+            negotiator.handle_incoming(&unsupported(40)),
+            Err(NegotiationError::RevisionUnsupported { mid: 40, revision: 6 })
+        );
+        assert_eq!(negotiator.revision(40), Some(6));
+    }
+
+    #[test]
+    fn other_errors_are_not_retried() {
+        let mut negotiator = negotiator(&RevisionCache::new());
+
+        negotiator.prepare(Message::MID0040rev1(MID0040rev1 {}));
+        let retry = negotiator.handle_incoming(&Message::MID0004rev1(MID0004rev1 {
+            mid: 40,
+            error_code: ErrorCode::InvalidData,
+        }));
+
+        assert_eq!(retry, Ok(None));
+        assert_eq!(negotiator.revision(40), Some(6));
+    }
+}
@@ -3,6 +3,7 @@ use std::io;
 use bytes::{Buf, BytesMut};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
+use open_protocol::messages::audi::AudiMessage;
 use open_protocol::{Header, Message};
 use open_protocol::decode::{self, Decoder, Decode};
 use crate::client::{ConnectionError, Event};
@@ -10,6 +11,8 @@ use crate::client::{ConnectionError, Event};
 pub struct Network {
     pub socket: TcpStream,
     pub read_buf: BytesMut,
+    /// Whether MID 0500-0503 are decoded as the Audi emergency status instead of motor tuning.
+    pub audi_messages: bool,
 }
 
 impl Network {
@@ -17,6 +20,7 @@ impl Network {
         Self {
             socket,
             read_buf: BytesMut::with_capacity(10 * 1024),
+            audi_messages: false,
         }
     }
 
@@ -48,9 +52,9 @@ impl Network {
 
     pub async fn read(&mut self, events: &mut VecDeque<Event>) -> io::Result<()> {
         loop {
-            let required = match read_message(&mut self.read_buf) {
-                Ok(message) => {
-                    events.push_back(Event::Incoming(message));
+            let required = match read_message(&mut self.read_buf, self.audi_messages) {
+                Ok(event) => {
+                    events.push_back(event);
                     return Ok(());
                 },
                 Err(decode::Error::InsufficientBytes { have, need }) => need - have,
@@ -72,7 +76,7 @@ impl Network {
     }
 }
 
-fn read_message(stream: &mut BytesMut) -> decode::Result<Message> {
+fn read_message(stream: &mut BytesMut, audi_messages: bool) -> decode::Result<Event> {
     if stream.len() < 20 {
         return Err(decode::Error::InsufficientBytes { have: stream.len(), need: 20 });
     }
@@ -84,8 +88,45 @@ fn read_message(stream: &mut BytesMut) -> decode::Result<Message> {
         return Err(decode::Error::InsufficientBytes { have: stream.len(), need: header.length as usize });
     }
 
-    let message = Message::decode_payload(header.mid, header.revision_number(), &mut decoder)?;
+    let event = if audi_messages && (500..=503).contains(&header.mid) {
+        Event::IncomingAudi(AudiMessage::decode_payload(header.mid, header.revision_number(), &mut decoder)?)
+    } else {
+        Event::Incoming(Message::decode_payload(header.mid, header.revision_number(), &mut decoder)?)
+    };
     decoder.expect_char(0x0 as char)?;
     stream.advance((header.length + 1) as usize);
-    Ok(message)
+    Ok(event)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use open_protocol::messages::{audi, motor_turning};
+
+    const MID0501: &str = "00230501001         011\0";
+
+    #[test]
+    fn read_audi_message() {
+        let mut stream = BytesMut::from(MID0501);
+
+        let event = read_message(&mut stream, true).unwrap();
+
+        assert!(matches!(
+            event,
+            Event::IncomingAudi(AudiMessage::MID0501rev1(audi::MID0501rev1 { emergency_status: true }))
+        ));
+        assert!(stream.is_empty());
+    }
+
+    #[test]
+    fn read_motor_tuning_message() {
+        let mut stream = BytesMut::from(MID0501);
+
+        let event = read_message(&mut stream, false).unwrap();
+
+        assert!(matches!(
+            event,
+            Event::Incoming(Message::MID0501rev1(motor_turning::MID0501rev1 { motor_tune_result: true }))
+        ));
+    }
 }
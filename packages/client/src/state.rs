@@ -0,0 +1,54 @@
+use open_protocol::messages::automatic_manual_mode::OperatingMode;
+use open_protocol::Message;
+
+/// State of the controller, as far as it is known from the messages received so far.
+/// Fields are `None` until the controller has reported them, which requires a subscription
+/// to MID 0400 Automatic/Manual mode and MID 0420 Open Protocol commands disabled.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct ControllerState {
+    pub operating_mode: Option<OperatingMode>,
+    pub commands_disabled: Option<bool>,
+}
+
+impl ControllerState {
+    /// Whether the controller currently accepts commands. Unknown state is treated as accepting.
+    pub fn accepts_commands(&self) -> bool {
+        self.operating_mode != Some(OperatingMode::Manual) && self.commands_disabled != Some(true)
+    }
+
+    /// Updates the state from an incoming message. Returns whether the state changed.
+    pub fn update(&mut self, message: &Message) -> bool {
+        let previous = self.clone();
+
+        match message {
+            Message::MID0401rev1(message) => self.operating_mode = Some(message.mode),
+            Message::MID0421rev1(message) => self.commands_disabled = Some(message.commands_disabled),
+            _ => {}
+        }
+
+        *self != previous
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use open_protocol::messages::automatic_manual_mode::MID0401rev1;
+    use open_protocol::messages::open_protocol_commands_disabled::MID0421rev1;
+
+    #[test]
+    fn update_state() {
+        let mut state = ControllerState::default();
+        assert!(state.accepts_commands());
+
+        assert!(state.update(&Message::MID0401rev1(MID0401rev1 { mode: OperatingMode::Manual })));
+        assert!(!state.accepts_commands());
+
+        assert!(state.update(&Message::MID0401rev1(MID0401rev1 { mode: OperatingMode::Automatic })));
+        assert!(state.accepts_commands());
+
+        assert!(state.update(&Message::MID0421rev1(MID0421rev1 { commands_disabled: true })));
+        assert!(!state.update(&Message::MID0421rev1(MID0421rev1 { commands_disabled: true })));
+        assert!(!state.accepts_commands());
+    }
+}
@@ -58,32 +58,61 @@ fn expand_enum(input: &DeriveInput, data_enum: &DataEnum) -> Result<TokenStream>
     let items = parse_enum_items(data_enum.variants.iter().collect())?;
     let mut has_other = false;
 
-    let mut decode_enum_lines = Vec::with_capacity(items.len());
+    let mut from_number_lines = Vec::with_capacity(items.len());
     for item in items {
         let name = item.ident;
         let value = item.value;
 
         let out = match value {
-            EnumValue::Number(n) => quote! { #n => Ok(Self::#name), },
+            EnumValue::Number(n) => quote! { #n => Self::#name, },
             EnumValue::Range(n) => {
                 let low = n.start();
                 let high = n.end();
-                quote! { n @ #low..=#high => Ok(Self::#name(n)), }
+                quote! { n @ #low..=#high => Self::#name(n), }
             },
             EnumValue::Other => {
                 has_other = true;
-                quote! { n => Ok(Self::#name(n)), }
+                quote! { n => Self::#name(n), }
             }
         };
 
-        decode_enum_lines.push(out);
+        from_number_lines.push(out);
     }
 
-    if !has_other {
-        decode_enum_lines.push(quote! {
-            n => Err(::open_protocol_codec::decode::Error::InvalidEnumNumber(n, decoder.pos() - size)),
-        });
-    }
+    let from_decoded_number = if has_other {
+        quote! { Ok(Self::from(number)) }
+    } else {
+        quote! {
+            Self::try_from(number)
+                .map_err(|n| ::open_protocol_codec::decode::Error::InvalidEnumNumber(n, decoder.pos() - size))
+        }
+    };
+
+    // The number conversion is generated here too, so the table is only written in the attributes.
+    let from_number = if has_other {
+        quote! {
+            impl ::std::convert::From<::open_protocol_codec::EnumNumber> for #enum_name {
+                fn from(number: ::open_protocol_codec::EnumNumber) -> Self {
+                    match number {
+                        #(#from_number_lines)*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl ::std::convert::TryFrom<::open_protocol_codec::EnumNumber> for #enum_name {
+                type Error = ::open_protocol_codec::EnumNumber;
+
+                fn try_from(number: ::open_protocol_codec::EnumNumber) -> ::std::result::Result<Self, Self::Error> {
+                    Ok(match number {
+                        #(#from_number_lines)*
+                        n => return Err(n),
+                    })
+                }
+            }
+        }
+    };
 
     Ok(quote! {
         impl ::open_protocol_codec::decode::Decode for #enum_name {
@@ -94,49 +123,57 @@ fn expand_enum(input: &DeriveInput, data_enum: &DataEnum) -> Result<TokenStream>
             fn decode_sized(decoder: &mut ::open_protocol_codec::decode::Decoder, size: usize) -> ::open_protocol_codec::decode::Result<Self> {
                 let number = ::open_protocol_codec::EnumNumber::decode_sized(decoder, size)?;
 
-                match number {
-                    #(#decode_enum_lines)*
-                }
+                #from_decoded_number
             }
         }
+
+        #from_number
     })
 }
 
 fn get_decode_field_line(field: &MessageField) -> Result<TokenStream> {
     let identifier = field.get_field_identifier()?;
     let data_type = &field.data_type;
+    let expression = get_decode_field_expression(field);
 
-    Ok(match &field.field_type {
+    Ok(quote! {
+        let #identifier: #data_type = #expression;
+    })
+}
+
+/// The expression reading the field from `decoder`, with the fields before it in `field_*` variables.
+pub(crate) fn get_decode_field_expression(field: &MessageField) -> TokenStream {
+    match &field.field_type {
         MessageFieldType::FixedLength { length } => {
             let length = get_amount_identifier(length);
             quote! {
-                let #identifier: #data_type = decoder.read_sized_field(#length)?;
+                decoder.read_sized_field(#length)?
             }
         },
 
         MessageFieldType::NumberedVariableLength { number, option: false } => {
             quote! {
-                let #identifier: #data_type = decoder.read_numbered_field(#number)?;
+                decoder.read_numbered_field(#number)?
             }
         },
 
         MessageFieldType::NumberedVariableLength { number, option: true } => {
             quote! {
-                let #identifier: #data_type = decoder.read_numbered_optional_field(#number)?;
+                decoder.read_numbered_optional_field(#number)?
             }
         },
 
         MessageFieldType::NumberedFixedLength { number, length, option: false } => {
             let length = get_amount_identifier(length);
             quote! {
-                let #identifier: #data_type = decoder.read_numbered_sized_field(#number, #length)?;
+                decoder.read_numbered_sized_field(#number, #length)?
             }
         },
 
         MessageFieldType::NumberedFixedLength { number, length, option: true } => {
             let length = get_amount_identifier(length);
             quote! {
-                let #identifier: #data_type = decoder.read_numbered_sized_optional_field(#number, #length)?;
+                decoder.read_numbered_sized_optional_field(#number, #length)?
             }
         },
 
@@ -144,20 +181,28 @@ fn get_decode_field_line(field: &MessageField) -> Result<TokenStream> {
             let amount = get_amount_identifier(amount);
             let length = get_amount_identifier(length);
             quote! {
-                let #identifier: #data_type = decoder.read_sized_list(#amount, #length)?;
+                decoder.read_sized_list(#amount, #length)?
+            }
+        }
+
+        MessageFieldType::NumberedList { number, length, amount } => {
+            let amount = get_amount_identifier(amount);
+            let length = get_amount_identifier(length);
+            quote! {
+                decoder.read_numbered_sized_list(#number, #amount, #length)?
             }
         }
 
         MessageFieldType::ListVariableLength { amount } => {
             let amount = get_amount_identifier(amount);
             quote! {
-                let #identifier: #data_type = decoder.read_list(#amount)?;
+                decoder.read_list(#amount)?
             }
         }
-    })
+    }
 }
 
-fn get_create_struct_line(field: &MessageField) -> Result<TokenStream> {
+pub(crate) fn get_create_struct_line(field: &MessageField) -> Result<TokenStream> {
     let var_identifier = field.get_field_identifier()?;
     let field_identifier = &field.ident.clone().unwrap();
 
@@ -166,7 +211,7 @@ fn get_create_struct_line(field: &MessageField) -> Result<TokenStream> {
     })
 }
 
-fn get_amount_identifier(amount: &Amount) -> TokenStream {
+pub(crate) fn get_amount_identifier(amount: &Amount) -> TokenStream {
     match amount {
         Amount::Fixed(size) => quote! { #size },
         Amount::FromField(field) => {
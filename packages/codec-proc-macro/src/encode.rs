@@ -46,7 +46,7 @@ fn expand_struct(input: &DeriveInput, data_struct: &DataStruct) -> Result<TokenS
     })
 }
 
-fn encode_field_line(field: &MessageField) -> Result<TokenStream> {
+pub(crate) fn encode_field_line(field: &MessageField) -> Result<TokenStream> {
     let self_ref = field.get_self_ref()?;
 
     let token_stream = match &field.field_type {
@@ -91,6 +91,14 @@ fn encode_field_line(field: &MessageField) -> Result<TokenStream> {
             }
         }
 
+        MessageFieldType::NumberedList { number, length, amount } => {
+            let length = get_amount_identifier(length);
+            let amount = get_amount_identifier(amount);
+            quote! {
+                encoder.write_numbered_sized_list(#self_ref, #number, #length, #amount)?;
+            }
+        }
+
         MessageFieldType::ListVariableLength { amount } => {
             let amount = get_amount_identifier(amount);
             quote! {
@@ -112,9 +120,9 @@ fn expand_enum(input: &DeriveInput, data_enum: &DataEnum) -> Result<TokenStream>
         let value = field.value;
 
         let out = match value {
-            EnumValue::Number(n) => quote! { Self::#item => &#n, },
-            EnumValue::Range(_) => quote! { Self::#item(n) => n, },
-            EnumValue::Other => quote! { Self::#item(n) => n, },
+            EnumValue::Number(n) => quote! { #enum_name::#item => #n, },
+            EnumValue::Range(_) => quote! { #enum_name::#item(n) => *n, },
+            EnumValue::Other => quote! { #enum_name::#item(n) => *n, },
         };
 
         write_enum_lines.push(out);
@@ -127,10 +135,15 @@ fn expand_enum(input: &DeriveInput, data_enum: &DataEnum) -> Result<TokenStream>
             }
 
             fn encode_sized(&self, encoder: &mut ::open_protocol_codec::encode::Encoder, size: usize) -> ::open_protocol_codec::encode::Result<()> {
-                let number = match self {
+                ::open_protocol_codec::EnumNumber::from(self).encode_sized(encoder, size)
+            }
+        }
+
+        impl ::std::convert::From<&#enum_name> for ::open_protocol_codec::EnumNumber {
+            fn from(value: &#enum_name) -> Self {
+                match value {
                     #(#write_enum_lines)*
-                };
-                number.encode_sized(encoder, size)
+                }
             }
         }
     })
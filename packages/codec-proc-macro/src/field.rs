@@ -52,6 +52,7 @@ pub enum MessageFieldType {
     NumberedVariableLength { number: u8, option: bool },
     NumberedFixedLength { number: u8, length: Amount, option: bool },
     List { length: Amount, amount: Amount },
+    NumberedList { number: u8, length: Amount, amount: Amount },
     ListVariableLength { amount: Amount },
 }
 
@@ -100,6 +101,9 @@ fn parse_field(index: usize, field: &Field) -> Result<MessageField> {
     }
 
     let field_type = match field_data {
+        ParseField { list: true, list_amount: Some(amount), length: Some(length), number: Some(number) }
+        => MessageFieldType::NumberedList { number, length, amount },
+
         ParseField { list: true, list_amount: Some(amount), length: Some(length), .. }
         => MessageFieldType::List { length, amount },
 
@@ -1,8 +1,11 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{DataStruct, DeriveInput, LitInt, LitStr, Meta};
-use open_protocol_codec::message::MessageType;
+use open_protocol_codec::message::{Direction, MessageType};
 use crate::base::{Error, Result};
+use crate::decode::{get_amount_identifier, get_create_struct_line, get_decode_field_expression};
+use crate::encode::encode_field_line;
+use crate::field::{parse_fields, MessageField, MessageFieldType};
 
 pub fn expand(input: DeriveInput) -> Result<TokenStream> {
     match &input.data {
@@ -20,10 +23,39 @@ fn get_message_type_tokens(message_type: &MessageType) -> TokenStream {
     }
 }
 
-fn get_details(input: &DeriveInput) -> Result<(u16, u16, MessageType)> {
+fn get_direction_tokens(direction: &Direction) -> TokenStream {
+    match direction {
+        Direction::IntegratorToController => quote! { ::open_protocol_codec::message::Direction::IntegratorToController },
+        Direction::ControllerToIntegrator => quote! { ::open_protocol_codec::message::Direction::ControllerToIntegrator },
+        Direction::Both => quote! { ::open_protocol_codec::message::Direction::Both },
+    }
+}
+
+fn get_optional_mid_tokens(mid: &Option<u16>) -> TokenStream {
+    match mid {
+        Some(mid) => quote! { Some(#mid) },
+        None => quote! { None },
+    }
+}
+
+struct MessageDetails {
+    mid: u16,
+    revision: u16,
+    message_type: MessageType,
+    direction: Direction,
+    ack: Option<u16>,
+    reply: Option<Option<u16>>,
+    subscribes: Option<u16>,
+}
+
+fn get_details(input: &DeriveInput) -> Result<MessageDetails> {
     let mut mid: u16 = 0;
     let mut revision: u16 = 0;
     let mut message_type = MessageType::Data;
+    let mut direction = Direction::IntegratorToController;
+    let mut ack = None;
+    let mut reply = None;
+    let mut subscribes = None;
 
     for attr in &input.attrs {
         match &attr.meta {
@@ -52,6 +84,41 @@ fn get_details(input: &DeriveInput) -> Result<(u16, u16, MessageType)> {
                             _ => return Err(meta.error(format!("Invalid value '{content}' for type field.")))
                         }
                         Ok(())
+                    } else if meta.path.is_ident("direction") {
+                        let value = meta.value()?;
+                        let parsed: LitStr = value.parse()?;
+                        let content = parsed.value();
+
+                        match content.as_str() {
+                            "to_controller" => direction = Direction::IntegratorToController,
+                            "to_integrator" => direction = Direction::ControllerToIntegrator,
+                            "both" => direction = Direction::Both,
+                            _ => return Err(meta.error(format!("Invalid value '{content}' for direction field.")))
+                        }
+                        Ok(())
+                    } else if meta.path.is_ident("ack") {
+                        let value = meta.value()?;
+                        let parsed: LitInt = value.parse()?;
+                        ack = Some(parsed.base10_parse::<u16>()?);
+                        Ok(())
+                    } else if meta.path.is_ident("reply") {
+                        let value = meta.value()?;
+                        if value.peek(LitStr) {
+                            let parsed: LitStr = value.parse()?;
+                            match parsed.value().as_str() {
+                                "none" => reply = Some(None),
+                                content => return Err(meta.error(format!("Invalid value '{content}' for reply field.")))
+                            }
+                        } else {
+                            let parsed: LitInt = value.parse()?;
+                            reply = Some(Some(parsed.base10_parse::<u16>()?));
+                        }
+                        Ok(())
+                    } else if meta.path.is_ident("subscribes") {
+                        let value = meta.value()?;
+                        let parsed: LitInt = value.parse()?;
+                        subscribes = Some(parsed.base10_parse::<u16>()?);
+                        Ok(())
                     } else {
                         Err(meta.error("Invalid argument"))
                     }
@@ -61,12 +128,12 @@ fn get_details(input: &DeriveInput) -> Result<(u16, u16, MessageType)> {
         }
     }
 
-    Ok((mid, revision, message_type))
+    Ok(MessageDetails { mid, revision, message_type, direction, ack, reply, subscribes })
 }
 
-fn expand_struct(input: &DeriveInput, _data_struct: &DataStruct) -> Result<TokenStream> {
+fn expand_struct(input: &DeriveInput, data_struct: &DataStruct) -> Result<TokenStream> {
     let struct_name = &input.ident;
-    let (mid, revision, message_type) = get_details(input)?;
+    let MessageDetails { mid, revision, message_type, direction, ack, reply, subscribes } = get_details(input)?;
 
     let revision_fns = if revision != 1 {
         quote! {
@@ -91,12 +158,218 @@ fn expand_struct(input: &DeriveInput, _data_struct: &DataStruct) -> Result<Token
         TokenStream::new()
     };
 
+    let direction_fns = if direction != Direction::IntegratorToController {
+        let direction_name = get_direction_tokens(&direction);
+        quote! {
+            fn direction() -> ::open_protocol_codec::message::Direction {
+                #direction_name
+            }
+            fn to_direction(&self) -> ::open_protocol_codec::message::Direction {
+                #direction_name
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let ack_fns = if ack.is_some() {
+        let ack_mid = get_optional_mid_tokens(&ack);
+        quote! {
+            fn ack_mid() -> Option<u16> { #ack_mid }
+            fn to_ack_mid(&self) -> Option<u16> { #ack_mid }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    // Only messages to the controller get a reply, MID 0005 unless specified otherwise.
+    let reply = match reply {
+        Some(reply) => Some(reply),
+        None if direction != Direction::IntegratorToController => Some(None),
+        None => None,
+    };
+
+    let reply_fns = match reply {
+        Some(reply) => {
+            let reply_mid = get_optional_mid_tokens(&reply);
+            quote! {
+                fn reply_mid() -> Option<u16> { #reply_mid }
+                fn to_reply_mid(&self) -> Option<u16> { #reply_mid }
+            }
+        }
+        None => TokenStream::new(),
+    };
+
+    let subscribes_fns = if subscribes.is_some() {
+        let subscribed_mid = get_optional_mid_tokens(&subscribes);
+        quote! {
+            fn subscribed_mid() -> Option<u16> { #subscribed_mid }
+            fn to_subscribed_mid(&self) -> Option<u16> { #subscribed_mid }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let fields_fns = get_fields_fns(data_struct)?;
+
     Ok(quote! {
         impl ::open_protocol_codec::message::Message for #struct_name {
             fn mid() -> u16 { #mid }
             fn to_mid(&self) -> u16 { #mid }
             #revision_fns
             #message_type_fns
+            #direction_fns
+            #ack_fns
+            #reply_fns
+            #subscribes_fns
+            #fields_fns
+        }
+    })
+}
+
+fn get_fields_fns(data_struct: &DataStruct) -> Result<TokenStream> {
+    // Messages that encode themselves have no field attributes, their payload is one field.
+    let encodes_itself = data_struct.fields.iter()
+        .all(|field| !field.attrs.iter().any(|attr| attr.path().is_ident("open_protocol_field")));
+    if encodes_itself && !data_struct.fields.is_empty() {
+        return Ok(quote! {
+            fn encode_fields(&self) -> ::open_protocol_codec::encode::Result<Vec<::open_protocol_codec::message::EncodedField>> {
+                let mut encoder = ::open_protocol_codec::encode::Encoder::new();
+                ::open_protocol_codec::encode::Encode::encode(self, &mut encoder)?;
+                Ok(vec![("payload", encoder.as_slice().to_vec())])
+            }
+
+            fn decode_fields(fields: &[::open_protocol_codec::message::EncodedField]) -> ::open_protocol_codec::decode::Result<Self> {
+                match fields {
+                    [] => Ok(<Self as ::std::default::Default>::default()),
+                    [("payload", bytes)] => {
+                        let decoder = &mut ::open_protocol_codec::decode::Decoder::new(bytes);
+                        let value = <Self as ::open_protocol_codec::decode::Decode>::decode(decoder)?;
+                        if decoder.pos() != decoder.len() {
+                            return Err(::open_protocol_codec::decode::Error::UnconvertibleField("payload".into()));
+                        }
+                        Ok(value)
+                    }
+                    [(name, _), ..] => Err(::open_protocol_codec::decode::Error::UnconvertibleField(name.to_string())),
+                }
+            }
+        });
+    }
+
+    let fields = parse_fields(&data_struct.fields)?;
+
+    if fields.is_empty() {
+        return Ok(quote! {
+            fn encode_fields(&self) -> ::open_protocol_codec::encode::Result<Vec<::open_protocol_codec::message::EncodedField>> {
+                Ok(Vec::new())
+            }
+
+            fn decode_fields(fields: &[::open_protocol_codec::message::EncodedField]) -> ::open_protocol_codec::decode::Result<Self> {
+                match fields.first() {
+                    Some((name, _)) => Err(::open_protocol_codec::decode::Error::UnconvertibleField(name.to_string())),
+                    None => Ok(<Self as ::std::default::Default>::default()),
+                }
+            }
+        });
+    }
+
+    let mut names = Vec::with_capacity(fields.len());
+    let mut encode_field_blocks = Vec::with_capacity(fields.len());
+    let mut decode_field_lines = Vec::with_capacity(fields.len());
+    let mut create_struct_lines = Vec::with_capacity(fields.len());
+
+    for field in &fields {
+        let field_ident = field.ident.as_ref().ok_or(Error::FieldWithoutIdentifier)?;
+        let name = field_ident.to_string();
+        let identifier = field.get_field_identifier()?;
+        let data_type = &field.data_type;
+        let encode_line = encode_field_line(field)?;
+        let decode_expression = get_decode_field_expression(field);
+
+        encode_field_blocks.push(quote! {
+            {
+                let mut encoder = ::open_protocol_codec::encode::Encoder::new();
+                #encode_line
+                fields.push((#name, encoder.as_slice().to_vec()));
+            }
+        });
+
+        let convert_expression = get_convert_field_expression(field, &name, decode_expression);
+        decode_field_lines.push(quote! {
+            let #identifier: #data_type = match fields.iter().find(|(name, _)| *name == #name) {
+                Some((_, bytes)) => #convert_expression,
+                None => defaults.#field_ident,
+            };
+        });
+
+        create_struct_lines.push(get_create_struct_line(field)?);
+        names.push(name);
+    }
+
+    Ok(quote! {
+        fn encode_fields(&self) -> ::open_protocol_codec::encode::Result<Vec<::open_protocol_codec::message::EncodedField>> {
+            let mut fields = Vec::new();
+            #(#encode_field_blocks)*
+            Ok(fields)
+        }
+
+        fn decode_fields(fields: &[::open_protocol_codec::message::EncodedField]) -> ::open_protocol_codec::decode::Result<Self> {
+            if let Some((name, _)) = fields.iter().find(|(name, _)| ![#(#names),*].contains(name)) {
+                return Err(::open_protocol_codec::decode::Error::UnconvertibleField(name.to_string()));
+            }
+
+            let defaults = <Self as ::std::default::Default>::default();
+            #(#decode_field_lines)*
+
+            Ok(Self {
+                #(#create_struct_lines)*
+            })
         }
     })
 }
+
+/// The expression reading a field of this revision from the `bytes` of the field in another
+/// revision. A fixed length field may have another length there, see
+/// `open_protocol_codec::message::decode_resized`. Other fields have to use up their bytes,
+/// otherwise they are longer in the other revision and would be cut off.
+fn get_convert_field_expression(field: &MessageField, name: &str, decode_expression: TokenStream) -> TokenStream {
+    let data_type = &field.data_type;
+    let unconvertible = quote! {
+        ::open_protocol_codec::decode::Error::UnconvertibleField(#name.into())
+    };
+
+    match &field.field_type {
+        MessageFieldType::FixedLength { length } => {
+            let length = get_amount_identifier(length);
+            quote! {
+                ::open_protocol_codec::message::decode_resized::<#data_type>(bytes, #length)
+                    .ok_or_else(|| #unconvertible)?
+            }
+        }
+        MessageFieldType::NumberedFixedLength { number, length, .. } => {
+            let length = get_amount_identifier(length);
+            quote! {
+                {
+                    let number = format!("{:02}", #number);
+                    match bytes.split_at_checked(::open_protocol_codec::FIELD_NUMBER_LEN) {
+                        Some((prefix, bytes)) if prefix == number.as_bytes() => {
+                            ::open_protocol_codec::message::decode_resized::<#data_type>(bytes, #length)
+                                .ok_or_else(|| #unconvertible)?
+                        }
+                        _ => return Err(#unconvertible),
+                    }
+                }
+            }
+        }
+        _ => quote! {
+            {
+                let decoder = &mut ::open_protocol_codec::decode::Decoder::new(bytes);
+                let value = #decode_expression;
+                if decoder.pos() != decoder.len() {
+                    return Err(#unconvertible);
+                }
+                value
+            }
+        },
+    }
+}
@@ -1,6 +1,6 @@
 use thiserror;
 use crate::{FieldNumber, FIELD_NUMBER_LEN};
-use chrono::{DateTime, Local, MappedLocalTime, TimeZone};
+use chrono::{NaiveDate, NaiveDateTime};
 
 #[derive(Debug, Eq, PartialEq, thiserror::Error)]
 pub enum Error {
@@ -30,6 +30,10 @@ pub enum Error {
     SizeMismatch { requested_size: usize, type_name: String },
     #[error("Integer {number} does not fit the type {type_name}.")]
     IntegerOverflow { type_name: String, number: u128 },
+    #[error("Missing {0}.")]
+    MissingField(String),
+    #[error("Field {0} cannot be carried over to this revision.")]
+    UnconvertibleField(String),
     #[error("Insufficient bytes to decode message, header indicates {need} bytes but only have {have} bytes.")]
     InsufficientBytes { have: usize, need: usize },
 
@@ -173,6 +177,21 @@ impl<'a> Decoder<'a> {
         Ok(list)
     }
 
+    pub fn read_numbered_sized_list<T: Decode>(
+        &mut self,
+        number: FieldNumber,
+        list_length: usize,
+        item_size: usize,
+    ) -> Result<Vec<T>> {
+        let decoded_number = FieldNumber::decode_sized(self, FIELD_NUMBER_LEN)?;
+
+        if decoded_number != number {
+            return Err(Error::InvalidArgNumber { wanted: number, actual: decoded_number })
+        }
+
+        self.read_sized_list(list_length, item_size)
+    }
+
     pub fn read_list<T: Decode>(
         &mut self,
         list_length: usize
@@ -344,6 +363,7 @@ impl Decode for char {
 }
 
 /// String based on the ASCII characters in the decoder, can be length 0-infinite.
+/// Unsized strings end at the NUL character terminating the message, which is not consumed.
 impl Decode for String {
     fn decode(decoder: &mut Decoder) -> Result<Self> {
         let mut chars = Vec::new();
@@ -351,11 +371,12 @@ impl Decode for String {
         while decoder.pos() < decoder.len() {
             let next_char = char::decode(decoder)?;
 
-            if next_char == ' ' || next_char == '\0' {
+            if next_char == '\0' {
+                decoder.back(1)?;
                 break;
             }
 
-            chars.push(char::decode(decoder)?);
+            chars.push(next_char);
         }
 
         Ok(String::from_iter(chars))
@@ -365,7 +386,7 @@ impl Decode for String {
         let chars = decoder.read_bytes(size)?;
 
         let mut end = size;
-        while chars[end - 1] == b' ' {
+        while end > 0 && chars[end - 1] == b' ' {
             end -= 1;
         }
 
@@ -411,7 +432,7 @@ impl<T> Decode for Option<T> where T: Decode {
     }
 }
 
-impl Decode for DateTime<Local> {
+impl Decode for NaiveDateTime {
     fn decode(decoder: &mut Decoder) -> Result<Self> {
         let year = u32::decode_sized(decoder, 4)?;
         decoder.expect_char('-')?;
@@ -425,10 +446,9 @@ impl Decode for DateTime<Local> {
         decoder.expect_char(':')?;
         let sec = u32::decode_sized(decoder, 2)?;
 
-        match Local.with_ymd_and_hms(year as i32, month, day, hour, min, sec) {
-            MappedLocalTime::Single(timestamp) => Ok(timestamp),
-            _ => Err(Error::InvalidTimestamp)
-        }
+        NaiveDate::from_ymd_opt(year as i32, month, day)
+            .and_then(|date| date.and_hms_opt(hour, min, sec))
+            .ok_or(Error::InvalidTimestamp)
     }
 
     fn decode_sized(decoder: &mut Decoder, size: usize) -> Result<Self> {
@@ -441,7 +461,7 @@ impl Decode for DateTime<Local> {
 
 #[cfg(test)]
 mod tests {
-    use chrono::{DateTime, Local, TimeZone};
+    use chrono::{NaiveDate, NaiveDateTime};
     use crate::decode::Error;
     use crate::decode::{Decode, Decoder, Result};
 
@@ -541,6 +561,42 @@ mod tests {
         assert_eq!(decoder.pos(), 5);
     }
 
+    #[test]
+    fn test_read_space_padded_string() {
+        let bytes = [b'H', b'i', b' ', b'1', b' ', b' ', b'7'];
+        let mut decoder = Decoder::new(&bytes[..]);
+
+        assert_eq!(String::decode_sized(&mut decoder, 6), Ok("Hi 1".to_string()));
+        assert_eq!(decoder.pos(), 6);
+    }
+
+    #[test]
+    fn test_read_blank_string() {
+        let bytes = [b' ', b' ', b' ', b'6'];
+        let mut decoder = Decoder::new(&bytes[..]);
+
+        assert_eq!(String::decode_sized(&mut decoder, 3), Ok("".to_string()));
+        assert_eq!(decoder.pos(), 3);
+    }
+
+    #[test]
+    fn test_read_unsized_string() {
+        let bytes = [b'H', b'e', b'l', b'l', b'o', b' ', b'1', b'\0'];
+        let mut decoder = Decoder::new(&bytes[..]);
+
+        assert_eq!(String::decode(&mut decoder), Ok("Hello 1".to_string()));
+        assert_eq!(decoder.pos(), 7);
+    }
+
+    #[test]
+    fn test_read_unterminated_unsized_string() {
+        let bytes = [b'H', b'e', b'l', b'l', b'o', b' '];
+        let mut decoder = Decoder::new(&bytes[..]);
+
+        assert_eq!(String::decode(&mut decoder), Ok("Hello ".to_string()));
+        assert_eq!(decoder.pos(), 6);
+    }
+
     #[test]
     fn test_read_option_string() {
         let bytes = [b'H', b'e', b'l', b'l', b'o', b'6', b'7', b'8'];
@@ -595,19 +651,26 @@ mod tests {
     fn test_read_timestamp() {
         let str = "2001-12-01:20:12:45000000";
         let mut decoder = Decoder::new(str.as_bytes());
-        let actual_timestamp = Local.with_ymd_and_hms(2001, 12, 1, 20, 12, 45).unwrap();
+        let actual_timestamp = NaiveDate::from_ymd_opt(2001, 12, 1).unwrap().and_hms_opt(20, 12, 45).unwrap();
 
-        let timestamp_res: Result<DateTime<Local>> = DateTime::decode(&mut decoder);
+        let timestamp_res: Result<NaiveDateTime> = NaiveDateTime::decode(&mut decoder);
 
         assert_eq!(timestamp_res, Ok(actual_timestamp));
     }
 
+    #[test]
+    fn test_read_nonexistent_date() {
+        let mut decoder = Decoder::new("2001-02-30:20:12:45".as_bytes());
+
+        assert_eq!(NaiveDateTime::decode(&mut decoder), Err(Error::InvalidTimestamp));
+    }
+
     #[test]
     fn test_read_invalid_timestamp() {
         let str = "2001:12:01:20:12:45000000";
         let mut decoder = Decoder::new(str.as_bytes());
 
-        let timestamp_res: Result<DateTime<Local>> = DateTime::decode(&mut decoder);
+        let timestamp_res: Result<NaiveDateTime> = NaiveDateTime::decode(&mut decoder);
 
         assert_eq!(timestamp_res, Err(Error::ExpectedCharacter { decoded_char: ':', expected_char: '-', pos: 4 }));
     }
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Datelike, Local, Timelike};
+use chrono::{Datelike, NaiveDateTime, Timelike};
 use thiserror;
 use crate::{FieldNumber, FIELD_NUMBER_LEN};
 
@@ -19,7 +19,13 @@ pub enum Error {
     #[error("Unsized encode is not allowed for this type.")]
     UnsizedEncodeNotAllowed,
     #[error("Character is a non-ASCII character, which is required.")]
-    NonAsciiCharacter(char)
+    NonAsciiCharacter(char),
+    #[error("Cannot write {0}, it is not a finite number.")]
+    NonFiniteNumber(String),
+    #[error("Cannot write a list of {actual} items as {expected} items")]
+    InvalidListLength { expected: usize, actual: usize },
+    #[error("Invalid MID {mid} revision {revision}")]
+    InvalidMessage { mid: u16, revision: u16 },
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -86,18 +92,31 @@ impl Encoder {
         Ok(())
     }
 
-    pub fn write_sized_list<T: Encode>(&mut self, list: &Vec<T>, item_size: usize, amount: usize) -> Result<()> {
-        for index in 0..amount {
-            let item = &list[index];
+    pub fn write_sized_list<T: Encode>(&mut self, list: &[T], item_size: usize, amount: usize) -> Result<()> {
+        check_list_length(list, amount)?;
+
+        for item in list {
+            self.write_sized_field(item, item_size)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn write_numbered_sized_list<T: Encode>(&mut self, list: &[T], number: FieldNumber, item_size: usize, amount: usize) -> Result<()> {
+        check_list_length(list, amount)?;
+        number.encode_sized(self, FIELD_NUMBER_LEN)?;
+
+        for item in list {
             self.write_sized_field(item, item_size)?;
         }
 
         Ok(())
     }
 
-    pub fn write_list<T: Encode>(&mut self, list: &Vec<T>, amount: usize) -> Result<()> {
-        for index in 0..amount {
-            let item = &list[index];
+    pub fn write_list<T: Encode>(&mut self, list: &[T], amount: usize) -> Result<()> {
+        check_list_length(list, amount)?;
+
+        for item in list {
             item.encode(self)?
         }
 
@@ -122,6 +141,14 @@ impl Encoder {
     }
 }
 
+fn check_list_length<T>(list: &[T], amount: usize) -> Result<()> {
+    if list.len() != amount {
+        return Err(Error::InvalidListLength { expected: amount, actual: list.len() });
+    }
+
+    Ok(())
+}
+
 pub fn encode<T: Encode>(item: &T) -> Result<String> {
     let mut encoder = Encoder::new();
     item.encode(&mut encoder)?;
@@ -135,7 +162,7 @@ impl Encode for u8 {
 
     fn encode_sized(&self, encoder: &mut Encoder, size: usize) -> Result<()> {
         for i in (0..size).rev() {
-            let digit_byte = (self / 10u8.pow(i as u32)) % 10 + b'0';
+            let digit_byte = ((u128::from(*self) / 10u128.pow(i as u32)) % 10) as u8 + b'0';
             encoder.write_byte(&digit_byte)?;
         }
         Ok(())
@@ -149,7 +176,7 @@ impl Encode for u16 {
 
     fn encode_sized(&self, encoder: &mut Encoder, size: usize) -> Result<()> {
         for i in (0..size).rev() {
-            let digit_byte = ((self / 10u16.pow(i as u32)) % 10) as u8 + b'0';
+            let digit_byte = ((u128::from(*self) / 10u128.pow(i as u32)) % 10) as u8 + b'0';
             encoder.write_byte(&digit_byte)?;
         }
 
@@ -164,7 +191,7 @@ impl Encode for u32 {
 
     fn encode_sized(&self, encoder: &mut Encoder, size: usize) -> Result<()> {
         for i in (0..size).rev() {
-            let digit_byte = ((self / 10u32.pow(i as u32)) % 10) as u8 + b'0';
+            let digit_byte = ((u128::from(*self) / 10u128.pow(i as u32)) % 10) as u8 + b'0';
             encoder.write_byte(&digit_byte)?;
         }
 
@@ -179,7 +206,7 @@ impl Encode for u64 {
 
     fn encode_sized(&self, encoder: &mut Encoder, size: usize) -> Result<()> {
         for i in (0..size).rev() {
-            let digit_byte = ((self / 10u64.pow(i as u32)) % 10) as u8 + b'0';
+            let digit_byte = ((u128::from(*self) / 10u128.pow(i as u32)) % 10) as u8 + b'0';
             encoder.write_byte(&digit_byte)?;
         }
 
@@ -262,7 +289,7 @@ impl<T: Encode> Encode for Option<T> {
     }
 }
 
-impl Encode for DateTime<Local> {
+impl Encode for NaiveDateTime {
     fn encode(&self, encoder: &mut Encoder) -> Result<()> {
         (self.year() as u32).encode_sized(encoder, 4)?;
         '-'.encode(encoder)?;
@@ -1,5 +1,5 @@
-use crate::decode::Decode;
-use crate::encode::Encode;
+use crate::decode::{self, Decode, Decoder};
+use crate::encode::{self, Encode, Encoder};
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum MessageType {
@@ -9,6 +9,13 @@ pub enum MessageType {
     UnsubscribeExtraData,
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Direction {
+    IntegratorToController,
+    ControllerToIntegrator,
+    Both,
+}
+
 pub trait Message: Encode + Decode {
     fn mid() -> u16;
     fn to_mid(&self) -> u16;
@@ -18,4 +25,93 @@ pub trait Message: Encode + Decode {
 
     fn message_type() -> MessageType { MessageType::Data }
     fn to_message_type(&self) -> MessageType { MessageType::Data }
+
+    fn direction() -> Direction { Direction::IntegratorToController }
+    fn to_direction(&self) -> Direction { Direction::IntegratorToController }
+
+    /// The MID the integrator acknowledges this message with, for messages uploaded by the
+    /// controller.
+    fn ack_mid() -> Option<u16> { None }
+    fn to_ack_mid(&self) -> Option<u16> { None }
+
+    /// The MID the controller replies with when it accepts this message. When it does not, it
+    /// replies with MID 0004.
+    fn reply_mid() -> Option<u16> { Some(5) }
+    fn to_reply_mid(&self) -> Option<u16> { Some(5) }
+
+    /// The MID of the data this message subscribes to. The revision of such a subscription is
+    /// the revision of the data it asks for.
+    fn subscribed_mid() -> Option<u16> { None }
+    fn to_subscribed_mid(&self) -> Option<u16> { None }
+
+    /// Encodes each field on its own, named after the struct field.
+    fn encode_fields(&self) -> encode::Result<Vec<EncodedField>>;
+
+    /// Builds the message from the fields of another revision. Fields that are not given take
+    /// their default value, given fields this revision does not have are an error.
+    fn decode_fields(fields: &[EncodedField]) -> decode::Result<Self>;
+
+    /// The encoded fields that are not at their default value. Another revision built from
+    /// them only needs to have the fields that are set.
+    fn encode_set_fields(&self) -> encode::Result<Vec<EncodedField>> where Self: Default {
+        let defaults = Self::default().encode_fields()?;
+
+        Ok(self.encode_fields()?
+            .into_iter()
+            .filter(|field| !defaults.contains(field))
+            .collect())
+    }
+}
+
+/// A field of a message encoded on its own, with the name of the struct field.
+pub type EncodedField = (&'static str, Vec<u8>);
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ConvertError {
+    #[error(transparent)]
+    Encode(#[from] encode::Error),
+    #[error(transparent)]
+    Decode(#[from] decode::Error),
+}
+
+/// Reads a fixed length field of another revision, whose length can differ from `size`. The
+/// value has to be the same at both lengths: a number with more digits, or a string with more
+/// characters, than `size` is not read.
+pub fn decode_resized<T: Encode + Decode + PartialEq>(bytes: &[u8], size: usize) -> Option<T> {
+    // Numbers are padded on the left, strings on the right.
+    let candidates = match bytes.len().checked_sub(size) {
+        Some(extra) if extra > 0 => vec![&bytes[extra..], &bytes[..size]],
+        _ => vec![bytes],
+    };
+
+    candidates.into_iter().find_map(|candidate| {
+        let value = T::decode_sized(&mut Decoder::new(candidate), candidate.len()).ok()?;
+
+        let mut original = Encoder::new();
+        value.encode_sized(&mut original, bytes.len()).ok()?;
+        let mut resized = Encoder::new();
+        value.encode_sized(&mut resized, size).ok()?;
+        let fitted = T::decode_sized(&mut Decoder::new(resized.as_slice()), size).ok()?;
+
+        (original.as_slice() == bytes && fitted == value).then_some(value)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_resized_number() {
+        assert_eq!(decode_resized::<u8>(b"0012", 2), Some(12));
+        assert_eq!(decode_resized::<u16>(b"12", 4), Some(12));
+        assert_eq!(decode_resized::<u8>(b"1234", 2), None);
+    }
+
+    #[test]
+    fn test_decode_resized_string() {
+        assert_eq!(decode_resized::<String>(b"abc   ", 4), Some("abc".to_string()));
+        assert_eq!(decode_resized::<String>(b"abc", 6), Some("abc".to_string()));
+        assert_eq!(decode_resized::<String>(b"abcdef", 4), None);
+    }
 }
@@ -1,2 +1,4 @@
 pub mod datatype;
 pub mod error;
+pub mod status;
+pub mod unit;
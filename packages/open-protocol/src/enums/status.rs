@@ -0,0 +1,112 @@
+use open_protocol_codec::EnumNumber;
+use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode};
+use crate::enums::unit::Unit;
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum OkNok {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Nok,
+    #[open_protocol_value(number = 1)]
+    Ok,
+}
+
+impl OkNok {
+    pub fn is_ok(&self) -> bool {
+        *self == OkNok::Ok
+    }
+}
+
+/// The status of a measured value compared to its limits.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum LimitStatus {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Low,
+    #[open_protocol_value(number = 1)]
+    Ok,
+    #[open_protocol_value(number = 2)]
+    High,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum BatchStatus {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Nok,
+    #[open_protocol_value(number = 1)]
+    Ok,
+    #[open_protocol_value(number = 2)]
+    NotUsed,
+    #[open_protocol_value(number = 3)]
+    Running,
+}
+
+/// The unit the torque values of a tightening result are sent in. The codes are the torque
+/// units of [`Unit`], any other code is a decode error.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum TorqueUnit {
+    #[default]
+    #[open_protocol_value(number = 1)]
+    NewtonMetre,
+    #[open_protocol_value(number = 2)]
+    FootPoundForce,
+    #[open_protocol_value(number = 3)]
+    InchPoundForce,
+    #[open_protocol_value(number = 4)]
+    KilopondMetre,
+    #[open_protocol_value(number = 5)]
+    KilogramForceCentimetre,
+    #[open_protocol_value(number = 6)]
+    OunceForceInch,
+    #[open_protocol_value(number = 7)]
+    Percent,
+    #[open_protocol_value(number = 8)]
+    NewtonCentimetre,
+}
+
+impl From<TorqueUnit> for Unit {
+    fn from(value: TorqueUnit) -> Self {
+        Unit::from(EnumNumber::from(&value))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum ResultType {
+    #[default]
+    #[open_protocol_value(number = 1)]
+    Tightening,
+    #[open_protocol_value(number = 2)]
+    Loosening,
+    #[open_protocol_value(number = 3)]
+    BatchIncrement,
+    #[open_protocol_value(number = 4)]
+    BatchDecrement,
+    #[open_protocol_value(number = 5)]
+    BypassParameterSetResult,
+    #[open_protocol_value(number = 6)]
+    AbortJobResult,
+    #[open_protocol_value(number = 7)]
+    SyncTightening,
+    #[open_protocol_value(number = 8)]
+    ReferenceSetup,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use open_protocol_codec::decode::{self, Decode, Decoder};
+
+    #[test]
+    fn torque_unit_to_unit() {
+        assert_eq!(Unit::from(TorqueUnit::NewtonMetre), Unit::NewtonMetre);
+        assert_eq!(Unit::from(TorqueUnit::NewtonCentimetre), Unit::NewtonCentimetre);
+    }
+
+    #[test]
+    fn decode_invalid_torque_unit() {
+        let mut decoder = Decoder::new("9".as_bytes());
+
+        assert_eq!(TorqueUnit::decode_sized(&mut decoder, 1), Err(decode::Error::InvalidEnumNumber(9, 0)));
+    }
+}
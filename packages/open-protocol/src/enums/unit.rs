@@ -0,0 +1,86 @@
+use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode};
+
+/// The unit code of a data field, trace or resolution field.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum Unit {
+    #[default]
+    #[open_protocol_value(number = 1)]
+    NewtonMetre,
+    #[open_protocol_value(number = 2)]
+    FootPoundForce,
+    #[open_protocol_value(number = 3)]
+    InchPoundForce,
+    #[open_protocol_value(number = 4)]
+    KilopondMetre,
+    #[open_protocol_value(number = 5)]
+    KilogramForceCentimetre,
+    #[open_protocol_value(number = 6)]
+    OunceForceInch,
+    #[open_protocol_value(number = 7)]
+    Percent,
+    #[open_protocol_value(number = 8)]
+    NewtonCentimetre,
+    #[open_protocol_value(number = 50)]
+    Degree,
+    #[open_protocol_value(number = 80)]
+    Ampere,
+    #[open_protocol_value(number = 200)]
+    Second,
+    #[open_protocol_value(number = 201)]
+    Millisecond,
+    #[open_protocol_value(number = 202)]
+    Minute,
+    #[open_protocol_value(other)]
+    Other(u16),
+}
+
+impl Unit {
+    pub fn code(&self) -> u16 {
+        u16::from(self)
+    }
+
+    pub fn symbol(&self) -> Option<&'static str> {
+        Some(match self {
+            Unit::NewtonMetre => "Nm",
+            Unit::FootPoundForce => "ft·lbf",
+            Unit::InchPoundForce => "in·lbf",
+            Unit::KilopondMetre => "kpm",
+            Unit::KilogramForceCentimetre => "kgf·cm",
+            Unit::OunceForceInch => "ozf·in",
+            Unit::Percent => "%",
+            Unit::NewtonCentimetre => "Ncm",
+            Unit::Degree => "°",
+            Unit::Ampere => "A",
+            Unit::Second => "s",
+            Unit::Millisecond => "ms",
+            Unit::Minute => "min",
+            Unit::Other(_) => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use open_protocol_codec::decode::{Decode, Decoder};
+    use open_protocol_codec::encode::{Encode, Encoder};
+
+    #[test]
+    fn code_roundtrip() {
+        for code in [1, 7, 50, 80, 201, 999] {
+            assert_eq!(Unit::from(code).code(), code);
+        }
+    }
+
+    #[test]
+    fn decode_matches_code() {
+        let mut decoder = Decoder::new("201".as_bytes());
+
+        assert_eq!(Unit::decode_sized(&mut decoder, 3), Ok(Unit::Millisecond));
+
+        let mut encoder = Encoder::new();
+        Unit::from(123).encode_sized(&mut encoder, 3).unwrap();
+
+        assert_eq!(encoder.to_string(), "123");
+    }
+}
@@ -5,6 +5,7 @@ mod messages_macro;
 
 pub use open_protocol_codec::encode;
 pub use open_protocol_codec::decode;
+pub use open_protocol_codec::message;
 pub use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode};
 
 use open_protocol_codec::decode::{Decode};
@@ -12,19 +13,30 @@ use open_protocol_codec::encode::{Encode};
 use crate::messages_macro::open_protocol_messages;
 use crate::messages::{
     alarm,
+    automatic_manual_mode,
     communication,
+    controller,
+    io_interface,
     job,
+    job_advanced,
     keep_alive,
     link_communication,
     mode,
+    motor_turning,
     multi_spindle_result,
     multi_spindle_status,
+    multiple_identifiers,
+    open_protocol_commands_disabled,
     parameter_set,
+    plc_user_data,
     powermacs_result,
     result,
+    selector,
+    statistic,
     tightening_result,
     time,
     tool,
+    tool_location_system,
     user_interface,
     vin
 };
@@ -92,6 +104,7 @@ open_protocol_messages!(
     MID 0013 revision 1: parameter_set::MID0013rev1,
     MID 0013 revision 2: parameter_set::MID0013rev2,
     MID 0014 revision 1: parameter_set::MID0014rev1,
+    MID 0014 revision 2: parameter_set::MID0014rev2,
     MID 0015 revision 1: parameter_set::MID0015rev1,
     MID 0015 revision 2: parameter_set::MID0015rev2,
     MID 0016 revision 1: parameter_set::MID0016rev1,
@@ -102,6 +115,13 @@ open_protocol_messages!(
     MID 0020 revision 1: parameter_set::MID0020rev1,
     MID 0021 revision 1: parameter_set::MID0021rev1,
     MID 0022 revision 1: parameter_set::MID0022rev1,
+    MID 2500 revision 1: parameter_set::MID2500rev1,
+    MID 2501 revision 1: parameter_set::MID2501rev1,
+    MID 2502 revision 1: parameter_set::MID2502rev1,
+    MID 2503 revision 1: parameter_set::MID2503rev1,
+    MID 2504 revision 1: parameter_set::MID2504rev1,
+    MID 2505 revision 1: parameter_set::MID2505rev1,
+    MID 2506 revision 1: parameter_set::MID2506rev1,
 
     MID 2600 revision 1: mode::MID2600rev1,
     MID 2601 revision 1: mode::MID2601rev1,
@@ -126,9 +146,17 @@ open_protocol_messages!(
     MID 0039 revision 1: job::MID0039rev1,
     MID 0039 revision 2: job::MID0039rev2,
 
+    MID 0040 revision 1: tool::MID0040rev1,
+    MID 0040 revision 2: tool::MID0040rev2,
+    MID 0040 revision 3: tool::MID0040rev3,
+    MID 0040 revision 4: tool::MID0040rev4,
+    MID 0040 revision 5: tool::MID0040rev5,
     MID 0040 revision 6: tool::MID0040rev6,
     MID 0041 revision 1: tool::MID0041rev1,
     MID 0041 revision 2: tool::MID0041rev2,
+    MID 0041 revision 3: tool::MID0041rev3,
+    MID 0041 revision 4: tool::MID0041rev4,
+    MID 0041 revision 5: tool::MID0041rev5,
     MID 0042 revision 2: tool::MID0042rev2,
     MID 0043 revision 2: tool::MID0043rev2,
     MID 0044 revision 1: tool::MID0044rev1,
@@ -140,6 +168,7 @@ open_protocol_messages!(
 
     MID 0050 revision 1: vin::MID0050rev1,
     MID 0051 revision 1: vin::MID0051rev1,
+    MID 0051 revision 2: vin::MID0051rev2,
     MID 0052 revision 1: vin::MID0052rev1,
     MID 0052 revision 2: vin::MID0052rev2,
     MID 0053 revision 1: vin::MID0053rev1,
@@ -147,13 +176,37 @@ open_protocol_messages!(
     MID 0054 revision 2: vin::MID0054rev2,
 
     MID 0060 revision 1: tightening_result::MID0060rev1,
+    MID 0060 revision 2: tightening_result::MID0060rev2,
+    MID 0060 revision 3: tightening_result::MID0060rev3,
+    MID 0060 revision 4: tightening_result::MID0060rev4,
+    MID 0060 revision 5: tightening_result::MID0060rev5,
+    MID 0060 revision 6: tightening_result::MID0060rev6,
+    MID 0060 revision 7: tightening_result::MID0060rev7,
+    MID 0060 revision 998: tightening_result::MID0060rev998,
     MID 0061 revision 1: tightening_result::MID0061rev1,
     MID 0061 revision 2: tightening_result::MID0061rev2,
     MID 0061 revision 3: tightening_result::MID0061rev3,
+    MID 0061 revision 4: tightening_result::MID0061rev4,
+    MID 0061 revision 5: tightening_result::MID0061rev5,
+    MID 0061 revision 6: tightening_result::MID0061rev6,
+    MID 0061 revision 7: tightening_result::MID0061rev7,
+    MID 0061 revision 998: tightening_result::MID0061rev998,
     MID 0062 revision 1: tightening_result::MID0062rev1,
     MID 0063 revision 1: tightening_result::MID0063rev1,
     MID 0064 revision 1: tightening_result::MID0064rev1,
+    MID 0064 revision 2: tightening_result::MID0064rev2,
+    MID 0064 revision 3: tightening_result::MID0064rev3,
+    MID 0064 revision 4: tightening_result::MID0064rev4,
+    MID 0064 revision 5: tightening_result::MID0064rev5,
+    MID 0064 revision 6: tightening_result::MID0064rev6,
+    MID 0064 revision 7: tightening_result::MID0064rev7,
     MID 0065 revision 1: tightening_result::MID0065rev1,
+    MID 0065 revision 2: tightening_result::MID0065rev2,
+    MID 0065 revision 3: tightening_result::MID0065rev3,
+    MID 0065 revision 4: tightening_result::MID0065rev4,
+    MID 0065 revision 5: tightening_result::MID0065rev5,
+    MID 0065 revision 6: tightening_result::MID0065rev6,
+    MID 0065 revision 7: tightening_result::MID0065rev7,
     MID 0066 revision 1: tightening_result::MID0066rev1,
     MID 0066 revision 2: tightening_result::MID0066rev2,
     MID 0067 revision 1: tightening_result::MID0067rev1,
@@ -209,18 +262,97 @@ open_protocol_messages!(
     MID 0111 revision 1: user_interface::MID0111rev1,
     MID 0113 revision 1: user_interface::MID0113rev1,
 
-    // job_advanced;
-    // multiple_identifiers;
-    // io_interface;
-    // plc_user_data;
-    // selector;
-    // tool_location_system;
-    // controller;
-    // statistic;
-    // automatic_manual_mode;
-    // open_protocol_commands_disabled;
-    // audi;
-    // motor_turning;
+    MID 0120 revision 1: job_advanced::MID0120rev1,
+    MID 0121 revision 1: job_advanced::MID0121rev1,
+    MID 0122 revision 1: job_advanced::MID0122rev1,
+    MID 0123 revision 1: job_advanced::MID0123rev1,
+    MID 0124 revision 1: job_advanced::MID0124rev1,
+    MID 0125 revision 1: job_advanced::MID0125rev1,
+    MID 0126 revision 1: job_advanced::MID0126rev1,
+    MID 0127 revision 1: job_advanced::MID0127rev1,
+    MID 0128 revision 1: job_advanced::MID0128rev1,
+    MID 0128 revision 2: job_advanced::MID0128rev2,
+    MID 0129 revision 1: job_advanced::MID0129rev1,
+    MID 0129 revision 2: job_advanced::MID0129rev2,
+    MID 0130 revision 1: job_advanced::MID0130rev1,
+    MID 0131 revision 1: job_advanced::MID0131rev1,
+    MID 0132 revision 1: job_advanced::MID0132rev1,
+    MID 0133 revision 1: job_advanced::MID0133rev1,
+    MID 0140 revision 1: job_advanced::MID0140rev1,
+
+    MID 0150 revision 1: multiple_identifiers::MID0150rev1,
+    MID 0151 revision 1: multiple_identifiers::MID0151rev1,
+    MID 0152 revision 1: multiple_identifiers::MID0152rev1,
+    MID 0153 revision 1: multiple_identifiers::MID0153rev1,
+    MID 0154 revision 1: multiple_identifiers::MID0154rev1,
+    MID 0155 revision 1: multiple_identifiers::MID0155rev1,
+    MID 0156 revision 1: multiple_identifiers::MID0156rev1,
+    MID 0157 revision 1: multiple_identifiers::MID0157rev1,
+
+    MID 0200 revision 1: io_interface::MID0200rev1,
+    MID 0210 revision 1: io_interface::MID0210rev1,
+    MID 0211 revision 1: io_interface::MID0211rev1,
+    MID 0212 revision 1: io_interface::MID0212rev1,
+    MID 0213 revision 1: io_interface::MID0213rev1,
+    MID 0214 revision 1: io_interface::MID0214rev1,
+    MID 0215 revision 1: io_interface::MID0215rev1,
+    MID 0215 revision 2: io_interface::MID0215rev2,
+    MID 0216 revision 1: io_interface::MID0216rev1,
+    MID 0217 revision 1: io_interface::MID0217rev1,
+    MID 0218 revision 1: io_interface::MID0218rev1,
+    MID 0219 revision 1: io_interface::MID0219rev1,
+    MID 0220 revision 1: io_interface::MID0220rev1,
+    MID 0221 revision 1: io_interface::MID0221rev1,
+    MID 0222 revision 1: io_interface::MID0222rev1,
+    MID 0223 revision 1: io_interface::MID0223rev1,
+    MID 0224 revision 1: io_interface::MID0224rev1,
+    MID 0225 revision 1: io_interface::MID0225rev1,
+
+    MID 0240 revision 1: plc_user_data::MID0240rev1,
+    MID 0241 revision 1: plc_user_data::MID0241rev1,
+    MID 0242 revision 1: plc_user_data::MID0242rev1,
+    MID 0243 revision 1: plc_user_data::MID0243rev1,
+    MID 0244 revision 1: plc_user_data::MID0244rev1,
+    MID 0245 revision 1: plc_user_data::MID0245rev1,
+
+    MID 0250 revision 1: selector::MID0250rev1,
+    MID 0251 revision 1: selector::MID0251rev1,
+    MID 0252 revision 1: selector::MID0252rev1,
+    MID 0253 revision 1: selector::MID0253rev1,
+    MID 0254 revision 1: selector::MID0254rev1,
+    MID 0255 revision 1: selector::MID0255rev1,
+
+    MID 0261 revision 1: tool_location_system::MID0261rev1,
+    MID 0262 revision 1: tool_location_system::MID0262rev1,
+    MID 0263 revision 1: tool_location_system::MID0263rev1,
+    MID 0264 revision 1: tool_location_system::MID0264rev1,
+    MID 0265 revision 1: tool_location_system::MID0265rev1,
+    MID 0266 revision 1: tool_location_system::MID0266rev1,
+
+    MID 0270 revision 1: controller::MID0270rev1,
+
+    MID 0300 revision 1: statistic::MID0300rev1,
+    MID 0301 revision 1: statistic::MID0301rev1,
+
+    MID 0400 revision 1: automatic_manual_mode::MID0400rev1,
+    MID 0401 revision 1: automatic_manual_mode::MID0401rev1,
+    MID 0402 revision 1: automatic_manual_mode::MID0402rev1,
+    MID 0403 revision 1: automatic_manual_mode::MID0403rev1,
+    MID 0410 revision 1: automatic_manual_mode::MID0410rev1,
+    MID 0411 revision 1: automatic_manual_mode::MID0411rev1,
+
+    MID 0420 revision 1: open_protocol_commands_disabled::MID0420rev1,
+    MID 0421 revision 1: open_protocol_commands_disabled::MID0421rev1,
+    MID 0422 revision 1: open_protocol_commands_disabled::MID0422rev1,
+    MID 0423 revision 1: open_protocol_commands_disabled::MID0423rev1,
+
+    // audi: uses MID 0500-0503 on Audi controllers only, see `audi::AudiMessage`.
+
+    MID 0500 revision 1: motor_turning::MID0500rev1,
+    MID 0501 revision 1: motor_turning::MID0501rev1,
+    MID 0502 revision 1: motor_turning::MID0502rev1,
+    MID 0503 revision 1: motor_turning::MID0503rev1,
+    MID 0504 revision 1: motor_turning::MID0504rev1,
 
     MID 9999 revision 1: keep_alive::MID9999rev1,
 );
@@ -296,4 +428,67 @@ mod tests {
 
         assert_eq!(packet, "005001002003010020");
     }
+
+    #[test]
+    fn supported_revisions() {
+        assert_eq!(Message::revisions(2), vec![1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(Message::revisions(1234), Vec::<u16>::new());
+    }
+
+    #[test]
+    fn message_metadata() {
+        use open_protocol_codec::message::Direction;
+
+        let result = Message::MID0061rev1(Default::default());
+        let request = Message::MID0012rev1(Default::default());
+        let subscription = Message::MID0060rev1(Default::default());
+
+        assert_eq!(result.direction(), Direction::ControllerToIntegrator);
+        assert_eq!(result.ack_mid(), Some(62));
+        assert_eq!(result.reply_mid(), None);
+        assert_eq!(request.direction(), Direction::IntegratorToController);
+        assert_eq!(request.reply_mid(), Some(13));
+        assert_eq!(subscription.reply_mid(), Some(5));
+        assert_eq!(subscription.subscribed_mid(), Some(61));
+        assert_eq!(request.subscribed_mid(), None);
+        assert_eq!(Message::MID0062rev1(Default::default()).reply_mid(), None);
+    }
+
+    #[test]
+    fn convert_revision_field_by_field() {
+        let request = Message::MID0064rev3(tightening_result::MID0064rev3 { tightening_id: 1234 });
+
+        assert_eq!(
+            request.convert(2),
+            Ok(Message::MID0064rev2(tightening_result::MID0064rev2 { tightening_id: 1234 }))
+        );
+    }
+
+    #[test]
+    fn convert_revision_with_other_field_length() {
+        let short = Message::MID0038rev1(job::MID0038rev1 { job_id: 12 });
+        let long = Message::MID0038rev2(job::MID0038rev2 { job_id: 1234 });
+
+        assert_eq!(short.convert(2), Ok(Message::MID0038rev2(job::MID0038rev2 { job_id: 12 })));
+        assert_eq!(
+            long.convert(1),
+            Err(decode::Error::UnconvertibleField("job_id".into()).into())
+        );
+    }
+
+    #[test]
+    fn convert_revision_without_set_field() {
+        let unset = Message::MID0040rev6(tool::MID0040rev6 { tool_number: 0 });
+        let set = Message::MID0040rev6(tool::MID0040rev6 { tool_number: 2 });
+
+        assert_eq!(unset.convert(5), Ok(Message::MID0040rev5(tool::MID0040rev5 {})));
+        assert_eq!(
+            set.convert(5),
+            Err(decode::Error::UnconvertibleField("tool_number".into()).into())
+        );
+        assert_eq!(
+            set.convert(8),
+            Err(decode::Error::InvalidMessage { mid: 40, revision: 8 }.into())
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Local};
+use chrono::NaiveDateTime;
 use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
@@ -30,7 +30,7 @@ pub enum AlarmStatus {
 
 /// A subscription request for alarms in the controller.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 70, revision = 1)]
+#[open_protocol_message(MID = 70, revision = 1, subscribes = 71)]
 pub struct MID0070rev1 {
     // No additional fields for this MID.
 }
@@ -38,7 +38,7 @@ pub struct MID0070rev1 {
 /// This message is sent when an alarm appears in the controller.
 /// It includes the alarm code, controller/tool status, and timestamp.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 71, revision = 1)]
+#[open_protocol_message(MID = 71, revision = 1, direction = "to_integrator", ack = 72)]
 pub struct MID0071rev1 {
     /// The alarm error code.
     #[open_protocol_field(length = 4)]
@@ -54,12 +54,12 @@ pub struct MID0071rev1 {
 
     /// Timestamp for the alarm (YYYY-MM-DD:HH:MM:SS).
     #[open_protocol_field(length = 19)]
-    pub timestamp: DateTime<Local>,
+    pub timestamp: NaiveDateTime,
 }
 
 /// Acknowledgment for MID 0071 Alarm.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 72, revision = 1)]
+#[open_protocol_message(MID = 72, revision = 1, reply = "none")]
 pub struct MID0072rev1 {
     // No additional fields for this MID.
 }
@@ -73,7 +73,7 @@ pub struct MID0073rev1 {
 
 /// The controller informs that the alarm has been acknowledged.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 74, revision = 1)]
+#[open_protocol_message(MID = 74, revision = 1, direction = "to_integrator", ack = 75)]
 pub struct MID0074rev1 {
     /// The alarm error code that was acknowledged.
     #[open_protocol_field(length = 4)]
@@ -82,7 +82,7 @@ pub struct MID0074rev1 {
 
 /// Acknowledges receipt of MID 0074 Alarm Acknowledged on Controller.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 75, revision = 1)]
+#[open_protocol_message(MID = 75, revision = 1, reply = "none")]
 pub struct MID0075rev1 {
     // No additional fields for this MID.
 }
@@ -90,7 +90,7 @@ pub struct MID0075rev1 {
 /// Provides the status of an alarm after subscription.
 /// This message is used to inform the integrator of active alarms.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 76, revision = 1)]
+#[open_protocol_message(MID = 76, revision = 1, direction = "to_integrator", ack = 77)]
 pub struct MID0076rev1 {
     /// Alarm status (0=no alarm, 1=alarm active).
     #[open_protocol_field(length = 1)]
@@ -110,12 +110,12 @@ pub struct MID0076rev1 {
 
     /// Timestamp of the alarm (YYYY-MM-DD:HH:MM:SS).
     #[open_protocol_field(length = 19)]
-    pub timestamp: DateTime<Local>,
+    pub timestamp: NaiveDateTime,
 }
 
 /// Acknowledges receipt of MID 0076 Alarm Status.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 77, revision = 1)]
+#[open_protocol_message(MID = 77, revision = 1, reply = "none")]
 pub struct MID0077rev1 {
     // No additional fields for this MID.
 }
@@ -132,7 +132,7 @@ pub struct MID0078rev1 {
 /// An alarm has appeared in the controller.
 /// This message replaces the old MID 0071.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 1000, revision = 1)]
+#[open_protocol_message(MID = 1000, revision = 1, direction = "to_integrator", ack = 1001)]
 pub struct MID1000rev1 {
     /// The alarm error code.
     #[open_protocol_field(length = 5)]
@@ -140,7 +140,7 @@ pub struct MID1000rev1 {
 
     /// Timestamp for the alarm (YYYY-MM-DD:HH:MM:SS).
     #[open_protocol_field(length = 19)]
-    pub timestamp: DateTime<Local>,
+    pub timestamp: NaiveDateTime,
 
     /// The number of variable data fields included in this message.
     #[open_protocol_field(length = 3)]
@@ -153,7 +153,7 @@ pub struct MID1000rev1 {
 
 /// Acknowledges receipt of MID 1000 Alarm.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 1001, revision = 1)]
+#[open_protocol_message(MID = 1001, revision = 1, reply = "none")]
 pub struct MID1001rev1 {
     // No additional fields for this MID.
 }
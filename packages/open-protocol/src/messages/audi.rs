@@ -0,0 +1,99 @@
+use open_protocol_codec::decode::{self, Decode, Decoder};
+use open_protocol_codec::encode::{self, Encode, Encoder};
+use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+
+/// A subscription for the Audi emergency status.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 500, revision = 1)]
+pub struct MID0500rev1 {
+    // No additional fields for this MID.
+}
+
+/// Sent each time the emergency status of the station changes.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 501, revision = 1, direction = "to_integrator", ack = 502)]
+pub struct MID0501rev1 {
+    /// Whether the emergency stop is active.
+    #[open_protocol_field(number = 1, length = 1)]
+    pub emergency_status: bool,
+}
+
+/// Acknowledges receipt of MID 0501 Audi emergency status upload.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 502, revision = 1, reply = "none")]
+pub struct MID0502rev1 {
+    // No additional fields for this MID.
+}
+
+/// Cancels a previously subscribed Audi emergency status.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 503, revision = 1)]
+pub struct MID0503rev1 {
+    // No additional fields for this MID.
+}
+
+/// Any Audi emergency status message.
+///
+/// Controllers configured for Audi use MID 0500-0503 for the emergency status, while all other
+/// controllers use the same MIDs for motor tuning (see [`crate::messages::motor_turning`]).
+/// Because of that, these messages are not part of [`crate::Message`], and clients talking to an
+/// Audi controller decode them with [`AudiMessage::decode_payload`] instead. The client does so
+/// when its event loop is built with `with_audi_messages`.
+#[derive(Debug, Eq, PartialEq)]
+pub enum AudiMessage {
+    MID0500rev1(MID0500rev1),
+    MID0501rev1(MID0501rev1),
+    MID0502rev1(MID0502rev1),
+    MID0503rev1(MID0503rev1),
+}
+
+impl AudiMessage {
+    pub fn mid_revision(&self) -> (u16, u16) {
+        match self {
+            AudiMessage::MID0500rev1(_) => (500, 1),
+            AudiMessage::MID0501rev1(_) => (501, 1),
+            AudiMessage::MID0502rev1(_) => (502, 1),
+            AudiMessage::MID0503rev1(_) => (503, 1),
+        }
+    }
+
+    pub fn encode_payload(&self, encoder: &mut Encoder) -> encode::Result<()> {
+        match self {
+            AudiMessage::MID0500rev1(payload) => payload.encode(encoder),
+            AudiMessage::MID0501rev1(payload) => payload.encode(encoder),
+            AudiMessage::MID0502rev1(payload) => payload.encode(encoder),
+            AudiMessage::MID0503rev1(payload) => payload.encode(encoder),
+        }
+    }
+
+    pub fn decode_payload(mid: u16, revision: u16, decoder: &mut Decoder) -> decode::Result<Self> {
+        Ok(match (mid, revision) {
+            (500, 1) => AudiMessage::MID0500rev1(MID0500rev1::decode(decoder)?),
+            (501, 1) => AudiMessage::MID0501rev1(MID0501rev1::decode(decoder)?),
+            (502, 1) => AudiMessage::MID0502rev1(MID0502rev1::decode(decoder)?),
+            (503, 1) => AudiMessage::MID0503rev1(MID0503rev1::decode(decoder)?),
+            _ => return Err(decode::Error::NotImplemented),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_audi_emergency_status() {
+        let mut decoder = Decoder::new("011".as_bytes());
+
+        let decoded = AudiMessage::decode_payload(501, 1, &mut decoder);
+
+        assert_eq!(decoded, Ok(AudiMessage::MID0501rev1(MID0501rev1 { emergency_status: true })));
+    }
+
+    #[test]
+    fn decode_unknown_audi_message() {
+        let mut decoder = Decoder::new("".as_bytes());
+
+        assert_eq!(AudiMessage::decode_payload(504, 1, &mut decoder), Err(decode::Error::NotImplemented));
+    }
+}
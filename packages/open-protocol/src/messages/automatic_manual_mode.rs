@@ -0,0 +1,93 @@
+use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum OperatingMode {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Automatic,
+    #[open_protocol_value(number = 1)]
+    Manual,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum AutoDisableSetting {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Off,
+    #[open_protocol_value(number = 1)]
+    On,
+}
+
+/// A subscription for the automatic/manual mode of the controller.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 400, revision = 1)]
+pub struct MID0400rev1 {
+    // No additional fields for this MID.
+}
+
+/// Sent each time the controller switches between automatic and manual mode.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 401, revision = 1, direction = "to_integrator", ack = 402)]
+pub struct MID0401rev1 {
+    /// The current mode of the controller.
+    #[open_protocol_field(number = 1, length = 1)]
+    pub mode: OperatingMode,
+}
+
+/// Acknowledges receipt of MID 0401 Automatic/Manual mode upload.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 402, revision = 1, reply = "none")]
+pub struct MID0402rev1 {
+    // No additional fields for this MID.
+}
+
+/// Cancels a previously subscribed automatic/manual mode.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 403, revision = 1)]
+pub struct MID0403rev1 {
+    // No additional fields for this MID.
+}
+
+/// Requests the AutoDisable settings of the controller. The controller replies with
+/// MID 0411 AutoDisable settings reply.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 410, revision = 1, reply = 411)]
+pub struct MID0410rev1 {
+    // No additional fields for this MID.
+}
+
+/// The AutoDisable settings of the controller.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 411, revision = 1, direction = "to_integrator")]
+pub struct MID0411rev1 {
+    /// Whether the tool is disabled automatically when the controller switches to manual mode.
+    #[open_protocol_field(number = 1, length = 2)]
+    pub auto_disable_setting: AutoDisableSetting,
+
+    /// The current batch, as reported by the controller.
+    #[open_protocol_field(number = 2, length = 2)]
+    pub current_batch: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use open_protocol_codec::{decode, encode};
+
+    #[test]
+    fn decode_mid0401rev1() {
+        let decoded = decode::decode::<MID0401rev1>("011".as_bytes());
+
+        assert_eq!(decoded, Ok(MID0401rev1 { mode: OperatingMode::Manual }));
+    }
+
+    #[test]
+    fn roundtrip_mid0411rev1() {
+        let message = MID0411rev1 { auto_disable_setting: AutoDisableSetting::On, current_batch: 3 };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(encoded, "01010203");
+        assert_eq!(decode::decode::<MID0411rev1>(encoded.as_bytes()), Ok(message));
+    }
+}
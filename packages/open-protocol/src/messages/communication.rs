@@ -1,6 +1,12 @@
+use open_protocol_codec::decode;
+use open_protocol_codec::encode::{self, Encoder};
+use open_protocol_codec::message::{Message, MessageType};
 use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
 use crate::enums::error::ErrorCode;
 
+/// The maximum length of the extra data in MID 0006, 0008 and 0009.
+pub const MAX_EXTRA_DATA_LENGTH: usize = 99;
+
 #[derive(Debug, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
 pub enum KeepAlive {
     #[open_protocol_value(number = 0)]
@@ -12,7 +18,7 @@ pub enum KeepAlive {
 #[derive(
     Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage,
 )]
-#[open_protocol_message(MID = 0001, revision = 7)]
+#[open_protocol_message(MID = 0001, revision = 7, reply = 2)]
 pub struct MID0001rev7 {
     #[open_protocol_field(number = 1, length = 1)]
     pub keep_alive: Option<KeepAlive>,
@@ -21,7 +27,7 @@ pub struct MID0001rev7 {
 #[derive(
     Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage,
 )]
-#[open_protocol_message(MID = 0002, revision = 1)]
+#[open_protocol_message(MID = 0002, revision = 1, direction = "to_integrator")]
 pub struct MID0002rev1 {
     #[open_protocol_field(number = 1, length = 4)]
     pub cell_id: u16,
@@ -34,7 +40,7 @@ pub struct MID0002rev1 {
 #[derive(
     Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage,
 )]
-#[open_protocol_message(MID = 0002, revision = 2)]
+#[open_protocol_message(MID = 0002, revision = 2, direction = "to_integrator")]
 pub struct MID0002rev2 {
     #[open_protocol_field(number = 1, length = 4)]
     pub cell_id: u16,
@@ -49,7 +55,7 @@ pub struct MID0002rev2 {
 #[derive(
     Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage,
 )]
-#[open_protocol_message(MID = 0002, revision = 3)]
+#[open_protocol_message(MID = 0002, revision = 3, direction = "to_integrator")]
 pub struct MID0002rev3 {
     #[open_protocol_field(number = 1, length = 4)]
     pub cell_id: u16,
@@ -70,7 +76,7 @@ pub struct MID0002rev3 {
 #[derive(
     Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage,
 )]
-#[open_protocol_message(MID = 0002, revision = 4)]
+#[open_protocol_message(MID = 0002, revision = 4, direction = "to_integrator")]
 pub struct MID0002rev4 {
     #[open_protocol_field(number = 1, length = 4)]
     pub cell_id: u16,
@@ -95,7 +101,7 @@ pub struct MID0002rev4 {
 #[derive(
     Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage,
 )]
-#[open_protocol_message(MID = 0002, revision = 5)]
+#[open_protocol_message(MID = 0002, revision = 5, direction = "to_integrator")]
 pub struct MID0002rev5 {
     #[open_protocol_field(number = 1, length = 4)]
     pub cell_id: u16,
@@ -124,7 +130,7 @@ pub struct MID0002rev5 {
 #[derive(
     Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage,
 )]
-#[open_protocol_message(MID = 0002, revision = 6)]
+#[open_protocol_message(MID = 0002, revision = 6, direction = "to_integrator")]
 pub struct MID0002rev6 {
     #[open_protocol_field(number = 1, length = 4)]
     pub cell_id: u16,
@@ -163,7 +169,7 @@ pub struct MID0002rev6 {
 #[derive(
     Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage,
 )]
-#[open_protocol_message(MID = 0002, revision = 7)]
+#[open_protocol_message(MID = 0002, revision = 7, direction = "to_integrator")]
 pub struct MID0002rev7 {
     #[open_protocol_field(number = 1, length = 4)]
     pub cell_id: u16,
@@ -215,7 +221,7 @@ pub struct MID0003rev1 {}
 /// has not been performed. The data field contains the message ID of the message request that
 /// failed as well as an error code.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0004, revision = 1)]
+#[open_protocol_message(MID = 0004, revision = 1, direction = "to_integrator")]
 pub struct MID0004rev1 {
     #[open_protocol_field(length = 4)]
     pub mid: u16,
@@ -228,7 +234,7 @@ pub struct MID0004rev1 {
 /// has not been performed. The data field contains the message ID of the message request that
 /// failed as well as an error code.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0004, revision = 2)]
+#[open_protocol_message(MID = 0004, revision = 2, direction = "to_integrator")]
 pub struct MID0004rev2 {
     #[open_protocol_field(length = 4)]
     pub mid: u16,
@@ -241,7 +247,7 @@ pub struct MID0004rev2 {
 /// subscription sent by the integrator was accepted. The data field contains the MID of the
 /// request accepted if the special MIDs for request or subscription are used.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0005, revision = 1)]
+#[open_protocol_message(MID = 0005, revision = 1, direction = "to_integrator")]
 pub struct MID0005rev1 {
     #[open_protocol_field(length = 4)]
     pub mid: u16,
@@ -251,16 +257,23 @@ pub struct MID0005rev1 {
 /// Do a request for data. This message is used for ALL request handling.
 /// When used it substitutes the use of all MID special request messages.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0006, revision = 1)]
+#[open_protocol_message(MID = 0006, revision = 1, reply = "none")]
 pub struct MID0006rev1 {
+    /// The MID to be requested.
     #[open_protocol_field(length = 4)]
-    requested_mid: u16,
+    pub requested_mid: u16,
+
+    /// The revision of the MID to request.
     #[open_protocol_field(length = 3)]
-    wanted_revision: u16,
+    pub wanted_revision: u16,
+
+    /// The length of the extra data field.
     #[open_protocol_field(length = 2)]
-    extra_data_length: u8,
-    #[open_protocol_field(list, amount = "extra_data_length", length = 1)]
-    extra_data: Vec<u8>,
+    pub extra_data_length: u8,
+
+    /// The extra data field (variable length).
+    #[open_protocol_field(length = "extra_data_length")]
+    pub extra_data: String,
 }
 
 /// # 5.2.7 MID 0008 Application data message subscription
@@ -283,8 +296,8 @@ pub struct MID0008rev1 {
     pub extra_data_length: u16,
 
     /// The extra data field (variable length).
-    #[open_protocol_field(list, amount = "extra_data_length", length = 1)]
-    pub extra_data: Vec<u8>,
+    #[open_protocol_field(length = "extra_data_length")]
+    pub extra_data: String,
 }
 
 /// # 5.2.8 MID 0009 Application Data Message unsubscribe.
@@ -306,14 +319,189 @@ pub struct MID0009rev1 {
     pub extra_data_length: u16,
 
     /// The extra data field (variable length).
-    #[open_protocol_field(list, amount = "extra_data_length", length = 1)]
-    pub extra_data: Vec<u8>,
+    #[open_protocol_field(length = "extra_data_length")]
+    pub extra_data: String,
+}
+
+/// Whether extra data of type `T` can be sent for `revision` of its MID. Extra data of revision N
+/// applies to the revisions N and higher of the MID that this library supports.
+fn supports_revision<T: Message>(revision: u16) -> bool {
+    revision >= T::revision() && crate::Message::revisions(T::mid()).contains(&revision)
+}
+
+fn encode_extra_data<T: Message>(extra_data: &T, revision: u16, message_type: MessageType) -> encode::Result<String> {
+    if T::message_type() != message_type || !supports_revision::<T>(revision) {
+        return Err(encode::Error::InvalidMessage { mid: T::mid(), revision });
+    }
+
+    let mut encoder = Encoder::new();
+    extra_data.encode(&mut encoder)?;
+
+    if encoder.len() > MAX_EXTRA_DATA_LENGTH {
+        return Err(encode::Error::InvalidSize(MAX_EXTRA_DATA_LENGTH));
+    }
+
+    Ok(encoder.to_string())
+}
+
+fn decode_extra_data<T: Message>(mid: u16, revision: u16, message_type: MessageType, extra_data: &str) -> decode::Result<T> {
+    if T::mid() != mid || T::message_type() != message_type || !supports_revision::<T>(revision) {
+        return Err(decode::Error::InvalidMessage { mid, revision });
+    }
+
+    decode::decode(extra_data.as_bytes())
+}
+
+impl MID0006rev1 {
+    /// Requests a MID without extra data.
+    pub fn new(requested_mid: u16, wanted_revision: u16) -> Self {
+        Self { requested_mid, wanted_revision, ..Default::default() }
+    }
+
+    /// Requests `wanted_revision` of the MID of the extra data, which must be of type
+    /// [`MessageType::RequestExtraData`] and apply to that revision.
+    pub fn with_extra_data<T: Message>(extra_data: &T, wanted_revision: u16) -> encode::Result<Self> {
+        let extra_data = encode_extra_data(extra_data, wanted_revision, MessageType::RequestExtraData)?;
+
+        Ok(Self {
+            requested_mid: T::mid(),
+            wanted_revision,
+            extra_data_length: extra_data.len() as u8,
+            extra_data,
+        })
+    }
+
+    /// Decodes the extra data, if it belongs to the requested MID and revision.
+    pub fn extra_data<T: Message>(&self) -> decode::Result<T> {
+        decode_extra_data(self.requested_mid, self.wanted_revision, MessageType::RequestExtraData, &self.extra_data)
+    }
+}
+
+impl MID0008rev1 {
+    /// Subscribes to a MID without extra data.
+    pub fn new(subscription_mid: u16, wanted_revision: u16) -> Self {
+        Self { subscription_mid, wanted_revision, ..Default::default() }
+    }
+
+    /// Subscribes to `wanted_revision` of the MID of the extra data, which must be of type
+    /// [`MessageType::SubscribeExtraData`] and apply to that revision.
+    pub fn with_extra_data<T: Message>(extra_data: &T, wanted_revision: u16) -> encode::Result<Self> {
+        let extra_data = encode_extra_data(extra_data, wanted_revision, MessageType::SubscribeExtraData)?;
+
+        Ok(Self {
+            subscription_mid: T::mid(),
+            wanted_revision,
+            extra_data_length: extra_data.len() as u16,
+            extra_data,
+        })
+    }
+
+    /// Decodes the extra data, if it belongs to the subscribed MID and revision.
+    pub fn extra_data<T: Message>(&self) -> decode::Result<T> {
+        decode_extra_data(self.subscription_mid, self.wanted_revision, MessageType::SubscribeExtraData, &self.extra_data)
+    }
+}
+
+impl MID0009rev1 {
+    /// Unsubscribes from a MID without extra data.
+    pub fn new(unsubscription_mid: u16, extra_data_revision: u16) -> Self {
+        Self { unsubscription_mid, extra_data_revision, ..Default::default() }
+    }
+
+    /// Unsubscribes from `revision` of the MID of the extra data, which must be of type
+    /// [`MessageType::UnsubscribeExtraData`] and apply to that revision.
+    pub fn with_extra_data<T: Message>(extra_data: &T, revision: u16) -> encode::Result<Self> {
+        let extra_data = encode_extra_data(extra_data, revision, MessageType::UnsubscribeExtraData)?;
+
+        Ok(Self {
+            unsubscription_mid: T::mid(),
+            extra_data_revision: revision,
+            extra_data_length: extra_data.len() as u16,
+            extra_data,
+        })
+    }
+
+    /// Decodes the extra data, if it belongs to the unsubscribed MID and revision.
+    pub fn extra_data<T: Message>(&self) -> decode::Result<T> {
+        decode_extra_data(self.unsubscription_mid, self.extra_data_revision, MessageType::UnsubscribeExtraData, &self.extra_data)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use open_protocol_codec::{decode, encode};
+    use crate::messages::result::{MID1201RequestExtraData, MID1201SubscriptionExtraData};
+    use crate::messages::tightening_result::{MID0900SubscribeExtraData, MID0900UnsubscribeExtraData, TraceType};
+
+    #[test]
+    fn subscribe_mid1201_with_extra_data() {
+        let message = MID0008rev1::with_extra_data(&MID1201SubscriptionExtraData { start_index: 42 }, 1).unwrap();
+
+        assert_eq!(encode::encode(&message), Ok("1201001100000000042".to_string()));
+        assert_eq!(message.extra_data::<MID1201SubscriptionExtraData>(), Ok(MID1201SubscriptionExtraData { start_index: 42 }));
+    }
+
+    #[test]
+    fn subscribe_mid1201rev3_with_extra_data() {
+        let message = MID0008rev1::with_extra_data(&MID1201SubscriptionExtraData { start_index: 42 }, 3).unwrap();
+
+        assert_eq!(encode::encode(&message), Ok("1201003100000000042".to_string()));
+        assert_eq!(message.extra_data::<MID1201SubscriptionExtraData>(), Ok(MID1201SubscriptionExtraData { start_index: 42 }));
+    }
+
+    #[test]
+    fn subscribe_unsupported_revision_with_extra_data() {
+        let result = MID0008rev1::with_extra_data(&MID1201SubscriptionExtraData { start_index: 42 }, 4);
+
+        assert_eq!(result, Err(encode::Error::InvalidMessage { mid: 1201, revision: 4 }));
+        assert_eq!(
+            MID0008rev1::new(1201, 4).extra_data::<MID1201SubscriptionExtraData>(),
+            Err(decode::Error::InvalidMessage { mid: 1201, revision: 4 })
+        );
+    }
+
+    #[test]
+    fn unsubscribe_mid0900rev2_with_extra_data() {
+        let extra_data = MID0900UnsubscribeExtraData { number_of_trace_types: 1, trace_types: vec![TraceType::Torque] };
+        let message = MID0009rev1::with_extra_data(&extra_data, 2).unwrap();
+
+        assert_eq!(encode::encode(&message), Ok("090000206001002".to_string()));
+        assert_eq!(message.extra_data(), Ok(extra_data));
+    }
+
+    #[test]
+    fn subscribe_with_wrong_extra_data_type() {
+        let result = MID0008rev1::with_extra_data(&MID1201RequestExtraData { index: 42 }, 1);
+
+        assert_eq!(result, Err(encode::Error::InvalidMessage { mid: 1201, revision: 1 }));
+    }
+
+    #[test]
+    fn extra_data_for_other_mid() {
+        let message = MID0008rev1::with_extra_data(&MID1201SubscriptionExtraData { start_index: 42 }, 1).unwrap();
+
+        assert_eq!(
+            message.extra_data::<MID0900SubscribeExtraData>(),
+            Err(decode::Error::InvalidMessage { mid: 1201, revision: 1 })
+        );
+    }
+
+    #[test]
+    fn roundtrip_mid0008rev1_mid0900_extra_data() {
+        let extra_data = MID0900SubscribeExtraData {
+            start_index: 0,
+            number_of_trace_types: 2,
+            trace_types: vec![TraceType::Angle, TraceType::Torque],
+            number_of_pids: 1,
+            pids: vec![2213],
+        };
+        let message = MID0008rev1::with_extra_data(&extra_data, 1).unwrap();
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(encoded, "090000127000000000000200100200102213");
+        assert_eq!(decode::decode::<MID0008rev1>(encoded.as_bytes()).unwrap().extra_data(), Ok(extra_data));
+    }
 
     #[test]
     fn encode_mid0001rev7_with_keep_alive() {
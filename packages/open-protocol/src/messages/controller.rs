@@ -0,0 +1,9 @@
+use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+
+/// Requests the controller to reboot. The controller answers with MID 0005 Command accepted
+/// before rebooting, or with MID 0004 Command error if it cannot reboot right now.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 270, revision = 1)]
+pub struct MID0270rev1 {
+    // No additional fields for this MID.
+}
@@ -0,0 +1,427 @@
+use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+use crate::messages::parameter_set::RelayStatus;
+
+/// Status to set an externally controlled relay to.
+#[derive(Debug, Eq, PartialEq, Default, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum ExternalRelayStatus {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Off,
+    #[open_protocol_value(number = 1)]
+    On,
+    #[open_protocol_value(number = 2)]
+    Flashing,
+    #[open_protocol_value(number = 3)]
+    KeepCurrentStatus,
+}
+
+#[derive(Debug, Eq, PartialEq, Default, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum DigitalInputStatus {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Low,
+    #[open_protocol_value(number = 1)]
+    High,
+}
+
+/// The function of a relay, as configured in the controller.
+#[derive(Debug, Eq, PartialEq, Default, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum RelayFunction {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Off,
+    #[open_protocol_value(number = 1)]
+    Ok,
+    #[open_protocol_value(number = 2)]
+    Nok,
+    #[open_protocol_value(number = 3)]
+    LowTorque,
+    #[open_protocol_value(number = 4)]
+    HighTorque,
+    #[open_protocol_value(number = 5)]
+    LowAngle,
+    #[open_protocol_value(number = 6)]
+    HighAngle,
+    #[open_protocol_value(number = 7)]
+    CycleComplete,
+    #[open_protocol_value(number = 8)]
+    Alarm,
+    #[open_protocol_value(number = 9)]
+    BatchNxOk,
+    #[open_protocol_value(number = 10)]
+    JobOk,
+    #[open_protocol_value(number = 11)]
+    JobNok,
+    #[open_protocol_value(number = 12)]
+    JobRunning,
+    #[open_protocol_value(other)]
+    Other(u16),
+}
+
+/// The function of a digital input, as configured in the controller. Functions not listed
+/// here, such as the parameter set and job selection bits, are decoded as `Other`.
+#[derive(Debug, Eq, PartialEq, Default, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum DigitalInputFunction {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Off,
+    #[open_protocol_value(number = 1)]
+    BatchIncrement,
+    #[open_protocol_value(number = 2)]
+    BatchDecrement,
+    #[open_protocol_value(number = 3)]
+    ResetBatch,
+    #[open_protocol_value(number = 4)]
+    BypassParameterSet,
+    #[open_protocol_value(number = 5)]
+    AbortJob,
+    #[open_protocol_value(number = 6)]
+    ResetJob,
+    #[open_protocol_value(number = 7)]
+    JobOff,
+    #[open_protocol_value(number = 8)]
+    ToolEnable,
+    #[open_protocol_value(number = 9)]
+    ToolDisableNormallyOpen,
+    #[open_protocol_value(number = 10)]
+    ToolDisableNormallyClosed,
+    #[open_protocol_value(number = 11)]
+    RemoteStart,
+    #[open_protocol_value(number = 12)]
+    RemoteStartPulse,
+    #[open_protocol_value(number = 13)]
+    UnlockToolOnDisable,
+    #[open_protocol_value(number = 14)]
+    DisableFieldbusSignals,
+    #[open_protocol_value(number = 15)]
+    AcknowledgeError,
+    #[open_protocol_value(number = 16)]
+    ResetRelays,
+    #[open_protocol_value(number = 17)]
+    ResetNok,
+    #[open_protocol_value(number = 18)]
+    SetHomePosition,
+    #[open_protocol_value(number = 19)]
+    SelectNextParameterSet,
+    #[open_protocol_value(number = 20)]
+    SelectPreviousParameterSet,
+    #[open_protocol_value(other)]
+    Other(u16),
+}
+
+/// Sets the status of the externally controlled relays.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 200, revision = 1)]
+pub struct MID0200rev1 {
+    #[open_protocol_field(number = 1, length = 1)]
+    pub relay_1: ExternalRelayStatus,
+    #[open_protocol_field(number = 2, length = 1)]
+    pub relay_2: ExternalRelayStatus,
+    #[open_protocol_field(number = 3, length = 1)]
+    pub relay_3: ExternalRelayStatus,
+    #[open_protocol_field(number = 4, length = 1)]
+    pub relay_4: ExternalRelayStatus,
+    #[open_protocol_field(number = 5, length = 1)]
+    pub relay_5: ExternalRelayStatus,
+    #[open_protocol_field(number = 6, length = 1)]
+    pub relay_6: ExternalRelayStatus,
+    #[open_protocol_field(number = 7, length = 1)]
+    pub relay_7: ExternalRelayStatus,
+    #[open_protocol_field(number = 8, length = 1)]
+    pub relay_8: ExternalRelayStatus,
+    #[open_protocol_field(number = 9, length = 1)]
+    pub relay_9: ExternalRelayStatus,
+    #[open_protocol_field(number = 10, length = 1)]
+    pub relay_10: ExternalRelayStatus,
+}
+
+/// A subscription for the status of the externally monitored inputs.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 210, revision = 1)]
+pub struct MID0210rev1 {
+    // No additional fields for this MID.
+}
+
+/// Sent each time the status of an externally monitored input changes.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 211, revision = 1, direction = "to_integrator", ack = 212)]
+pub struct MID0211rev1 {
+    #[open_protocol_field(number = 1, length = 1)]
+    pub digital_input_1: DigitalInputStatus,
+    #[open_protocol_field(number = 2, length = 1)]
+    pub digital_input_2: DigitalInputStatus,
+    #[open_protocol_field(number = 3, length = 1)]
+    pub digital_input_3: DigitalInputStatus,
+    #[open_protocol_field(number = 4, length = 1)]
+    pub digital_input_4: DigitalInputStatus,
+    #[open_protocol_field(number = 5, length = 1)]
+    pub digital_input_5: DigitalInputStatus,
+    #[open_protocol_field(number = 6, length = 1)]
+    pub digital_input_6: DigitalInputStatus,
+    #[open_protocol_field(number = 7, length = 1)]
+    pub digital_input_7: DigitalInputStatus,
+    #[open_protocol_field(number = 8, length = 1)]
+    pub digital_input_8: DigitalInputStatus,
+}
+
+/// Acknowledges receipt of MID 0211 Status externally monitored inputs.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 212, revision = 1, reply = "none")]
+pub struct MID0212rev1 {
+    // No additional fields for this MID.
+}
+
+/// Cancels a previously subscribed status of externally monitored inputs.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 213, revision = 1)]
+pub struct MID0213rev1 {
+    // No additional fields for this MID.
+}
+
+/// Requests the status of the relays and digital inputs of an I/O device.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 214, revision = 1, reply = 215)]
+pub struct MID0214rev1 {
+    /// The number of the I/O device.
+    #[open_protocol_field(length = 2)]
+    pub io_device_number: u8,
+}
+
+/// The status of the relays and digital inputs of an I/O device.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 215, revision = 1, direction = "to_integrator")]
+pub struct MID0215rev1 {
+    /// The number of the I/O device.
+    #[open_protocol_field(number = 1, length = 2)]
+    pub io_device_number: u8,
+
+    /// Function and status of the 8 relays of the device.
+    #[open_protocol_field(number = 2, list, amount = 8, length = 4)]
+    pub relays: Vec<RelayState>,
+
+    /// Function and status of the 8 digital inputs of the device.
+    #[open_protocol_field(number = 3, list, amount = 8, length = 4)]
+    pub digital_inputs: Vec<DigitalInputState>,
+}
+
+/// The status of the relays and digital inputs of an I/O device.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 215, revision = 2, direction = "to_integrator")]
+pub struct MID0215rev2 {
+    /// The number of the I/O device.
+    #[open_protocol_field(number = 1, length = 2)]
+    pub io_device_number: u8,
+
+    /// The number of relays of the device.
+    #[open_protocol_field(number = 2, length = 2)]
+    pub number_of_relays: u8,
+
+    /// Function and status of each relay of the device.
+    #[open_protocol_field(number = 3, list, amount = "number_of_relays", length = 4)]
+    pub relays: Vec<RelayState>,
+
+    /// The number of digital inputs of the device.
+    #[open_protocol_field(number = 4, length = 2)]
+    pub number_of_digital_inputs: u8,
+
+    /// Function and status of each digital input of the device.
+    #[open_protocol_field(number = 5, list, amount = "number_of_digital_inputs", length = 4)]
+    pub digital_inputs: Vec<DigitalInputState>,
+}
+
+/// A subscription for the status of a single relay function.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 216, revision = 1)]
+pub struct MID0216rev1 {
+    /// The relay function to subscribe for.
+    #[open_protocol_field(length = 3)]
+    pub relay_number: RelayFunction,
+}
+
+/// Sent when the status of the subscribed relay function changes.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 217, revision = 1, direction = "to_integrator", ack = 218)]
+pub struct MID0217rev1 {
+    /// The relay function that changed.
+    #[open_protocol_field(number = 1, length = 3)]
+    pub relay_number: RelayFunction,
+
+    /// The new status of the relay.
+    #[open_protocol_field(number = 2, length = 1)]
+    pub relay_status: RelayStatus,
+}
+
+/// Acknowledges receipt of MID 0217 Relay function.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 218, revision = 1, reply = "none")]
+pub struct MID0218rev1 {
+    // No additional fields for this MID.
+}
+
+/// Cancels a previously subscribed relay function.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 219, revision = 1)]
+pub struct MID0219rev1 {
+    /// The relay function to unsubscribe from.
+    #[open_protocol_field(length = 3)]
+    pub relay_number: RelayFunction,
+}
+
+/// A subscription for the status of a single digital input function.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 220, revision = 1)]
+pub struct MID0220rev1 {
+    /// The digital input function to subscribe for.
+    #[open_protocol_field(length = 3)]
+    pub digital_input_number: DigitalInputFunction,
+}
+
+/// Sent when the status of the subscribed digital input function changes.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 221, revision = 1, direction = "to_integrator", ack = 222)]
+pub struct MID0221rev1 {
+    /// The digital input function that changed.
+    #[open_protocol_field(number = 1, length = 3)]
+    pub digital_input_number: DigitalInputFunction,
+
+    /// The new status of the digital input.
+    #[open_protocol_field(number = 2, length = 1)]
+    pub digital_input_status: DigitalInputStatus,
+}
+
+/// Acknowledges receipt of MID 0221 Digital input function.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 222, revision = 1, reply = "none")]
+pub struct MID0222rev1 {
+    // No additional fields for this MID.
+}
+
+/// Cancels a previously subscribed digital input function.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 223, revision = 1)]
+pub struct MID0223rev1 {
+    /// The digital input function to unsubscribe from.
+    #[open_protocol_field(length = 3)]
+    pub digital_input_number: DigitalInputFunction,
+}
+
+/// Sets a digital input function from the integrator, as if the physical input was high.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 224, revision = 1)]
+pub struct MID0224rev1 {
+    /// The digital input function to set.
+    #[open_protocol_field(length = 3)]
+    pub digital_input_number: DigitalInputFunction,
+}
+
+/// Resets a digital input function previously set with MID 0224.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 225, revision = 1)]
+pub struct MID0225rev1 {
+    /// The digital input function to reset.
+    #[open_protocol_field(length = 3)]
+    pub digital_input_number: DigitalInputFunction,
+}
+
+/// Function and status of a single relay of an I/O device.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub struct RelayState {
+    /// The function of the relay.
+    #[open_protocol_field(length = 3)]
+    pub relay_number: RelayFunction,
+
+    /// The status of the relay.
+    #[open_protocol_field(length = 1)]
+    pub relay_status: RelayStatus,
+}
+
+/// Function and status of a single digital input of an I/O device.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub struct DigitalInputState {
+    /// The function of the digital input.
+    #[open_protocol_field(length = 3)]
+    pub digital_input_number: DigitalInputFunction,
+
+    /// The status of the digital input.
+    #[open_protocol_field(length = 1)]
+    pub digital_input_status: DigitalInputStatus,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use open_protocol_codec::{decode, encode};
+
+    #[test]
+    fn encode_mid0200rev1() {
+        let message = MID0200rev1 {
+            relay_1: ExternalRelayStatus::On,
+            relay_2: ExternalRelayStatus::Flashing,
+            relay_10: ExternalRelayStatus::KeepCurrentStatus,
+            ..Default::default()
+        };
+
+        let encoded = encode::encode(&message);
+
+        assert_eq!(encoded, Ok("011022030040050060070080090103".to_string()));
+    }
+
+    #[test]
+    fn decode_mid0217rev1() {
+        let decoded = decode::decode::<MID0217rev1>("01002021".as_bytes());
+
+        assert_eq!(decoded, Ok(MID0217rev1 {
+            relay_number: RelayFunction::Nok,
+            relay_status: RelayStatus::Active,
+        }));
+    }
+
+    #[test]
+    fn decode_mid0217rev1_other_relay() {
+        let decoded = decode::decode::<MID0217rev1>("01250020".as_bytes());
+
+        assert_eq!(decoded, Ok(MID0217rev1 {
+            relay_number: RelayFunction::Other(250),
+            relay_status: RelayStatus::Inactive,
+        }));
+    }
+
+    #[test]
+    fn decode_mid0221rev1() {
+        let decoded = decode::decode::<MID0221rev1>("01003021".as_bytes());
+        assert_eq!(decoded, Ok(MID0221rev1 {
+            digital_input_number: DigitalInputFunction::ResetBatch,
+            digital_input_status: DigitalInputStatus::High,
+        }));
+
+        let decoded = decode::decode::<MID0221rev1>("01008020".as_bytes());
+        assert_eq!(decoded, Ok(MID0221rev1 {
+            digital_input_number: DigitalInputFunction::ToolEnable,
+            digital_input_status: DigitalInputStatus::Low,
+        }));
+
+        let decoded = decode::decode::<MID0221rev1>("01300021".as_bytes());
+        assert_eq!(decoded.map(|message| message.digital_input_number), Ok(DigitalInputFunction::Other(300)));
+    }
+
+    #[test]
+    fn roundtrip_mid0215rev2() {
+        let message = MID0215rev2 {
+            io_device_number: 1,
+            number_of_relays: 2,
+            relays: vec![
+                RelayState { relay_number: RelayFunction::Ok, relay_status: RelayStatus::Active },
+                RelayState { relay_number: RelayFunction::Nok, relay_status: RelayStatus::Inactive },
+            ],
+            number_of_digital_inputs: 1,
+            digital_inputs: vec![
+                DigitalInputState { digital_input_number: DigitalInputFunction::AbortJob, digital_input_status: DigitalInputStatus::High },
+            ],
+        };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(encoded, "0101020203001100200401050051");
+        assert_eq!(decode::decode::<MID0215rev2>(encoded.as_bytes()), Ok(message));
+    }
+}
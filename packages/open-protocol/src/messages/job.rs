@@ -1,9 +1,9 @@
-use chrono::{DateTime, Local};
+use chrono::NaiveDateTime;
 use open_protocol_codec::{decode, encode};
 use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0030, revision = 1)]
+#[open_protocol_message(MID = 0030, revision = 1, reply = 31)]
 pub struct MID0030rev1 {
     // No data field for this MID
 }
@@ -11,7 +11,7 @@ pub struct MID0030rev1 {
 #[derive(
     Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage,
 )]
-#[open_protocol_message(MID = 0031, revision = 1)]
+#[open_protocol_message(MID = 0031, revision = 1, direction = "to_integrator")]
 pub struct MID0031rev1 {
     /// Number of jobs available on the controller
     #[open_protocol_field(length = 2)]
@@ -23,7 +23,7 @@ pub struct MID0031rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0031, revision = 2)]
+#[open_protocol_message(MID = 0031, revision = 2, direction = "to_integrator")]
 pub struct MID0031rev2 {
     /// Number of jobs available on the controller
     #[open_protocol_field(length = 4)]
@@ -35,7 +35,7 @@ pub struct MID0031rev2 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0032, revision = 1)]
+#[open_protocol_message(MID = 0032, revision = 1, reply = 33)]
 pub struct MID0032rev1 {
     /// The ID of the requested job, 2 ASCII characters long
     #[open_protocol_field(length = 2)]
@@ -43,7 +43,7 @@ pub struct MID0032rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0032, revision = 2)]
+#[open_protocol_message(MID = 0032, revision = 2, reply = 33)]
 pub struct MID0032rev2 {
     /// The ID of the requested job, 4 ASCII characters long
     #[open_protocol_field(length = 4)]
@@ -51,7 +51,7 @@ pub struct MID0032rev2 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0033, revision = 1)]
+#[open_protocol_message(MID = 0033, revision = 1, direction = "to_integrator")]
 pub struct MID0033rev1 {
     /// The ID of the job
     #[open_protocol_field(number = 1, length = 2)]
@@ -107,13 +107,13 @@ pub struct MID0033rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0034, revision = 1)]
+#[open_protocol_message(MID = 0034, revision = 1, subscribes = 35)]
 pub struct MID0034rev1 {
     // No data field for this MID
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0035, revision = 1)]
+#[open_protocol_message(MID = 0035, revision = 1, direction = "to_integrator", ack = 36)]
 pub struct MID0035rev1 {
     /// ID of the job being executed
     #[open_protocol_field(number = 1, length = 2)]
@@ -137,11 +137,11 @@ pub struct MID0035rev1 {
 
     /// Timestamp of the job information
     #[open_protocol_field(number = 6, length = 19)]
-    pub timestamp: DateTime<Local>,
+    pub timestamp: NaiveDateTime,
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0036, revision = 1)]
+#[open_protocol_message(MID = 0036, revision = 1, reply = "none")]
 pub struct MID0036rev1 {
     // No data field for this MID
 }
@@ -236,7 +236,7 @@ impl encode::Encode for JobParameterRev1 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::TimeZone;
+    use chrono::NaiveDate;
     use open_protocol_codec::{decode, encode, message::Message};
 
     #[test]
@@ -268,7 +268,7 @@ mod tests {
             job_batch_mode: 0,
             job_batch_size: 8,
             job_batch_counter: 3,
-            timestamp: Local.with_ymd_and_hms(2001, 12, 1, 20, 12, 45).unwrap(),
+            timestamp: NaiveDate::from_ymd_opt(2001, 12, 1).unwrap().and_hms_opt(20, 12, 45).unwrap(),
         };
 
         let encoded = encode::encode(&message);
@@ -289,7 +289,7 @@ mod tests {
             job_batch_mode: 0,
             job_batch_size: 8,
             job_batch_counter: 3,
-            timestamp: Local.with_ymd_and_hms(2001, 12, 1, 20, 12, 45).unwrap(),
+            timestamp: NaiveDate::from_ymd_opt(2001, 12, 1).unwrap().and_hms_opt(20, 12, 45).unwrap(),
         };
 
         assert_eq!(decoded, Ok(obj));
@@ -0,0 +1,356 @@
+use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+use crate::messages::job::JobParameterRev1;
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum JobOffStatus {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    SetJobOff,
+    #[open_protocol_value(number = 1)]
+    ResetJobOff,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum ForcedOrder {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Free,
+    #[open_protocol_value(number = 1)]
+    Forced,
+    #[open_protocol_value(number = 2)]
+    FreeAndForced,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum ToolLoosening {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Enable,
+    #[open_protocol_value(number = 1)]
+    Disable,
+    #[open_protocol_value(number = 2)]
+    EnableOnlyOnNok,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum JobBatchMode {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    OnlyOk,
+    #[open_protocol_value(number = 1)]
+    OkAndNok,
+}
+
+/// Subscribe to the Job line control info, sent when a line control Job is started or alerted.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 120, revision = 1)]
+pub struct MID0120rev1 {
+    // No additional fields for this MID.
+}
+
+/// Job line control is started.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 121, revision = 1, direction = "to_integrator", ack = 125)]
+pub struct MID0121rev1 {
+    // No additional fields for this MID.
+}
+
+/// Job line control alert 1 is set.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 122, revision = 1, direction = "to_integrator", ack = 125)]
+pub struct MID0122rev1 {
+    // No additional fields for this MID.
+}
+
+/// Job line control alert 2 is set.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 123, revision = 1, direction = "to_integrator", ack = 125)]
+pub struct MID0123rev1 {
+    // No additional fields for this MID.
+}
+
+/// Job line control is done.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 124, revision = 1, direction = "to_integrator", ack = 125)]
+pub struct MID0124rev1 {
+    // No additional fields for this MID.
+}
+
+/// Acknowledges receipt of a Job line control info message (MID 0121-0124).
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 125, revision = 1, reply = "none")]
+pub struct MID0125rev1 {
+    // No additional fields for this MID.
+}
+
+/// Cancels a previously subscribed Job line control info.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 126, revision = 1)]
+pub struct MID0126rev1 {
+    // No additional fields for this MID.
+}
+
+/// Aborts the currently running Job, if there is one.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 127, revision = 1)]
+pub struct MID0127rev1 {
+    // No additional fields for this MID.
+}
+
+/// Increments the Job batch counter of the currently running Job.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 128, revision = 1)]
+pub struct MID0128rev1 {
+    // No additional fields for this MID.
+}
+
+/// Increments the Job batch counter for a specific channel and parameter set in the running Job.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 128, revision = 2)]
+pub struct MID0128rev2 {
+    /// The channel ID of the parameter set in the Job list.
+    #[open_protocol_field(number = 1, length = 2)]
+    pub channel_id: u8,
+
+    /// The parameter set ID in the Job list.
+    #[open_protocol_field(number = 2, length = 3)]
+    pub parameter_set_id: u16,
+}
+
+/// Decrements the batch counter of the last tightened parameter set in the running Job.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 129, revision = 1)]
+pub struct MID0129rev1 {
+    // No additional fields for this MID.
+}
+
+/// Decrements the batch counter for a specific channel and parameter set in the running Job.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 129, revision = 2)]
+pub struct MID0129rev2 {
+    /// The channel ID of the parameter set in the Job list.
+    #[open_protocol_field(number = 1, length = 2)]
+    pub channel_id: u8,
+
+    /// The parameter set ID in the Job list.
+    #[open_protocol_field(number = 2, length = 3)]
+    pub parameter_set_id: u16,
+}
+
+/// Sets or resets the Job off state of the controller.
+/// While Job off is set, no Job can be selected.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 130, revision = 1)]
+pub struct MID0130rev1 {
+    /// Job off status (0=Set Job off, 1=Reset Job off).
+    #[open_protocol_field(length = 1)]
+    pub job_off_status: JobOffStatus,
+}
+
+/// Sets the Job line control start, the line control Job is started immediately.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 131, revision = 1)]
+pub struct MID0131rev1 {
+    // No additional fields for this MID.
+}
+
+/// Sets the Job line control alert 1 on the running line control Job.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 132, revision = 1)]
+pub struct MID0132rev1 {
+    // No additional fields for this MID.
+}
+
+/// Sets the Job line control alert 2 on the running line control Job.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 133, revision = 1)]
+pub struct MID0133rev1 {
+    // No additional fields for this MID.
+}
+
+/// Downloads and starts a dynamic Job. The Job is not stored in the controller and is only
+/// run once.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 140, revision = 1)]
+pub struct MID0140rev1 {
+    /// The ID of the dynamic Job, 99 is reserved for dynamic Jobs
+    #[open_protocol_field(number = 1, length = 2)]
+    pub job_id: u8,
+
+    /// The name of the Job, maximum 25 characters
+    #[open_protocol_field(number = 2, length = 25)]
+    pub job_name: String,
+
+    /// Number of parameter sets included in the Job
+    #[open_protocol_field(number = 3, length = 2)]
+    pub number_of_parameter_sets: u8,
+
+    /// List of parameter sets in the Job
+    #[open_protocol_field(number = 4, list, amount = "number_of_parameter_sets", length = 12)]
+    pub job_list: Vec<JobParameterRev1>,
+
+    /// Indicates the order type: 0=free, 1=forced, 2=free and forced
+    #[open_protocol_field(number = 5, length = 1)]
+    pub forced_order: ForcedOrder,
+
+    /// Indicates if the tool should be locked when the Job is done
+    #[open_protocol_field(number = 6, length = 1)]
+    pub lock_at_job_done: bool,
+
+    /// Tool loosening mode: 0=Enable, 1=Disable, 2=Enable only on NOK tightenings
+    #[open_protocol_field(number = 7, length = 1)]
+    pub tool_loosening: ToolLoosening,
+
+    /// Indicates if the Job is repeatable
+    #[open_protocol_field(number = 8, length = 1)]
+    pub repeat_job: bool,
+
+    /// Job batch mode: 0=Only OK tightenings counted, 1=Both OK and NOK tightenings counted
+    #[open_protocol_field(number = 9, length = 1)]
+    pub job_batch_mode: JobBatchMode,
+
+    /// Indicates if the batch status is set to OK when the batch is incremented
+    #[open_protocol_field(number = 10, length = 1)]
+    pub batch_status_at_increment: bool,
+
+    /// Indicates if the batch is decremented at an OK loosening
+    #[open_protocol_field(number = 11, length = 1)]
+    pub decrement_batch_at_ok_loosening: bool,
+
+    /// Maximum time allowed for the first tightening in the Job, in seconds
+    #[open_protocol_field(number = 12, length = 4)]
+    pub max_time_first_tightening: u16,
+
+    /// Maximum time allowed to complete the Job, in seconds
+    #[open_protocol_field(number = 13, length = 5)]
+    pub max_time_to_complete_job: u32,
+
+    /// Time the result is displayed at auto select, in seconds
+    #[open_protocol_field(number = 14, length = 4)]
+    pub display_result_at_auto_select: u16,
+
+    /// Indicates if line control is used
+    #[open_protocol_field(number = 15, length = 1)]
+    pub use_line_control: bool,
+
+    /// The identifier result part used by the Job
+    #[open_protocol_field(number = 16, length = 1)]
+    pub identifier_result_part: u8,
+
+    /// Indicates if the results of non-tightenings are reported
+    #[open_protocol_field(number = 17, length = 1)]
+    pub result_of_non_tightenings: bool,
+
+    /// Indicates if all identifiers are reset when the Job is done
+    #[open_protocol_field(number = 18, length = 1)]
+    pub reset_all_identifiers_at_job_done: bool,
+
+    /// Reserved for future use
+    #[open_protocol_field(number = 19, length = 1)]
+    pub reserved: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use open_protocol_codec::{decode, encode, message::Message};
+
+    #[test]
+    fn test_mid0127rev1() {
+        assert_eq!(MID0127rev1::mid(), 127);
+        assert_eq!(MID0127rev1::revision(), 1);
+        assert_eq!(encode::encode(&MID0127rev1 {}), Ok("".to_string()));
+    }
+
+    #[test]
+    fn encode_mid0129rev2() {
+        let message = MID0129rev2 { channel_id: 1, parameter_set_id: 12 };
+
+        let encoded = encode::encode(&message);
+
+        assert_eq!(encoded, Ok("010102012".to_string()));
+    }
+
+    #[test]
+    fn decode_mid0129rev2() {
+        let decoded = decode::decode::<MID0129rev2>("010102012".as_bytes());
+
+        assert_eq!(decoded, Ok(MID0129rev2 { channel_id: 1, parameter_set_id: 12 }));
+    }
+
+    #[test]
+    fn roundtrip_mid0130rev1() {
+        let message = MID0130rev1 { job_off_status: JobOffStatus::ResetJobOff };
+
+        let encoded = encode::encode(&message).unwrap();
+        let decoded = decode::decode::<MID0130rev1>(encoded.as_bytes());
+
+        assert_eq!(encoded, "1");
+        assert_eq!(decoded, Ok(message));
+    }
+
+    #[test]
+    fn roundtrip_mid0140rev1() {
+        let message = MID0140rev1 {
+            job_id: 99,
+            job_name: "Dynamic".into(),
+            number_of_parameter_sets: 2,
+            job_list: vec![
+                JobParameterRev1 { channel_id: 1, program_id: 10, auto_select: true, batch_size: 4 },
+                JobParameterRev1 { channel_id: 2, program_id: 11, auto_select: false, batch_size: 2 },
+            ],
+            forced_order: ForcedOrder::Forced,
+            lock_at_job_done: true,
+            tool_loosening: ToolLoosening::EnableOnlyOnNok,
+            repeat_job: false,
+            job_batch_mode: JobBatchMode::OkAndNok,
+            batch_status_at_increment: false,
+            decrement_batch_at_ok_loosening: true,
+            max_time_first_tightening: 30,
+            max_time_to_complete_job: 600,
+            display_result_at_auto_select: 5,
+            use_line_control: false,
+            identifier_result_part: 1,
+            result_of_non_tightenings: false,
+            reset_all_identifiers_at_job_done: true,
+            reserved: 0,
+        };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(
+            encoded,
+            "0199\
+             02Dynamic                  \
+             0302\
+             0401:010:1:04;02:011:0:02;\
+             051\
+             061\
+             072\
+             080\
+             091\
+             100\
+             111\
+             120030\
+             1300600\
+             140005\
+             150\
+             161\
+             170\
+             181\
+             190"
+        );
+        assert_eq!(decode::decode::<MID0140rev1>(encoded.as_bytes()), Ok(message));
+    }
+
+    #[test]
+    fn encode_mid0140rev1_short_job_list() {
+        let message = MID0140rev1 {
+            number_of_parameter_sets: 2,
+            job_list: vec![JobParameterRev1 { channel_id: 1, program_id: 10, auto_select: true, batch_size: 4 }],
+            ..Default::default()
+        };
+
+        assert_eq!(encode::encode(&message), Err(encode::Error::InvalidListLength { expected: 2, actual: 1 }));
+    }
+}
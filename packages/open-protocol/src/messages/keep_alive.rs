@@ -1,5 +1,5 @@
 use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 9999, revision = 1)]
+#[open_protocol_message(MID = 9999, revision = 1, direction = "both", reply = 9999)]
 pub struct MID9999rev1 {}
@@ -1,14 +1,14 @@
-use chrono::{DateTime, Local};
+use chrono::NaiveDateTime;
 use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 2600, revision = 1)]
+#[open_protocol_message(MID = 2600, revision = 1, reply = 2601)]
 pub struct MID2600rev1 {
 }
 
 /// Contains the list of all modes in the controller.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 2601, revision = 1)]
+#[open_protocol_message(MID = 2601, revision = 1, direction = "to_integrator")]
 pub struct MID2601rev1 {
     /// Number of modes in the controller
     #[open_protocol_field(length = 3)]
@@ -37,7 +37,7 @@ pub struct ModeData {
 
 /// Requests detailed mode data for a specific mode ID.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 2602, revision = 1)]
+#[open_protocol_message(MID = 2602, revision = 1, reply = 2603)]
 pub struct MID2602rev1 {
     /// Mode ID of the mode to request
     #[open_protocol_field(number = 1, length = 4)]
@@ -46,7 +46,7 @@ pub struct MID2602rev1 {
 
 /// Provides detailed information about a mode, including its bolts.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 2603, revision = 1)]
+#[open_protocol_message(MID = 2603, revision = 1, direction = "to_integrator")]
 pub struct MID2603rev1 {
     /// Mode ID
     #[open_protocol_field(length = 4)]
@@ -95,7 +95,7 @@ pub struct BoltData {
 
 /// Confirms mode selection and provides metadata.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 2604, revision = 1)]
+#[open_protocol_message(MID = 2604, revision = 1, direction = "to_integrator", ack = 2605)]
 pub struct MID2604rev1 {
     /// Mode ID of the selected mode
     #[open_protocol_field(number = 1, length = 4)]
@@ -103,7 +103,7 @@ pub struct MID2604rev1 {
 
     /// Date of the last change in the mode setting (format YYYY-MM-DD:HH:MM:SS)
     #[open_protocol_field(number = 2, length = 19)]
-    pub last_change_date: DateTime<Local>,
+    pub last_change_date: NaiveDateTime,
 
     /// Number of bolts in the mode
     #[open_protocol_field(number = 3, length = 3)]
@@ -112,7 +112,7 @@ pub struct MID2604rev1 {
 
 /// Acknowledgment of mode selection.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 2605, revision = 1)]
+#[open_protocol_message(MID = 2605, revision = 1, reply = "none")]
 pub struct MID2605rev1 {
 }
 
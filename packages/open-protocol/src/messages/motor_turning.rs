@@ -0,0 +1,55 @@
+use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+
+/// A subscription for the result of motor tunings.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 500, revision = 1)]
+pub struct MID0500rev1 {
+    // No additional fields for this MID.
+}
+
+/// Sent each time a motor tuning is finished.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 501, revision = 1, direction = "to_integrator", ack = 502)]
+pub struct MID0501rev1 {
+    /// Whether the motor tuning succeeded.
+    #[open_protocol_field(number = 1, length = 1)]
+    pub motor_tune_result: bool,
+}
+
+/// Acknowledges receipt of MID 0501 Motor tuning result data upload.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 502, revision = 1, reply = "none")]
+pub struct MID0502rev1 {
+    // No additional fields for this MID.
+}
+
+/// Cancels a previously subscribed motor tuning result.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 503, revision = 1)]
+pub struct MID0503rev1 {
+    // No additional fields for this MID.
+}
+
+/// Starts a new motor tuning. The controller answers with MID 0005 Command accepted, or with
+/// MID 0004 Command error if the tool is in use or the tuning failed to start.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 504, revision = 1)]
+pub struct MID0504rev1 {
+    // No additional fields for this MID.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use open_protocol_codec::{decode, encode};
+
+    #[test]
+    fn roundtrip_mid0501rev1() {
+        let message = MID0501rev1 { motor_tune_result: true };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(encoded, "011");
+        assert_eq!(decode::decode::<MID0501rev1>(encoded.as_bytes()), Ok(message));
+    }
+}
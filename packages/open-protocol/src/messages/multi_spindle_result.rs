@@ -1,5 +1,6 @@
-use chrono::{DateTime, Local};
+use chrono::NaiveDateTime;
 use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+use crate::enums::status::{BatchStatus, OkNok};
 
 /// A subscription for the multi-spindle result.
 /// For Power Focus, the subscription must be addressed to the sync Master.
@@ -12,7 +13,7 @@ pub struct MID0100rev1 {
 /// This message is sent after each sync tightening.
 /// It contains the final result of the tightening for all spindles involved.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 101, revision = 1)]
+#[open_protocol_message(MID = 101, revision = 1, direction = "to_integrator", ack = 102)]
 pub struct MID0101rev1 {
     /// The number of spindles or presses involved in the tightening.
     #[open_protocol_field(length = 2)]
@@ -28,7 +29,7 @@ pub struct MID0101rev1 {
 
     /// The overall status of the sync tightening (1=OK, 0=NOK).
     #[open_protocol_field(length = 1)]
-    pub sync_overall_status: u8,
+    pub sync_overall_status: OkNok,
 
     /// The VIN (Vehicle Identification Number) associated with the tightening.
     #[open_protocol_field(length = 25)]
@@ -52,11 +53,11 @@ pub struct MID0101rev1 {
 
     /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running).
     #[open_protocol_field(length = 1)]
-    pub batch_status: u8,
+    pub batch_status: BatchStatus,
 
     /// Timestamp for the multi-spindle result (YYYY-MM-DD:HH:MM:SS).
     #[open_protocol_field(length = 19)]
-    pub timestamp: DateTime<Local>,
+    pub timestamp: NaiveDateTime,
 
     /// Status of each spindle in the multi-spindle tightening.
     #[open_protocol_field(list, amount = "number_of_spindles", length = 5)]
@@ -65,7 +66,7 @@ pub struct MID0101rev1 {
 
 /// Acknowledges receipt of MID 0101 Multi-Spindle Result.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 102, revision = 1)]
+#[open_protocol_message(MID = 102, revision = 1, reply = "none")]
 pub struct MID0102rev1 {
     // No additional fields for this MID.
 }
@@ -79,7 +80,7 @@ pub struct MID0103rev1 {
 
 /// Requests an old multi-spindle tightening result by its unique ID.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 104, revision = 1)]
+#[open_protocol_message(MID = 104, revision = 1, reply = 101)]
 pub struct MID0104rev1 {
     /// The ID of the requested old multi-spindle result.
     #[open_protocol_field(length = 10)]
@@ -99,5 +100,5 @@ pub struct SpindleResult {
 
     /// The overall status of the tightening for this spindle (0=NOK, 1=OK).
     #[open_protocol_field(length = 1)]
-    pub overall_status: u8,
+    pub overall_status: OkNok,
 }
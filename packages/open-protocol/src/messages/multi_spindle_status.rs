@@ -1,4 +1,4 @@
-use chrono::{DateTime, Local};
+use chrono::NaiveDateTime;
 use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
 
 /// A subscription for the multi-spindle status.
@@ -12,7 +12,7 @@ pub struct MID0090rev1 {
 /// The multi-spindle status is sent after each sync tightening.
 /// It contains both the common status of the multiple and the individual status of each spindle.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 91, revision = 1)]
+#[open_protocol_message(MID = 91, revision = 1, direction = "to_integrator", ack = 92)]
 pub struct MID0091rev1 {
     /// The number of spindles in the tightening.
     #[open_protocol_field(length = 2)]
@@ -28,7 +28,7 @@ pub struct MID0091rev1 {
 
     /// Timestamp for the multi-spindle status (YYYY-MM-DD:HH:MM:SS).
     #[open_protocol_field(length = 19)]
-    pub timestamp: DateTime<Local>,
+    pub timestamp: NaiveDateTime,
 
     /// The overall status of the sync tightening (1=OK, 0=NOK).
     #[open_protocol_field(length = 1)]
@@ -41,7 +41,7 @@ pub struct MID0091rev1 {
 
 /// Acknowledges receipt of MID 0091 Multi-Spindle Status.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 92, revision = 1)]
+#[open_protocol_message(MID = 92, revision = 1, reply = "none")]
 pub struct MID0092rev1 {
     // No additional fields for this MID.
 }
@@ -0,0 +1,170 @@
+use open_protocol_codec::{decode, encode};
+use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum IdentifierStatus {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    NotUsed,
+    #[open_protocol_value(number = 1)]
+    Accepted,
+    #[open_protocol_value(number = 2)]
+    Bypassed,
+    #[open_protocol_value(number = 3)]
+    Reset,
+}
+
+/// Used by the integrator to send an identifier to the controller. The controller decides
+/// which identifier result part (work order, part number, serial number, ...) the identifier
+/// belongs to, based on its configuration.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolMessage)]
+#[open_protocol_message(MID = 150, revision = 1)]
+pub struct MID0150rev1 {
+    /// The identifier, maximum 100 ASCII characters.
+    pub identifier_data: String,
+}
+
+/// A subscription for the multiple identifiers and result parts.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 151, revision = 1)]
+pub struct MID0151rev1 {
+    // No additional fields for this MID.
+}
+
+/// Sent when a new identifier is received by the controller, and contains the status of all
+/// four identifier result parts.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 152, revision = 1, direction = "to_integrator", ack = 153)]
+pub struct MID0152rev1 {
+    /// Status of the first identifier result part.
+    #[open_protocol_field(number = 1, length = 26)]
+    pub first_identifier: IdentifierResultPart,
+
+    /// Status of the second identifier result part.
+    #[open_protocol_field(number = 2, length = 26)]
+    pub second_identifier: IdentifierResultPart,
+
+    /// Status of the third identifier result part.
+    #[open_protocol_field(number = 3, length = 26)]
+    pub third_identifier: IdentifierResultPart,
+
+    /// Status of the fourth identifier result part.
+    #[open_protocol_field(number = 4, length = 26)]
+    pub fourth_identifier: IdentifierResultPart,
+}
+
+/// Acknowledges receipt of MID 0152 Multiple identifiers and result parts.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 153, revision = 1, reply = "none")]
+pub struct MID0153rev1 {
+    // No additional fields for this MID.
+}
+
+/// Cancels a previously subscribed multiple identifiers and result parts notification.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 154, revision = 1)]
+pub struct MID0154rev1 {
+    // No additional fields for this MID.
+}
+
+/// Bypasses the next identifier expected by the controller.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 155, revision = 1)]
+pub struct MID0155rev1 {
+    // No additional fields for this MID.
+}
+
+/// Resets the latest received identifier.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 156, revision = 1)]
+pub struct MID0156rev1 {
+    // No additional fields for this MID.
+}
+
+/// Resets all identifiers received by the controller.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 157, revision = 1)]
+pub struct MID0157rev1 {
+    // No additional fields for this MID.
+}
+
+/// A single identifier result part, together with its status.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub struct IdentifierResultPart {
+    /// The identifier, right-padded with spaces.
+    #[open_protocol_field(length = 25)]
+    pub identifier: String,
+
+    /// The status of the identifier result part.
+    #[open_protocol_field(length = 1)]
+    pub status: IdentifierStatus,
+}
+
+impl decode::Decode for MID0150rev1 {
+    fn decode(decoder: &mut decode::Decoder) -> decode::Result<Self> {
+        let identifier_data = String::decode(decoder)?;
+
+        Ok(Self { identifier_data })
+    }
+}
+
+impl encode::Encode for MID0150rev1 {
+    fn encode(&self, encoder: &mut encode::Encoder) -> encode::Result<()> {
+        if self.identifier_data.len() > 100 {
+            return Err(encode::Error::InvalidSize(100));
+        }
+
+        self.identifier_data.encode(encoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_mid0150rev1() {
+        let message = MID0150rev1 { identifier_data: "WO-2024-0001".into() };
+
+        let encoded = encode::encode(&message);
+
+        assert_eq!(encoded, Ok("WO-2024-0001".to_string()));
+    }
+
+    #[test]
+    fn encode_mid0150rev1_too_long() {
+        let message = MID0150rev1 { identifier_data: "X".repeat(101) };
+
+        let encoded = encode::encode(&message);
+
+        assert_eq!(encoded, Err(encode::Error::InvalidSize(100)));
+    }
+
+    #[test]
+    fn decode_mid0150rev1() {
+        let decoded = decode::decode::<MID0150rev1>("SN 12345\0".as_bytes());
+
+        assert_eq!(decoded, Ok(MID0150rev1 { identifier_data: "SN 12345".into() }));
+    }
+
+    #[test]
+    fn roundtrip_mid0152rev1() {
+        let message = MID0152rev1 {
+            first_identifier: IdentifierResultPart { identifier: "WO-1".into(), status: IdentifierStatus::Accepted },
+            second_identifier: IdentifierResultPart { identifier: "PN-2".into(), status: IdentifierStatus::Accepted },
+            third_identifier: IdentifierResultPart { identifier: "".into(), status: IdentifierStatus::Bypassed },
+            fourth_identifier: IdentifierResultPart::default(),
+        };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(
+            encoded,
+            "01WO-1                     1\
+             02PN-2                     1\
+             03                         2\
+             04                         0"
+        );
+        assert_eq!(decode::decode::<MID0152rev1>(encoded.as_bytes()), Ok(message));
+    }
+}
@@ -0,0 +1,48 @@
+use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+
+/// A subscription for the status of the digital input that disables Open Protocol commands.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 420, revision = 1)]
+pub struct MID0420rev1 {
+    // No additional fields for this MID.
+}
+
+/// Sent each time the digital input that disables Open Protocol commands changes. While the
+/// commands are disabled, the controller rejects every command with MID 0004 Command error.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 421, revision = 1, direction = "to_integrator", ack = 422)]
+pub struct MID0421rev1 {
+    /// Whether Open Protocol commands are disabled.
+    #[open_protocol_field(number = 1, length = 1)]
+    pub commands_disabled: bool,
+}
+
+/// Acknowledges receipt of MID 0421 Open Protocol commands disabled.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 422, revision = 1, reply = "none")]
+pub struct MID0422rev1 {
+    // No additional fields for this MID.
+}
+
+/// Cancels a previously subscribed Open Protocol commands disabled.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 423, revision = 1)]
+pub struct MID0423rev1 {
+    // No additional fields for this MID.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use open_protocol_codec::{decode, encode};
+
+    #[test]
+    fn roundtrip_mid0421rev1() {
+        let message = MID0421rev1 { commands_disabled: true };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(encoded, "011");
+        assert_eq!(decode::decode::<MID0421rev1>(encoded.as_bytes()), Ok(message));
+    }
+}
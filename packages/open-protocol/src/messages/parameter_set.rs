@@ -19,12 +19,12 @@ pub enum RelayStatus {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0010, revision = 1)]
+#[open_protocol_message(MID = 0010, revision = 1, reply = 11)]
 pub struct MID0010rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0011, revision = 1)]
+#[open_protocol_message(MID = 0011, revision = 1, direction = "to_integrator")]
 pub struct MID0011rev1 {
     /// Three ASCII digits for the number of parameter sets
     #[open_protocol_field(length = 3)]
@@ -36,7 +36,7 @@ pub struct MID0011rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0011, revision = 2)]
+#[open_protocol_message(MID = 0011, revision = 2, direction = "to_integrator")]
 pub struct MID0011rev2 {
     /// Three ASCII digits for the number of parameter sets
     #[open_protocol_field(length = 3)]
@@ -52,7 +52,7 @@ pub struct MID0011rev2 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0011, revision = 3)]
+#[open_protocol_message(MID = 0011, revision = 3, direction = "to_integrator")]
 pub struct MID0011rev3 {
     /// Three ASCII digits for the number of parameter sets
     #[open_protocol_field(length = 3)]
@@ -72,7 +72,7 @@ pub struct MID0011rev3 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0011, revision = 4)]
+#[open_protocol_message(MID = 0011, revision = 4, direction = "to_integrator")]
 pub struct MID0011rev4 {
     /// Three ASCII digits for the number of parameter sets
     #[open_protocol_field(length = 3)]
@@ -96,7 +96,7 @@ pub struct MID0011rev4 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0012, revision = 1)]
+#[open_protocol_message(MID = 0012, revision = 1, reply = 13)]
 pub struct MID0012rev1 {
 
     /// Three ASCII digits for the parameter set ID
@@ -105,7 +105,7 @@ pub struct MID0012rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0013, revision = 1)]
+#[open_protocol_message(MID = 0013, revision = 1, direction = "to_integrator")]
 pub struct MID0013rev1 {
     /// Three ASCII digits for the parameter set ID
     #[open_protocol_field(number = 1, length = 3)]
@@ -149,7 +149,7 @@ pub struct MID0013rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0013, revision = 2)]
+#[open_protocol_message(MID = 0013, revision = 2, direction = "to_integrator")]
 pub struct MID0013rev2 {
     /// Three ASCII digits for the parameter set ID
     #[open_protocol_field(number = 1, length = 3)]
@@ -201,12 +201,17 @@ pub struct MID0013rev2 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0014, revision = 1)]
+#[open_protocol_message(MID = 0014, revision = 1, subscribes = 15)]
 pub struct MID0014rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0015, revision = 1)]
+#[open_protocol_message(MID = 0014, revision = 2, subscribes = 15)]
+pub struct MID0014rev2 {
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0015, revision = 1, direction = "to_integrator", ack = 16)]
 pub struct MID0015rev1 {
     /// Three ASCII digits for the parameter set ID
     #[open_protocol_field(number = 1, length = 3)]
@@ -218,7 +223,7 @@ pub struct MID0015rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0015, revision = 2)]
+#[open_protocol_message(MID = 0015, revision = 2, direction = "to_integrator", ack = 16)]
 pub struct MID0015rev2 {
     /// Three ASCII digits for the parameter set ID (000-999)
     #[open_protocol_field(number = 1, length = 3)]
@@ -274,7 +279,7 @@ pub struct MID0015rev2 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0016, revision = 1)]
+#[open_protocol_message(MID = 0016, revision = 1, reply = "none")]
 pub struct MID0016rev1 {
 }
 
@@ -325,12 +330,12 @@ pub struct MID0020rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0021, revision = 1)]
+#[open_protocol_message(MID = 0021, revision = 1, subscribes = 22)]
 pub struct MID0021rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0022, revision = 1)]
+#[open_protocol_message(MID = 0022, revision = 1, direction = "to_integrator", ack = 23)]
 pub struct MID0022rev1 {
 
     /// One ASCII digit representing the relay status (0 = inactive, 1 = active)
@@ -338,8 +343,110 @@ pub struct MID0022rev1 {
     pub relay_status: RelayStatus,
 }
 
+/// A program in the program catalog of the controller.
+#[derive(Debug, Default, Clone, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub struct ProgramCatalogEntry {
+    /// Four ASCII digits for the program ID
+    #[open_protocol_field(length = 4)]
+    pub program_id: u32,
+
+    /// Three ASCII digits for the node type (e.g., 201 for a multistep tightening program)
+    #[open_protocol_field(length = 3)]
+    pub node_type: u16,
+
+    /// 25 ASCII characters for the program name
+    #[open_protocol_field(length = 25)]
+    pub program_name: String,
+}
+
+/// Requests the program catalog of the controller, answered by MID 2501.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 2500, revision = 1, reply = 2501)]
+pub struct MID2500rev1 {
+}
+
+/// The program catalog of the controller.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 2501, revision = 1, direction = "to_integrator")]
+pub struct MID2501rev1 {
+
+    /// Four ASCII digits for the number of programs in the catalog
+    #[open_protocol_field(number = 1, length = 4)]
+    pub number_of_programs: u16,
+
+    /// The programs in the catalog, 32 ASCII characters each
+    #[open_protocol_field(number = 2, list, amount = "number_of_programs", length = 32)]
+    pub programs: Vec<ProgramCatalogEntry>,
+}
+
+/// Requests the data of a program, answered by MID 2503.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 2502, revision = 1, reply = 2503)]
+pub struct MID2502rev1 {
+
+    /// Four ASCII digits for the program ID. A zero value is illegal.
+    #[open_protocol_field(number = 1, length = 4)]
+    pub program_id: u32,
+
+    /// Three ASCII digits for the node type (e.g., 201 for a multistep tightening program)
+    #[open_protocol_field(number = 2, length = 3)]
+    pub node_type: u16,
+}
+
+/// The data of a program, uploaded from the controller.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 2503, revision = 1, direction = "to_integrator")]
+pub struct MID2503rev1 {
+
+    /// Four ASCII digits for the program ID
+    #[open_protocol_field(number = 1, length = 4)]
+    pub program_id: u32,
+
+    /// Three ASCII digits for the node type (e.g., 201 for a multistep tightening program)
+    #[open_protocol_field(number = 2, length = 3)]
+    pub node_type: u16,
+
+    /// The program data, running until the end of the message
+    #[open_protocol_field(number = 3)]
+    pub program_data: String,
+}
+
+/// Downloads the data of a program to the controller, creating or replacing the program.
+/// The controller answers with MID 0005 or MID 0004.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 2504, revision = 1)]
+pub struct MID2504rev1 {
+
+    /// Four ASCII digits for the program ID. A zero value is illegal.
+    #[open_protocol_field(number = 1, length = 4)]
+    pub program_id: u32,
+
+    /// Three ASCII digits for the node type (e.g., 201 for a multistep tightening program)
+    #[open_protocol_field(number = 2, length = 3)]
+    pub node_type: u16,
+
+    /// The program data, running until the end of the message
+    #[open_protocol_field(number = 3)]
+    pub program_data: String,
+}
+
+/// Selects a program by its program ID. The controller answers with MID 0005 or MID 0004.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 2505, revision = 1)]
+pub struct MID2505rev1 {
+
+    /// Four ASCII digits for the program ID. A zero value is illegal.
+    #[open_protocol_field(number = 1, length = 4)]
+    pub program_id: u32,
+
+    /// Three ASCII digits for the node type (e.g., 201 for a multistep tightening program)
+    #[open_protocol_field(number = 2, length = 3)]
+    pub node_type: u16,
+}
+
+/// Parameter set selected with program ID. Sent when a new program is selected in the controller.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 2506, revision = 1)]
+#[open_protocol_message(MID = 2506, revision = 1, direction = "to_integrator")]
 pub struct MID2506rev1 {
 
     /// Four ASCII digits for the program ID. A zero value is illegal.
@@ -397,4 +504,35 @@ mod tests {
             parameter_set_ids: vec![1, 2, 3, 10, 20],
         }));
     }
+
+    #[test]
+    fn roundtrip_mid2501rev1() {
+        let message = MID2501rev1 {
+            number_of_programs: 2,
+            programs: vec![
+                ProgramCatalogEntry { program_id: 1, node_type: 201, program_name: "Wheel nut".into() },
+                ProgramCatalogEntry { program_id: 2, node_type: 201, program_name: "Seat bolt".into() },
+            ],
+        };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(
+            encoded,
+            "010002\
+             020001201Wheel nut                \
+             0002201Seat bolt                "
+        );
+        assert_eq!(decode::decode::<MID2501rev1>(encoded.as_bytes()), Ok(message));
+    }
+
+    #[test]
+    fn roundtrip_mid2504rev1() {
+        let message = MID2504rev1 { program_id: 12, node_type: 201, program_data: "<program/>".into() };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(encoded, "0100120220103<program/>");
+        assert_eq!(decode::decode::<MID2504rev1>(format!("{encoded}\0").as_bytes()), Ok(message));
+    }
 }
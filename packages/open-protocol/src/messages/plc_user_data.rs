@@ -0,0 +1,253 @@
+use std::ops::Deref;
+use open_protocol_codec::decode::{self, Decode, Decoder};
+use open_protocol_codec::encode::{self, Encode, Encoder};
+use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+
+/// Maximum number of bytes of user data that fit in the fieldbus area of the controller.
+pub const MAX_USER_DATA_LENGTH: usize = 200;
+
+/// User data exchanged with the fieldbus area of the controller.
+/// On the wire each byte is sent as two ASCII hexadecimal characters.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct UserData(Vec<u8>);
+
+impl UserData {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// The ASCII hexadecimal representation, as sent on the wire.
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{:02X}", byte)).collect()
+    }
+
+    /// Parses user data from its ASCII hexadecimal representation.
+    pub fn from_hex(hex: &str) -> decode::Result<Self> {
+        let mut decoder = Decoder::from(hex);
+        Self::decode_hex(&mut decoder, hex.len())
+    }
+
+    fn decode_hex(decoder: &mut Decoder, size: usize) -> decode::Result<Self> {
+        if !size.is_multiple_of(2) {
+            return Err(decode::Error::SizeMismatch { requested_size: size, type_name: "UserData".into() });
+        }
+
+        let mut bytes = Vec::with_capacity(size / 2);
+        for _ in 0..(size / 2) {
+            let hi = read_hex_digit(decoder)?;
+            let lo = read_hex_digit(decoder)?;
+            bytes.push(hi * 16 + lo);
+        }
+
+        Ok(Self(bytes))
+    }
+}
+
+fn read_hex_digit(decoder: &mut Decoder) -> decode::Result<u8> {
+    let c = char::decode(decoder)?;
+    c.to_digit(16)
+        .map(|digit| digit as u8)
+        .ok_or(decode::Error::InvalidCharacter(c, decoder.pos() - 1))
+}
+
+impl From<Vec<u8>> for UserData {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&[u8]> for UserData {
+    fn from(value: &[u8]) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+impl Deref for UserData {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Encode for UserData {
+    fn encode(&self, encoder: &mut Encoder) -> encode::Result<()> {
+        if self.0.len() > MAX_USER_DATA_LENGTH {
+            return Err(encode::Error::InvalidSize(MAX_USER_DATA_LENGTH * 2));
+        }
+
+        encoder.write_bytes(self.to_hex().as_bytes())
+    }
+
+    fn encode_sized(&self, encoder: &mut Encoder, size: usize) -> encode::Result<()> {
+        if self.0.len() * 2 != size {
+            return Err(encode::Error::InvalidSize(size));
+        }
+
+        self.encode(encoder)
+    }
+}
+
+/// Unsized user data runs until the NUL character terminating the message.
+impl Decode for UserData {
+    fn decode(decoder: &mut Decoder) -> decode::Result<Self> {
+        let hex = String::decode(decoder)?;
+        decoder.back(hex.len())?;
+        Self::decode_hex(decoder, hex.len())
+    }
+
+    fn decode_sized(decoder: &mut Decoder, size: usize) -> decode::Result<Self> {
+        Self::decode_hex(decoder, size)
+    }
+}
+
+/// Sends user data to the fieldbus area of the controller.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolMessage)]
+#[open_protocol_message(MID = 240, revision = 1)]
+pub struct MID0240rev1 {
+    /// The user data, at most 200 bytes.
+    pub user_data: UserData,
+}
+
+/// A subscription for the user data set in the controller.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 241, revision = 1)]
+pub struct MID0241rev1 {
+    // No additional fields for this MID.
+}
+
+/// Sent each time the user data in the controller changes.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolMessage)]
+#[open_protocol_message(MID = 242, revision = 1, direction = "to_integrator", ack = 243)]
+pub struct MID0242rev1 {
+    /// The user data, at most 200 bytes.
+    pub user_data: UserData,
+}
+
+/// Acknowledges receipt of MID 0242 User data upload.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 243, revision = 1, reply = "none")]
+pub struct MID0243rev1 {
+    // No additional fields for this MID.
+}
+
+/// Cancels a previously subscribed user data upload.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 244, revision = 1)]
+pub struct MID0244rev1 {
+    // No additional fields for this MID.
+}
+
+/// Sends user data to the fieldbus area of the controller, starting at a byte offset.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 245, revision = 1)]
+pub struct MID0245rev1 {
+    /// Offset in bytes in the user data area where the data is written.
+    #[open_protocol_field(number = 1, length = 3)]
+    pub offset: u16,
+
+    /// The user data, at most 200 bytes including the offset.
+    #[open_protocol_field(number = 2)]
+    pub user_data: UserData,
+}
+
+impl Decode for MID0240rev1 {
+    fn decode(decoder: &mut Decoder) -> decode::Result<Self> {
+        Ok(Self { user_data: UserData::decode(decoder)? })
+    }
+}
+
+impl Encode for MID0240rev1 {
+    fn encode(&self, encoder: &mut Encoder) -> encode::Result<()> {
+        self.user_data.encode(encoder)
+    }
+}
+
+/// The offset is checked here, as the user data alone cannot tell whether it fits.
+impl Encode for MID0245rev1 {
+    fn encode(&self, encoder: &mut Encoder) -> encode::Result<()> {
+        if self.offset as usize + self.user_data.len() > MAX_USER_DATA_LENGTH {
+            return Err(encode::Error::InvalidSize(MAX_USER_DATA_LENGTH * 2));
+        }
+
+        encoder.write_numbered_sized_field(&self.offset, 1, 3)?;
+        encoder.write_numbered_field(&self.user_data, 2)
+    }
+}
+
+impl Decode for MID0242rev1 {
+    fn decode(decoder: &mut Decoder) -> decode::Result<Self> {
+        Ok(Self { user_data: UserData::decode(decoder)? })
+    }
+}
+
+impl Encode for MID0242rev1 {
+    fn encode(&self, encoder: &mut Encoder) -> encode::Result<()> {
+        self.user_data.encode(encoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_data_hex() {
+        let user_data = UserData::from(vec![0x01, 0xAB, 0xFF]);
+
+        assert_eq!(user_data.to_hex(), "01ABFF");
+        assert_eq!(UserData::from_hex("01abFF"), Ok(user_data));
+    }
+
+    #[test]
+    fn user_data_invalid_hex() {
+        assert_eq!(UserData::from_hex("0G"), Err(decode::Error::InvalidCharacter('G', 1)));
+        assert!(UserData::from_hex("012").is_err());
+    }
+
+    #[test]
+    fn encode_mid0240rev1() {
+        let message = MID0240rev1 { user_data: b"LINE1".as_slice().into() };
+
+        let encoded = encode::encode(&message);
+
+        assert_eq!(encoded, Ok("4C494E4531".to_string()));
+    }
+
+    #[test]
+    fn encode_mid0240rev1_too_long() {
+        let message = MID0240rev1 { user_data: vec![0; MAX_USER_DATA_LENGTH + 1].into() };
+
+        assert_eq!(encode::encode(&message), Err(encode::Error::InvalidSize(400)));
+    }
+
+    #[test]
+    fn decode_mid0242rev1() {
+        let decoded = decode::decode::<MID0242rev1>("DEADBEEF\0".as_bytes());
+
+        assert_eq!(decoded.map(|m| m.user_data.as_bytes().to_vec()), Ok(vec![0xDE, 0xAD, 0xBE, 0xEF]));
+    }
+
+    #[test]
+    fn roundtrip_mid0245rev1() {
+        let message = MID0245rev1 { offset: 10, user_data: vec![0x12, 0x34].into() };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(encoded, "01010021234");
+        assert_eq!(decode::decode::<MID0245rev1>(encoded.as_bytes()), Ok(message));
+    }
+
+    #[test]
+    fn encode_mid0245rev1_out_of_range() {
+        let fits = MID0245rev1 { offset: 198, user_data: vec![0x12, 0x34].into() };
+        let too_long = MID0245rev1 { offset: 199, user_data: vec![0x12, 0x34].into() };
+
+        assert!(encode::encode(&fits).is_ok());
+        assert_eq!(encode::encode(&too_long), Err(encode::Error::InvalidSize(400)));
+    }
+}
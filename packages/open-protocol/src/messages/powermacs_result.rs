@@ -1,4 +1,4 @@
-use chrono::{DateTime, Local};
+use chrono::NaiveDateTime;
 use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
 
 /// Subscribe to the last PowerMACS tightening result data.
@@ -11,7 +11,7 @@ pub struct MID0105rev1 {
 /// The last PowerMACS tightening result station data.
 /// This contains summary information about the tightening station.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 106, revision = 1)]
+#[open_protocol_message(MID = 106, revision = 1, direction = "to_integrator", ack = 108)]
 pub struct MID0106rev1 {
     /// The station number where the tightening occurred.
     #[open_protocol_field(length = 4)]
@@ -31,7 +31,7 @@ pub struct MID0106rev1 {
 
     /// The timestamp when the batch started.
     #[open_protocol_field(length = 19)]
-    pub batch_start_time: DateTime<Local>,
+    pub batch_start_time: NaiveDateTime,
 
     /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running).
     #[open_protocol_field(length = 1)]
@@ -45,7 +45,7 @@ pub struct MID0106rev1 {
 /// The last PowerMACS tightening result bolt data.
 /// This contains details about the individual bolts involved in the tightening.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 107, revision = 1)]
+#[open_protocol_message(MID = 107, revision = 1, direction = "to_integrator", ack = 108)]
 pub struct MID0107rev1 {
     /// The number of bolts included in the tightening operation.
     #[open_protocol_field(length = 3)]
@@ -74,7 +74,7 @@ pub struct BoltData {
 
 /// Acknowledge receipt of PowerMACS tightening result data.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 108, revision = 1)]
+#[open_protocol_message(MID = 108, revision = 1, reply = "none")]
 pub struct MID0108rev1 {
     // No additional fields for this MID.
 }
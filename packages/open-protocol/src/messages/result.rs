@@ -1,14 +1,151 @@
+use chrono::NaiveDateTime;
+use open_protocol_codec::message::Message;
 use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+use crate::enums::status::{OkNok, ResultType};
+use crate::types::datafield::DataField;
 
+/// An object (for example a bolt) of an operation result, with its own data fields.
+#[derive(Debug, Default, Clone, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub struct OperationObject {
+    /// The user defined object ID.
+    #[open_protocol_field(length = 4)]
+    pub object_id: u16,
+
+    /// The status of the object.
+    #[open_protocol_field(length = 1)]
+    pub status: OkNok,
+
+    /// The number of data fields of the object.
+    #[open_protocol_field(length = 3)]
+    pub number_of_data_fields: u16,
+
+    /// Data fields of the object.
+    #[open_protocol_field(list, amount = "number_of_data_fields")]
+    pub data_fields: Vec<DataField>,
+}
+
+/// 5.9.2 MID 1201 Operation result overall data
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 1201, revision = 1)]
-pub struct MID1201rev1 {}
+#[open_protocol_message(MID = 1201, revision = 1, direction = "to_integrator", ack = 1203)]
+pub struct MID1201rev1 {
+    /// The total number of messages (MID 1201 and MID 1202) of this result.
+    #[open_protocol_field(length = 3)]
+    pub total_messages: u16,
+
+    /// The number of this message within the result, starting at 1.
+    #[open_protocol_field(length = 3)]
+    pub message_number: u16,
+
+    /// The unique identifier for the result data.
+    #[open_protocol_field(length = 10)]
+    pub result_data_id: u32,
+
+    /// Timestamp of the operation.
+    #[open_protocol_field(length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// The number of objects in the operation.
+    #[open_protocol_field(length = 3)]
+    pub number_of_objects: u16,
+
+    /// The objects of the operation.
+    #[open_protocol_field(list, amount = "number_of_objects")]
+    pub objects: Vec<OperationObject>,
+
+    /// The number of overall data fields.
+    #[open_protocol_field(length = 3)]
+    pub number_of_data_fields: u16,
+
+    /// Overall data fields of the operation.
+    #[open_protocol_field(list, amount = "number_of_data_fields")]
+    pub data_fields: Vec<DataField>,
+}
+
+/// 5.9.2 MID 1201 Operation result overall data
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 1201, revision = 2)]
-pub struct MID1201rev2 {}
+#[open_protocol_message(MID = 1201, revision = 2, direction = "to_integrator", ack = 1203)]
+pub struct MID1201rev2 {
+    /// The total number of messages (MID 1201 and MID 1202) of this result.
+    #[open_protocol_field(length = 3)]
+    pub total_messages: u16,
+
+    /// The number of this message within the result, starting at 1.
+    #[open_protocol_field(length = 3)]
+    pub message_number: u16,
+
+    /// The unique identifier for the result data.
+    #[open_protocol_field(length = 10)]
+    pub result_data_id: u32,
+
+    /// Timestamp of the operation.
+    #[open_protocol_field(length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// The result type.
+    #[open_protocol_field(length = 2)]
+    pub result_type: ResultType,
+
+    /// The number of objects in the operation.
+    #[open_protocol_field(length = 3)]
+    pub number_of_objects: u16,
+
+    /// The objects of the operation.
+    #[open_protocol_field(list, amount = "number_of_objects")]
+    pub objects: Vec<OperationObject>,
+
+    /// The number of overall data fields.
+    #[open_protocol_field(length = 3)]
+    pub number_of_data_fields: u16,
+
+    /// Overall data fields of the operation.
+    #[open_protocol_field(list, amount = "number_of_data_fields")]
+    pub data_fields: Vec<DataField>,
+}
+
+/// 5.9.2 MID 1201 Operation result overall data
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 1201, revision = 3)]
-pub struct MID1201rev3 {}
+#[open_protocol_message(MID = 1201, revision = 3, direction = "to_integrator", ack = 1203)]
+pub struct MID1201rev3 {
+    /// The total number of messages (MID 1201 and MID 1202) of this result.
+    #[open_protocol_field(length = 3)]
+    pub total_messages: u16,
+
+    /// The number of this message within the result, starting at 1.
+    #[open_protocol_field(length = 3)]
+    pub message_number: u16,
+
+    /// The unique identifier for the result data.
+    #[open_protocol_field(length = 10)]
+    pub result_data_id: u32,
+
+    /// Timestamp of the operation.
+    #[open_protocol_field(length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// The result type.
+    #[open_protocol_field(length = 2)]
+    pub result_type: ResultType,
+
+    /// The sequence number of the result in the result database of the controller.
+    #[open_protocol_field(length = 10)]
+    pub sequence_number: u64,
+
+    /// The number of objects in the operation.
+    #[open_protocol_field(length = 3)]
+    pub number_of_objects: u16,
+
+    /// The objects of the operation.
+    #[open_protocol_field(list, amount = "number_of_objects")]
+    pub objects: Vec<OperationObject>,
+
+    /// The number of overall data fields.
+    #[open_protocol_field(length = 3)]
+    pub number_of_data_fields: u16,
+
+    /// Overall data fields of the operation.
+    #[open_protocol_field(list, amount = "number_of_data_fields")]
+    pub data_fields: Vec<DataField>,
+}
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
 #[open_protocol_message(MID = 1201, revision = 1, type = "request_extra_data")]
@@ -21,16 +158,186 @@ pub struct MID1201RequestExtraData {
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
 #[open_protocol_message(MID = 1201, revision = 1, type = "subscribe_extra_data")]
-pub struct MID1201SubscriptionExtraData {}
+pub struct MID1201SubscriptionExtraData {
+    /// The index of the first result to send. If equal to zero, only new results are sent.
+    #[open_protocol_field(length = 10)]
+    pub start_index: u64,
+}
 
+/// 5.9.3 MID 1202 Operation result object data
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 1202, revision = 1)]
-pub struct MID1202rev1 {}
+#[open_protocol_message(MID = 1202, revision = 1, direction = "to_integrator", ack = 1203)]
+pub struct MID1202rev1 {
+    /// The total number of messages (MID 1201 and MID 1202) of this result.
+    #[open_protocol_field(length = 3)]
+    pub total_messages: u16,
+
+    /// The number of this message within the result, starting at 1.
+    #[open_protocol_field(length = 3)]
+    pub message_number: u16,
+
+    /// The unique identifier for the result data, the same as in MID 1201.
+    #[open_protocol_field(length = 10)]
+    pub result_data_id: u32,
+
+    /// The user defined object ID.
+    #[open_protocol_field(length = 4)]
+    pub object_id: u16,
+
+    /// The number of step data fields.
+    #[open_protocol_field(length = 3)]
+    pub number_of_step_data_fields: u16,
+
+    /// Data fields per step, identified by their step number.
+    #[open_protocol_field(list, amount = "number_of_step_data_fields")]
+    pub step_data_fields: Vec<DataField>,
+}
 
+/// 5.9.3 MID 1202 Operation result object data
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 1202, revision = 2)]
-pub struct MID1202rev2 {}
+#[open_protocol_message(MID = 1202, revision = 2, direction = "to_integrator", ack = 1203)]
+pub struct MID1202rev2 {
+    /// The total number of messages (MID 1201 and MID 1202) of this result.
+    #[open_protocol_field(length = 3)]
+    pub total_messages: u16,
+
+    /// The number of this message within the result, starting at 1.
+    #[open_protocol_field(length = 3)]
+    pub message_number: u16,
+
+    /// The unique identifier for the result data, the same as in MID 1201.
+    #[open_protocol_field(length = 10)]
+    pub result_data_id: u32,
+
+    /// The user defined object ID.
+    #[open_protocol_field(length = 4)]
+    pub object_id: u16,
+
+    /// The number of step data fields.
+    #[open_protocol_field(length = 3)]
+    pub number_of_step_data_fields: u16,
+
+    /// Data fields per step, identified by their step number.
+    #[open_protocol_field(list, amount = "number_of_step_data_fields")]
+    pub step_data_fields: Vec<DataField>,
+
+    /// The number of bolt data fields.
+    #[open_protocol_field(length = 3)]
+    pub number_of_bolt_data_fields: u16,
+
+    /// Data fields for the bolt as a whole.
+    #[open_protocol_field(list, amount = "number_of_bolt_data_fields")]
+    pub bolt_data_fields: Vec<DataField>,
+}
 
+/// 5.9.4 MID 1203 Operation result data acknowledge. Sent for every MID 1201 and MID 1202.
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 1203, revision = 1)]
-pub struct MID1203rev1 {}
+#[open_protocol_message(MID = 1203, revision = 1, reply = "none")]
+pub struct MID1203rev1 {
+    /// The MID of the acknowledged message.
+    #[open_protocol_field(length = 4)]
+    pub acknowledged_mid: u16,
+
+    /// The unique identifier for the result data of the acknowledged message.
+    #[open_protocol_field(length = 10)]
+    pub result_data_id: u32,
+}
+
+macro_rules! impl_acknowledge {
+    ( $( $msg:ty ),* ) => {
+        $(
+            impl $msg {
+                /// The MID 1203 Operation result data acknowledge for this message.
+                pub fn acknowledge(&self) -> MID1203rev1 {
+                    MID1203rev1 { acknowledged_mid: Self::mid(), result_data_id: self.result_data_id }
+                }
+            }
+        )*
+    };
+}
+
+impl_acknowledge!(MID1201rev1, MID1201rev2, MID1201rev3, MID1202rev1, MID1202rev2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use open_protocol_codec::{decode, encode};
+    use crate::enums::datatype::DataType;
+
+    fn data_field(parameter_id: u32, step_no: Option<u16>, data_value: &str) -> DataField {
+        DataField {
+            parameter_id,
+            length: data_value.len() as u16,
+            data_type: DataType::UnsignedInteger,
+            unit: 1,
+            step_no,
+            data_value: data_value.into(),
+        }
+    }
+
+    #[test]
+    fn roundtrip_mid1201rev3() {
+        let message = MID1201rev3 {
+            total_messages: 2,
+            message_number: 1,
+            result_data_id: 42,
+            timestamp: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap().and_hms_opt(3, 4, 5).unwrap(),
+            result_type: ResultType::Tightening,
+            sequence_number: 1001,
+            number_of_objects: 1,
+            objects: vec![OperationObject {
+                object_id: 1,
+                status: OkNok::Ok,
+                number_of_data_fields: 1,
+                data_fields: vec![data_field(2213, None, "1250")],
+            }],
+            number_of_data_fields: 1,
+            data_fields: vec![data_field(20, None, "1")],
+        };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(
+            encoded,
+            "002001\
+             0000000042\
+             2024-01-02:03:04:05\
+             01\
+             0000001001\
+             001\
+             00011001022130040100100001250\
+             001\
+             000200010100100001"
+        );
+        assert_eq!(decode::decode::<MID1201rev3>(encoded.as_bytes()), Ok(message));
+    }
+
+    #[test]
+    fn roundtrip_mid1202rev2() {
+        let message = MID1202rev2 {
+            total_messages: 2,
+            message_number: 2,
+            result_data_id: 42,
+            object_id: 1,
+            number_of_step_data_fields: 2,
+            step_data_fields: vec![data_field(2213, Some(1), "0500"), data_field(2213, Some(2), "1250")],
+            number_of_bolt_data_fields: 1,
+            bolt_data_fields: vec![data_field(2214, None, "180")],
+        };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(decode::decode::<MID1202rev2>(encoded.as_bytes()), Ok(message));
+    }
+
+    #[test]
+    fn acknowledge_mid1202rev1() {
+        let message = MID1202rev1 { result_data_id: 42, ..Default::default() };
+
+        let ack = message.acknowledge();
+
+        assert_eq!(ack, MID1203rev1 { acknowledged_mid: 1202, result_data_id: 42 });
+        assert_eq!(encode::encode(&ack), Ok("12020000000042".to_string()));
+    }
+}
@@ -0,0 +1,170 @@
+use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+
+/// Number of sockets on a selector.
+pub const SELECTOR_SOCKETS: usize = 8;
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum SocketStatus {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    NotLifted,
+    #[open_protocol_value(number = 1)]
+    Lifted,
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum LightCommand {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Off,
+    #[open_protocol_value(number = 1)]
+    On,
+    #[open_protocol_value(number = 2)]
+    Flashing,
+}
+
+/// A subscription for the socket status of the selectors.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 250, revision = 1)]
+pub struct MID0250rev1 {
+    // No additional fields for this MID.
+}
+
+/// Sent each time a socket of a selector is lifted or put back.
+#[derive(Debug, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 251, revision = 1, direction = "to_integrator", ack = 252)]
+pub struct MID0251rev1 {
+    /// The ID of the selector device.
+    #[open_protocol_field(number = 1, length = 2)]
+    pub device_id: u8,
+
+    /// The status of each of the 8 sockets of the selector.
+    #[open_protocol_field(number = 2, list, amount = 8, length = 1)]
+    pub socket_statuses: Vec<SocketStatus>,
+}
+
+/// Acknowledges receipt of MID 0251 Selector socket info.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 252, revision = 1, reply = "none")]
+pub struct MID0252rev1 {
+    // No additional fields for this MID.
+}
+
+/// Cancels a previously subscribed selector socket info.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 253, revision = 1)]
+pub struct MID0253rev1 {
+    // No additional fields for this MID.
+}
+
+/// Controls the green lights of the sockets of a selector.
+#[derive(Debug, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 254, revision = 1)]
+pub struct MID0254rev1 {
+    /// The ID of the selector device.
+    #[open_protocol_field(number = 1, length = 2)]
+    pub device_id: u8,
+
+    /// The command for the green light of each of the 8 sockets of the selector.
+    #[open_protocol_field(number = 2, list, amount = 8, length = 1)]
+    pub green_lights: Vec<LightCommand>,
+}
+
+/// Controls the red lights of the sockets of a selector.
+#[derive(Debug, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 255, revision = 1)]
+pub struct MID0255rev1 {
+    /// The ID of the selector device.
+    #[open_protocol_field(number = 1, length = 2)]
+    pub device_id: u8,
+
+    /// The command for the red light of each of the 8 sockets of the selector.
+    #[open_protocol_field(number = 2, list, amount = 8, length = 1)]
+    pub red_lights: Vec<LightCommand>,
+}
+
+/// Defaults to all sockets put back, so the message is complete.
+impl Default for MID0251rev1 {
+    fn default() -> Self {
+        Self { device_id: 0, socket_statuses: vec![SocketStatus::NotLifted; SELECTOR_SOCKETS] }
+    }
+}
+
+/// Defaults to all lights off, so the message is complete.
+impl Default for MID0254rev1 {
+    fn default() -> Self {
+        Self { device_id: 0, green_lights: vec![LightCommand::Off; SELECTOR_SOCKETS] }
+    }
+}
+
+/// Defaults to all lights off, so the message is complete.
+impl Default for MID0255rev1 {
+    fn default() -> Self {
+        Self { device_id: 0, red_lights: vec![LightCommand::Off; SELECTOR_SOCKETS] }
+    }
+}
+
+impl MID0251rev1 {
+    /// Returns the number (1-8) of the lifted socket, if exactly one socket is lifted.
+    pub fn lifted_socket(&self) -> Option<u8> {
+        let mut lifted = self.socket_statuses.iter()
+            .enumerate()
+            .filter(|(_, status)| **status == SocketStatus::Lifted);
+
+        match (lifted.next(), lifted.next()) {
+            (Some((index, _)), None) => Some(index as u8 + 1),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use open_protocol_codec::{decode, encode};
+
+    #[test]
+    fn decode_mid0251rev1() {
+        let decoded = decode::decode::<MID0251rev1>("01030200100000".as_bytes()).unwrap();
+
+        assert_eq!(decoded.device_id, 3);
+        assert_eq!(decoded.socket_statuses, vec![
+            SocketStatus::NotLifted,
+            SocketStatus::NotLifted,
+            SocketStatus::Lifted,
+            SocketStatus::NotLifted,
+            SocketStatus::NotLifted,
+            SocketStatus::NotLifted,
+            SocketStatus::NotLifted,
+            SocketStatus::NotLifted,
+        ]);
+        assert_eq!(decoded.lifted_socket(), Some(3));
+    }
+
+    #[test]
+    fn encode_mid0254rev1() {
+        let mut green_lights = vec![LightCommand::Off; SELECTOR_SOCKETS];
+        green_lights[0] = LightCommand::On;
+        green_lights[7] = LightCommand::Flashing;
+
+        let message = MID0254rev1 { device_id: 1, green_lights };
+
+        let encoded = encode::encode(&message);
+
+        assert_eq!(encoded, Ok("01010210000002".to_string()));
+    }
+
+    #[test]
+    fn encode_default() {
+        assert_eq!(encode::encode(&MID0251rev1::default()), Ok("01000200000000".to_string()));
+        assert_eq!(encode::encode(&MID0254rev1::default()), Ok("01000200000000".to_string()));
+        assert_eq!(encode::encode(&MID0255rev1::default()), Ok("01000200000000".to_string()));
+    }
+
+    #[test]
+    fn encode_mid0255rev1_missing_sockets() {
+        let message = MID0255rev1 { device_id: 1, red_lights: vec![LightCommand::On] };
+
+        assert_eq!(encode::encode(&message), Err(encode::Error::InvalidListLength { expected: 8, actual: 1 }));
+    }
+}
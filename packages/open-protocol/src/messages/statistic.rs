@@ -0,0 +1,147 @@
+use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum HistogramType {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Torque,
+    #[open_protocol_value(number = 1)]
+    Angle,
+    #[open_protocol_value(number = 2)]
+    Current,
+    #[open_protocol_value(number = 3)]
+    PrevailTorque,
+    #[open_protocol_value(number = 4)]
+    SelfTap,
+    #[open_protocol_value(number = 5)]
+    RundownAngle,
+}
+
+/// Requests the histogram of a parameter set. The controller replies with MID 0301 Histogram
+/// upload reply, or with MID 0004 Command error if there is no histogram for the parameter set.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 300, revision = 1, reply = 301)]
+pub struct MID0300rev1 {
+    /// The parameter set ID.
+    #[open_protocol_field(number = 1, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// The type of histogram requested.
+    #[open_protocol_field(number = 2, length = 2)]
+    pub histogram_type: HistogramType,
+}
+
+/// The histogram of a parameter set, as requested with MID 0300 Histogram upload request.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 301, revision = 1, direction = "to_integrator")]
+pub struct MID0301rev1 {
+    /// The parameter set ID.
+    #[open_protocol_field(number = 1, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// The type of histogram.
+    #[open_protocol_field(number = 2, length = 2)]
+    pub histogram_type: HistogramType,
+
+    /// The standard deviation of the results, multiplied by 100.
+    #[open_protocol_field(number = 3, length = 6)]
+    pub sigma: u32,
+
+    /// The mean value of the results, multiplied by 100.
+    #[open_protocol_field(number = 4, length = 6)]
+    pub mean_value: u32,
+
+    /// The width of a class, multiplied by 100.
+    #[open_protocol_field(number = 5, length = 6)]
+    pub class_range: u32,
+
+    /// The number of results in each of the 9 classes, from low to high.
+    #[open_protocol_field(number = 6, list, amount = 9, length = 4)]
+    pub class_values: Vec<u16>,
+}
+
+/// A single class of a histogram, with its boundaries in the unit of the histogram type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistogramBucket {
+    /// Inclusive lower boundary of the class.
+    pub lower: f64,
+    /// Exclusive upper boundary of the class.
+    pub upper: f64,
+    /// The number of results within the boundaries.
+    pub count: u16,
+}
+
+/// A histogram of a parameter set, with the classes placed around the mean value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    pub parameter_set_id: u16,
+    pub histogram_type: HistogramType,
+    pub sigma: f64,
+    pub mean: f64,
+    pub buckets: Vec<HistogramBucket>,
+}
+
+impl Histogram {
+    /// The total number of results in the histogram.
+    pub fn total(&self) -> u32 {
+        self.buckets.iter().map(|bucket| bucket.count as u32).sum()
+    }
+}
+
+impl From<&MID0301rev1> for Histogram {
+    fn from(value: &MID0301rev1) -> Self {
+        let mean = value.mean_value as f64 / 100.0;
+        let class_range = value.class_range as f64 / 100.0;
+        let first_lower = mean - class_range * value.class_values.len() as f64 / 2.0;
+
+        let buckets = value.class_values.iter()
+            .enumerate()
+            .map(|(index, count)| {
+                let lower = first_lower + class_range * index as f64;
+                HistogramBucket { lower, upper: lower + class_range, count: *count }
+            })
+            .collect();
+
+        Self {
+            parameter_set_id: value.parameter_set_id,
+            histogram_type: value.histogram_type,
+            sigma: value.sigma as f64 / 100.0,
+            mean,
+            buckets,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use open_protocol_codec::{decode, encode};
+
+    #[test]
+    fn encode_mid0300rev1() {
+        let message = MID0300rev1 { parameter_set_id: 1, histogram_type: HistogramType::Angle };
+
+        let encoded = encode::encode(&message);
+
+        assert_eq!(encoded, Ok("010010201".to_string()));
+    }
+
+    #[test]
+    fn decode_mid0301rev1_histogram() {
+        let decoded = decode::decode::<MID0301rev1>(
+            "010010200030000300400120005000050\
+             06000000010004001000200010000400010000".as_bytes()
+        ).unwrap();
+
+        assert_eq!(decoded.class_values, vec![0, 1, 4, 10, 20, 10, 4, 1, 0]);
+
+        let histogram = Histogram::from(&decoded);
+
+        assert_eq!(histogram.histogram_type, HistogramType::Torque);
+        assert_eq!(histogram.sigma, 0.3);
+        assert_eq!(histogram.mean, 12.0);
+        assert_eq!(histogram.total(), 50);
+        assert_eq!(histogram.buckets[0], HistogramBucket { lower: 9.75, upper: 10.25, count: 0 });
+        assert_eq!(histogram.buckets[4], HistogramBucket { lower: 11.75, upper: 12.25, count: 20 });
+    }
+}
@@ -1,9 +1,10 @@
-use chrono::{DateTime, Local};
+use chrono::NaiveDateTime;
 use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+use crate::enums::status::{BatchStatus, LimitStatus, OkNok, ResultType, TorqueUnit};
 use crate::types::datafield::DataField;
-use crate::types::trace::TraceSample;
+use crate::types::trace::{ResolutionField, TraceSample};
 
-#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, OpenProtocolEncode, OpenProtocolDecode)]
 pub enum TraceType {
     #[default]
     #[open_protocol_value(number = 1)]
@@ -36,15 +37,64 @@ pub enum ObjectType {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0060, revision = 1)]
+#[open_protocol_message(MID = 0060, revision = 1, subscribes = 61)]
 pub struct MID0060rev1 {
     // Subscribe to tightening result data
     // No fields for this revision
 }
 
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0060, revision = 2, subscribes = 61)]
+pub struct MID0060rev2 {
+    // Subscribe to revision 2 of the tightening result data
+    // No fields for this revision
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0060, revision = 3, subscribes = 61)]
+pub struct MID0060rev3 {
+    // Subscribe to revision 3 of the tightening result data
+    // No fields for this revision
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0060, revision = 4, subscribes = 61)]
+pub struct MID0060rev4 {
+    // Subscribe to revision 4 of the tightening result data
+    // No fields for this revision
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0060, revision = 5, subscribes = 61)]
+pub struct MID0060rev5 {
+    // Subscribe to revision 5 of the tightening result data
+    // No fields for this revision
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0060, revision = 6, subscribes = 61)]
+pub struct MID0060rev6 {
+    // Subscribe to revision 6 of the tightening result data
+    // No fields for this revision
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0060, revision = 7, subscribes = 61)]
+pub struct MID0060rev7 {
+    // Subscribe to revision 7 of the tightening result data
+    // No fields for this revision
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0060, revision = 998, subscribes = 61)]
+pub struct MID0060rev998 {
+    // Subscribe to revision 998 of the tightening result data
+    // No fields for this revision
+}
+
 // MID0061 revisions
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0061, revision = 1)]
+#[open_protocol_message(MID = 0061, revision = 1, direction = "to_integrator", ack = 62)]
 pub struct MID0061rev1 {
     /// The cell ID of the tightening system
     #[open_protocol_field(number = 1, length = 4)]
@@ -66,319 +116,2514 @@ pub struct MID0061rev1 {
     #[open_protocol_field(number = 5, length = 2)]
     pub job_id: u8,
 
-    /// The parameter set ID used
-    #[open_protocol_field(number = 6, length = 3)]
-    pub parameter_set_id: u16,
+    /// The parameter set ID used
+    #[open_protocol_field(number = 6, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// The total number of tightenings required in the batch
+    #[open_protocol_field(number = 7, length = 4)]
+    pub batch_size: u16,
+
+    /// The current batch counter
+    #[open_protocol_field(number = 8, length = 4)]
+    pub batch_counter: u16,
+
+    /// The status of the tightening (0=NOK, 1=OK)
+    #[open_protocol_field(number = 9, length = 1)]
+    pub tightening_status: OkNok,
+
+    /// The torque status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 10, length = 1)]
+    pub torque_status: LimitStatus,
+
+    /// The angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 11, length = 1)]
+    pub angle_status: LimitStatus,
+
+    /// The minimum torque limit
+    #[open_protocol_field(number = 12, length = 6)]
+    pub torque_min_limit: u32,
+
+    /// The maximum torque limit
+    #[open_protocol_field(number = 13, length = 6)]
+    pub torque_max_limit: u32,
+
+    /// The final torque target
+    #[open_protocol_field(number = 14, length = 6)]
+    pub torque_final_target: u32,
+
+    /// The achieved torque value
+    #[open_protocol_field(number = 15, length = 6)]
+    pub torque: u32,
+
+    /// The minimum angle limit
+    #[open_protocol_field(number = 16, length = 5)]
+    pub angle_min_limit: u16,
+
+    /// The maximum angle limit
+    #[open_protocol_field(number = 17, length = 5)]
+    pub angle_max_limit: u16,
+
+    /// The final angle target
+    #[open_protocol_field(number = 18, length = 5)]
+    pub angle_final_target: u16,
+
+    /// The achieved angle value
+    #[open_protocol_field(number = 19, length = 5)]
+    pub angle: u16,
+
+    /// Timestamp of the tightening
+    #[open_protocol_field(number = 20, length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// Timestamp of the last parameter set change
+    #[open_protocol_field(number = 21, length = 19)]
+    pub last_parameter_set_change: NaiveDateTime,
+
+    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
+    #[open_protocol_field(number = 22, length = 1)]
+    pub batch_status: BatchStatus,
+
+    /// The tightening ID, a unique identifier for each result
+    #[open_protocol_field(number = 23, length = 10)]
+    pub tightening_id: u32,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0061, revision = 2, direction = "to_integrator", ack = 62)]
+pub struct MID0061rev2 {
+    /// The cell ID of the tightening system
+    #[open_protocol_field(number = 1, length = 4)]
+    pub cell_id: u16,
+
+    /// The channel ID for the tightening
+    #[open_protocol_field(number = 2, length = 2)]
+    pub channel_id: u8,
+
+    /// The name of the torque controller
+    #[open_protocol_field(number = 3, length = 25)]
+    pub controller_name: String,
+
+    /// The Vehicle Identification Number (VIN) used in the tightening
+    #[open_protocol_field(number = 4, length = 25)]
+    pub vin_number: String,
+
+    /// The ID of the job performed
+    #[open_protocol_field(number = 5, length = 4)]
+    pub job_id: u16,
+
+    /// The parameter set ID used
+    #[open_protocol_field(number = 6, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 7, length = 2)]
+    pub strategy: u8,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 8, length = 5)]
+    pub strategy_options: u32,
+
+    /// Batch size for the tightening operation
+    #[open_protocol_field(number = 9, length = 4)]
+    pub batch_size: u16,
+
+    /// The current count of tightenings in the batch
+    #[open_protocol_field(number = 10, length = 4)]
+    pub batch_counter: u16,
+
+    /// The status of the tightening (0=NOK, 1=OK)
+    #[open_protocol_field(number = 11, length = 1)]
+    pub tightening_status: OkNok,
+
+    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
+    #[open_protocol_field(number = 12, length = 1)]
+    pub batch_status: BatchStatus,
+
+    /// The torque status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 13, length = 1)]
+    pub torque_status: LimitStatus,
+
+    /// The angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 14, length = 1)]
+    pub angle_status: LimitStatus,
+
+    /// The rundown angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 15, length = 1)]
+    pub rundown_angle_status: LimitStatus,
+
+    /// Current monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 16, length = 1)]
+    pub current_monitoring_status: LimitStatus,
+
+    /// Self-tap status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 17, length = 1)]
+    pub self_tap_status: LimitStatus,
+
+    /// Prevail torque monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 18, length = 1)]
+    pub prevail_torque_monitoring_status: LimitStatus,
+
+    /// Prevail torque compensate status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 19, length = 1)]
+    pub prevail_torque_compensate_status: LimitStatus,
+
+    /// Tightening error status
+    #[open_protocol_field(number = 20, length = 10)]
+    pub tightening_error_status: String,
+
+    /// Minimum torque limit
+    #[open_protocol_field(number = 21, length = 6)]
+    pub torque_min_limit: u32,
+
+    /// Maximum torque limit
+    #[open_protocol_field(number = 22, length = 6)]
+    pub torque_max_limit: u32,
+
+    /// Final torque target
+    #[open_protocol_field(number = 23, length = 6)]
+    pub torque_final_target: u32,
+
+    /// Achieved torque value
+    #[open_protocol_field(number = 24, length = 6)]
+    pub torque: u32,
+
+    /// Minimum angle limit
+    #[open_protocol_field(number = 25, length = 5)]
+    pub angle_min_limit: u16,
+
+    /// Maximum angle limit
+    #[open_protocol_field(number = 26, length = 5)]
+    pub angle_max_limit: u16,
+
+    /// Final angle target
+    #[open_protocol_field(number = 27, length = 5)]
+    pub angle_final_target: u16,
+
+    /// Achieved angle value
+    #[open_protocol_field(number = 28, length = 5)]
+    pub angle: u16,
+
+    /// Minimum rundown angle
+    #[open_protocol_field(number = 29, length = 5)]
+    pub rundown_angle_min: u16,
+
+    /// Maximum rundown angle
+    #[open_protocol_field(number = 30, length = 5)]
+    pub rundown_angle_max: u16,
+
+    /// Achieved rundown angle
+    #[open_protocol_field(number = 31, length = 5)]
+    pub rundown_angle: u16,
+
+    /// Minimum current monitoring value
+    #[open_protocol_field(number = 32, length = 3)]
+    pub current_monitoring_min: u16,
+
+    /// Maximum current monitoring value
+    #[open_protocol_field(number = 33, length = 3)]
+    pub current_monitoring_max: u16,
+
+    /// Achieved current monitoring value
+    #[open_protocol_field(number = 34, length = 3)]
+    pub current_monitoring_value: u16,
+
+    /// Minimum self-tap torque
+    #[open_protocol_field(number = 35, length = 6)]
+    pub self_tap_torque_min: u32,
+
+    /// Maximum self-tap torque
+    #[open_protocol_field(number = 36, length = 6)]
+    pub self_tap_torque_max: u32,
+
+    /// Achieved self-tap torque
+    #[open_protocol_field(number = 37, length = 6)]
+    pub self_tap_torque: u32,
+
+    /// Minimum prevail torque value
+    #[open_protocol_field(number = 38, length = 6)]
+    pub prevail_torque_min: u32,
+
+    /// Maximum prevail torque value
+    #[open_protocol_field(number = 39, length = 6)]
+    pub prevail_torque_max: u32,
+
+    /// Achieved prevail torque value
+    #[open_protocol_field(number = 40, length = 6)]
+    pub prevail_torque: u32,
+
+    /// The tightening ID, a unique identifier for this result
+    #[open_protocol_field(number = 41, length = 10)]
+    pub tightening_id: u32,
+
+    /// The job sequence number, a unique number for each job
+    #[open_protocol_field(number = 42, length = 5)]
+    pub job_sequence_number: u16,
+
+    /// The sync tightening ID
+    #[open_protocol_field(number = 43, length = 5)]
+    pub sync_tightening_id: u16,
+
+    /// The serial number of the tool
+    #[open_protocol_field(number = 44, length = 14)]
+    pub tool_serial_number: String,
+
+    /// Timestamp of the tightening
+    #[open_protocol_field(number = 45, length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// Timestamp of the last parameter set change
+    #[open_protocol_field(number = 46, length = 19)]
+    pub last_parameter_set_change: NaiveDateTime,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0061, revision = 3, direction = "to_integrator", ack = 62)]
+pub struct MID0061rev3 {
+    /// The cell ID of the tightening system
+    #[open_protocol_field(number = 1, length = 4)]
+    pub cell_id: u16,
+
+    /// The channel ID for the tightening
+    #[open_protocol_field(number = 2, length = 2)]
+    pub channel_id: u8,
+
+    /// The name of the torque controller
+    #[open_protocol_field(number = 3, length = 25)]
+    pub controller_name: String,
+
+    /// The Vehicle Identification Number (VIN) used in the tightening
+    #[open_protocol_field(number = 4, length = 25)]
+    pub vin_number: String,
+
+    /// The ID of the job performed
+    #[open_protocol_field(number = 5, length = 4)]
+    pub job_id: u16,
+
+    /// The parameter set ID used
+    #[open_protocol_field(number = 6, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 7, length = 2)]
+    pub strategy: u8,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 8, length = 5)]
+    pub strategy_options: u32,
+
+    /// Batch size for the tightening operation
+    #[open_protocol_field(number = 9, length = 4)]
+    pub batch_size: u16,
+
+    /// The current count of tightenings in the batch
+    #[open_protocol_field(number = 10, length = 4)]
+    pub batch_counter: u16,
+
+    /// The status of the tightening (0=NOK, 1=OK)
+    #[open_protocol_field(number = 11, length = 1)]
+    pub tightening_status: OkNok,
+
+    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
+    #[open_protocol_field(number = 12, length = 1)]
+    pub batch_status: BatchStatus,
+
+    /// The torque status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 13, length = 1)]
+    pub torque_status: LimitStatus,
+
+    /// The angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 14, length = 1)]
+    pub angle_status: LimitStatus,
+
+    /// The rundown angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 15, length = 1)]
+    pub rundown_angle_status: LimitStatus,
+
+    /// Current monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 16, length = 1)]
+    pub current_monitoring_status: LimitStatus,
+
+    /// Self-tap status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 17, length = 1)]
+    pub self_tap_status: LimitStatus,
+
+    /// Prevail torque monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 18, length = 1)]
+    pub prevail_torque_monitoring_status: LimitStatus,
+
+    /// Prevail torque compensate status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 19, length = 1)]
+    pub prevail_torque_compensate_status: LimitStatus,
+
+    /// Tightening error status
+    #[open_protocol_field(number = 20, length = 10)]
+    pub tightening_error_status: String,
+
+    /// Minimum torque limit
+    #[open_protocol_field(number = 21, length = 6)]
+    pub torque_min_limit: u32,
+
+    /// Maximum torque limit
+    #[open_protocol_field(number = 22, length = 6)]
+    pub torque_max_limit: u32,
+
+    /// Final torque target
+    #[open_protocol_field(number = 23, length = 6)]
+    pub torque_final_target: u32,
+
+    /// Achieved torque value
+    #[open_protocol_field(number = 24, length = 6)]
+    pub torque: u32,
+
+    /// Minimum angle limit
+    #[open_protocol_field(number = 25, length = 5)]
+    pub angle_min_limit: u16,
+
+    /// Maximum angle limit
+    #[open_protocol_field(number = 26, length = 5)]
+    pub angle_max_limit: u16,
+
+    /// Final angle target
+    #[open_protocol_field(number = 27, length = 5)]
+    pub angle_final_target: u16,
+
+    /// Achieved angle value
+    #[open_protocol_field(number = 28, length = 5)]
+    pub angle: u16,
+
+    /// Minimum rundown angle
+    #[open_protocol_field(number = 29, length = 5)]
+    pub rundown_angle_min: u16,
+
+    /// Maximum rundown angle
+    #[open_protocol_field(number = 30, length = 5)]
+    pub rundown_angle_max: u16,
+
+    /// Achieved rundown angle
+    #[open_protocol_field(number = 31, length = 5)]
+    pub rundown_angle: u16,
+
+    /// Minimum current monitoring value
+    #[open_protocol_field(number = 32, length = 3)]
+    pub current_monitoring_min: u16,
+
+    /// Maximum current monitoring value
+    #[open_protocol_field(number = 33, length = 3)]
+    pub current_monitoring_max: u16,
+
+    /// Achieved current monitoring value
+    #[open_protocol_field(number = 34, length = 3)]
+    pub current_monitoring_value: u16,
+
+    /// Minimum self-tap torque
+    #[open_protocol_field(number = 35, length = 6)]
+    pub self_tap_torque_min: u32,
+
+    /// Maximum self-tap torque
+    #[open_protocol_field(number = 36, length = 6)]
+    pub self_tap_torque_max: u32,
+
+    /// Achieved self-tap torque
+    #[open_protocol_field(number = 37, length = 6)]
+    pub self_tap_torque: u32,
+
+    /// Minimum prevail torque value
+    #[open_protocol_field(number = 38, length = 6)]
+    pub prevail_torque_min: u32,
+
+    /// Maximum prevail torque value
+    #[open_protocol_field(number = 39, length = 6)]
+    pub prevail_torque_max: u32,
+
+    /// Achieved prevail torque value
+    #[open_protocol_field(number = 40, length = 6)]
+    pub prevail_torque: u32,
+
+    /// The tightening ID, a unique identifier for this result
+    #[open_protocol_field(number = 41, length = 10)]
+    pub tightening_id: u32,
+
+    /// The job sequence number, a unique number for each job
+    #[open_protocol_field(number = 42, length = 5)]
+    pub job_sequence_number: u16,
+
+    /// The sync tightening ID
+    #[open_protocol_field(number = 43, length = 5)]
+    pub sync_tightening_id: u16,
+
+    /// The serial number of the tool
+    #[open_protocol_field(number = 44, length = 14)]
+    pub tool_serial_number: String,
+
+    /// Timestamp of the tightening
+    #[open_protocol_field(number = 45, length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// Timestamp of the last parameter set change
+    #[open_protocol_field(number = 46, length = 19)]
+    pub last_parameter_set_change: NaiveDateTime,
+
+    /// Name of the parameter set used
+    #[open_protocol_field(number = 47, length = 25)]
+    pub parameter_set_name: String,
+
+    /// The unit of torque values (1=Nm, 2=Lbf.ft, 3=Lbf.In, etc.)
+    #[open_protocol_field(number = 48, length = 1)]
+    pub torque_unit: TorqueUnit,
+
+    /// The result type (1=Tightening, 2=Loosening, etc.)
+    #[open_protocol_field(number = 49, length = 2)]
+    pub result_type: ResultType,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0061, revision = 4, direction = "to_integrator", ack = 62)]
+pub struct MID0061rev4 {
+    /// The cell ID of the tightening system
+    #[open_protocol_field(number = 1, length = 4)]
+    pub cell_id: u16,
+
+    /// The channel ID for the tightening
+    #[open_protocol_field(number = 2, length = 2)]
+    pub channel_id: u8,
+
+    /// The name of the torque controller
+    #[open_protocol_field(number = 3, length = 25)]
+    pub controller_name: String,
+
+    /// The Vehicle Identification Number (VIN) used in the tightening
+    #[open_protocol_field(number = 4, length = 25)]
+    pub vin_number: String,
+
+    /// The ID of the job performed
+    #[open_protocol_field(number = 5, length = 4)]
+    pub job_id: u16,
+
+    /// The parameter set ID used
+    #[open_protocol_field(number = 6, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 7, length = 2)]
+    pub strategy: u8,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 8, length = 5)]
+    pub strategy_options: u32,
+
+    /// Batch size for the tightening operation
+    #[open_protocol_field(number = 9, length = 4)]
+    pub batch_size: u16,
+
+    /// The current count of tightenings in the batch
+    #[open_protocol_field(number = 10, length = 4)]
+    pub batch_counter: u16,
+
+    /// The status of the tightening (0=NOK, 1=OK)
+    #[open_protocol_field(number = 11, length = 1)]
+    pub tightening_status: OkNok,
+
+    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
+    #[open_protocol_field(number = 12, length = 1)]
+    pub batch_status: BatchStatus,
+
+    /// The torque status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 13, length = 1)]
+    pub torque_status: LimitStatus,
+
+    /// The angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 14, length = 1)]
+    pub angle_status: LimitStatus,
+
+    /// The rundown angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 15, length = 1)]
+    pub rundown_angle_status: LimitStatus,
+
+    /// Current monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 16, length = 1)]
+    pub current_monitoring_status: LimitStatus,
+
+    /// Self-tap status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 17, length = 1)]
+    pub self_tap_status: LimitStatus,
+
+    /// Prevail torque monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 18, length = 1)]
+    pub prevail_torque_monitoring_status: LimitStatus,
+
+    /// Prevail torque compensate status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 19, length = 1)]
+    pub prevail_torque_compensate_status: LimitStatus,
+
+    /// Tightening error status
+    #[open_protocol_field(number = 20, length = 10)]
+    pub tightening_error_status: String,
+
+    /// Minimum torque limit
+    #[open_protocol_field(number = 21, length = 6)]
+    pub torque_min_limit: u32,
+
+    /// Maximum torque limit
+    #[open_protocol_field(number = 22, length = 6)]
+    pub torque_max_limit: u32,
+
+    /// Final torque target
+    #[open_protocol_field(number = 23, length = 6)]
+    pub torque_final_target: u32,
+
+    /// Achieved torque value
+    #[open_protocol_field(number = 24, length = 6)]
+    pub torque: u32,
+
+    /// Minimum angle limit
+    #[open_protocol_field(number = 25, length = 5)]
+    pub angle_min_limit: u16,
+
+    /// Maximum angle limit
+    #[open_protocol_field(number = 26, length = 5)]
+    pub angle_max_limit: u16,
+
+    /// Final angle target
+    #[open_protocol_field(number = 27, length = 5)]
+    pub angle_final_target: u16,
+
+    /// Achieved angle value
+    #[open_protocol_field(number = 28, length = 5)]
+    pub angle: u16,
+
+    /// Minimum rundown angle
+    #[open_protocol_field(number = 29, length = 5)]
+    pub rundown_angle_min: u16,
+
+    /// Maximum rundown angle
+    #[open_protocol_field(number = 30, length = 5)]
+    pub rundown_angle_max: u16,
+
+    /// Achieved rundown angle
+    #[open_protocol_field(number = 31, length = 5)]
+    pub rundown_angle: u16,
+
+    /// Minimum current monitoring value
+    #[open_protocol_field(number = 32, length = 3)]
+    pub current_monitoring_min: u16,
+
+    /// Maximum current monitoring value
+    #[open_protocol_field(number = 33, length = 3)]
+    pub current_monitoring_max: u16,
+
+    /// Achieved current monitoring value
+    #[open_protocol_field(number = 34, length = 3)]
+    pub current_monitoring_value: u16,
+
+    /// Minimum self-tap torque
+    #[open_protocol_field(number = 35, length = 6)]
+    pub self_tap_torque_min: u32,
+
+    /// Maximum self-tap torque
+    #[open_protocol_field(number = 36, length = 6)]
+    pub self_tap_torque_max: u32,
+
+    /// Achieved self-tap torque
+    #[open_protocol_field(number = 37, length = 6)]
+    pub self_tap_torque: u32,
+
+    /// Minimum prevail torque value
+    #[open_protocol_field(number = 38, length = 6)]
+    pub prevail_torque_min: u32,
+
+    /// Maximum prevail torque value
+    #[open_protocol_field(number = 39, length = 6)]
+    pub prevail_torque_max: u32,
+
+    /// Achieved prevail torque value
+    #[open_protocol_field(number = 40, length = 6)]
+    pub prevail_torque: u32,
+
+    /// The tightening ID, a unique identifier for this result
+    #[open_protocol_field(number = 41, length = 10)]
+    pub tightening_id: u32,
+
+    /// The job sequence number, a unique number for each job
+    #[open_protocol_field(number = 42, length = 5)]
+    pub job_sequence_number: u16,
+
+    /// The sync tightening ID
+    #[open_protocol_field(number = 43, length = 5)]
+    pub sync_tightening_id: u16,
+
+    /// The serial number of the tool
+    #[open_protocol_field(number = 44, length = 14)]
+    pub tool_serial_number: String,
+
+    /// Timestamp of the tightening
+    #[open_protocol_field(number = 45, length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// Timestamp of the last parameter set change
+    #[open_protocol_field(number = 46, length = 19)]
+    pub last_parameter_set_change: NaiveDateTime,
+
+    /// Name of the parameter set used
+    #[open_protocol_field(number = 47, length = 25)]
+    pub parameter_set_name: String,
+
+    /// The unit of torque values (1=Nm, 2=Lbf.ft, 3=Lbf.In, etc.)
+    #[open_protocol_field(number = 48, length = 1)]
+    pub torque_unit: TorqueUnit,
+
+    /// The result type (1=Tightening, 2=Loosening, etc.)
+    #[open_protocol_field(number = 49, length = 2)]
+    pub result_type: ResultType,
+
+    /// The second identifier result part
+    #[open_protocol_field(number = 50, length = 25)]
+    pub identifier_result_part_2: String,
+
+    /// The third identifier result part
+    #[open_protocol_field(number = 51, length = 25)]
+    pub identifier_result_part_3: String,
+
+    /// The fourth identifier result part
+    #[open_protocol_field(number = 52, length = 25)]
+    pub identifier_result_part_4: String,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0061, revision = 5, direction = "to_integrator", ack = 62)]
+pub struct MID0061rev5 {
+    /// The cell ID of the tightening system
+    #[open_protocol_field(number = 1, length = 4)]
+    pub cell_id: u16,
+
+    /// The channel ID for the tightening
+    #[open_protocol_field(number = 2, length = 2)]
+    pub channel_id: u8,
+
+    /// The name of the torque controller
+    #[open_protocol_field(number = 3, length = 25)]
+    pub controller_name: String,
+
+    /// The Vehicle Identification Number (VIN) used in the tightening
+    #[open_protocol_field(number = 4, length = 25)]
+    pub vin_number: String,
+
+    /// The ID of the job performed
+    #[open_protocol_field(number = 5, length = 4)]
+    pub job_id: u16,
+
+    /// The parameter set ID used
+    #[open_protocol_field(number = 6, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 7, length = 2)]
+    pub strategy: u8,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 8, length = 5)]
+    pub strategy_options: u32,
+
+    /// Batch size for the tightening operation
+    #[open_protocol_field(number = 9, length = 4)]
+    pub batch_size: u16,
+
+    /// The current count of tightenings in the batch
+    #[open_protocol_field(number = 10, length = 4)]
+    pub batch_counter: u16,
+
+    /// The status of the tightening (0=NOK, 1=OK)
+    #[open_protocol_field(number = 11, length = 1)]
+    pub tightening_status: OkNok,
+
+    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
+    #[open_protocol_field(number = 12, length = 1)]
+    pub batch_status: BatchStatus,
+
+    /// The torque status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 13, length = 1)]
+    pub torque_status: LimitStatus,
+
+    /// The angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 14, length = 1)]
+    pub angle_status: LimitStatus,
+
+    /// The rundown angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 15, length = 1)]
+    pub rundown_angle_status: LimitStatus,
+
+    /// Current monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 16, length = 1)]
+    pub current_monitoring_status: LimitStatus,
+
+    /// Self-tap status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 17, length = 1)]
+    pub self_tap_status: LimitStatus,
+
+    /// Prevail torque monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 18, length = 1)]
+    pub prevail_torque_monitoring_status: LimitStatus,
+
+    /// Prevail torque compensate status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 19, length = 1)]
+    pub prevail_torque_compensate_status: LimitStatus,
+
+    /// Tightening error status
+    #[open_protocol_field(number = 20, length = 10)]
+    pub tightening_error_status: String,
+
+    /// Minimum torque limit
+    #[open_protocol_field(number = 21, length = 6)]
+    pub torque_min_limit: u32,
+
+    /// Maximum torque limit
+    #[open_protocol_field(number = 22, length = 6)]
+    pub torque_max_limit: u32,
+
+    /// Final torque target
+    #[open_protocol_field(number = 23, length = 6)]
+    pub torque_final_target: u32,
+
+    /// Achieved torque value
+    #[open_protocol_field(number = 24, length = 6)]
+    pub torque: u32,
+
+    /// Minimum angle limit
+    #[open_protocol_field(number = 25, length = 5)]
+    pub angle_min_limit: u16,
+
+    /// Maximum angle limit
+    #[open_protocol_field(number = 26, length = 5)]
+    pub angle_max_limit: u16,
+
+    /// Final angle target
+    #[open_protocol_field(number = 27, length = 5)]
+    pub angle_final_target: u16,
+
+    /// Achieved angle value
+    #[open_protocol_field(number = 28, length = 5)]
+    pub angle: u16,
+
+    /// Minimum rundown angle
+    #[open_protocol_field(number = 29, length = 5)]
+    pub rundown_angle_min: u16,
+
+    /// Maximum rundown angle
+    #[open_protocol_field(number = 30, length = 5)]
+    pub rundown_angle_max: u16,
+
+    /// Achieved rundown angle
+    #[open_protocol_field(number = 31, length = 5)]
+    pub rundown_angle: u16,
+
+    /// Minimum current monitoring value
+    #[open_protocol_field(number = 32, length = 3)]
+    pub current_monitoring_min: u16,
+
+    /// Maximum current monitoring value
+    #[open_protocol_field(number = 33, length = 3)]
+    pub current_monitoring_max: u16,
+
+    /// Achieved current monitoring value
+    #[open_protocol_field(number = 34, length = 3)]
+    pub current_monitoring_value: u16,
+
+    /// Minimum self-tap torque
+    #[open_protocol_field(number = 35, length = 6)]
+    pub self_tap_torque_min: u32,
+
+    /// Maximum self-tap torque
+    #[open_protocol_field(number = 36, length = 6)]
+    pub self_tap_torque_max: u32,
+
+    /// Achieved self-tap torque
+    #[open_protocol_field(number = 37, length = 6)]
+    pub self_tap_torque: u32,
+
+    /// Minimum prevail torque value
+    #[open_protocol_field(number = 38, length = 6)]
+    pub prevail_torque_min: u32,
+
+    /// Maximum prevail torque value
+    #[open_protocol_field(number = 39, length = 6)]
+    pub prevail_torque_max: u32,
+
+    /// Achieved prevail torque value
+    #[open_protocol_field(number = 40, length = 6)]
+    pub prevail_torque: u32,
+
+    /// The tightening ID, a unique identifier for this result
+    #[open_protocol_field(number = 41, length = 10)]
+    pub tightening_id: u32,
+
+    /// The job sequence number, a unique number for each job
+    #[open_protocol_field(number = 42, length = 5)]
+    pub job_sequence_number: u16,
+
+    /// The sync tightening ID
+    #[open_protocol_field(number = 43, length = 5)]
+    pub sync_tightening_id: u16,
+
+    /// The serial number of the tool
+    #[open_protocol_field(number = 44, length = 14)]
+    pub tool_serial_number: String,
+
+    /// Timestamp of the tightening
+    #[open_protocol_field(number = 45, length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// Timestamp of the last parameter set change
+    #[open_protocol_field(number = 46, length = 19)]
+    pub last_parameter_set_change: NaiveDateTime,
+
+    /// Name of the parameter set used
+    #[open_protocol_field(number = 47, length = 25)]
+    pub parameter_set_name: String,
+
+    /// The unit of torque values (1=Nm, 2=Lbf.ft, 3=Lbf.In, etc.)
+    #[open_protocol_field(number = 48, length = 1)]
+    pub torque_unit: TorqueUnit,
+
+    /// The result type (1=Tightening, 2=Loosening, etc.)
+    #[open_protocol_field(number = 49, length = 2)]
+    pub result_type: ResultType,
+
+    /// The second identifier result part
+    #[open_protocol_field(number = 50, length = 25)]
+    pub identifier_result_part_2: String,
+
+    /// The third identifier result part
+    #[open_protocol_field(number = 51, length = 25)]
+    pub identifier_result_part_3: String,
+
+    /// The fourth identifier result part
+    #[open_protocol_field(number = 52, length = 25)]
+    pub identifier_result_part_4: String,
+
+    /// Customer specific tightening error code
+    #[open_protocol_field(number = 53, length = 4)]
+    pub customer_tightening_error_code: String,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0061, revision = 6, direction = "to_integrator", ack = 62)]
+pub struct MID0061rev6 {
+    /// The cell ID of the tightening system
+    #[open_protocol_field(number = 1, length = 4)]
+    pub cell_id: u16,
+
+    /// The channel ID for the tightening
+    #[open_protocol_field(number = 2, length = 2)]
+    pub channel_id: u8,
+
+    /// The name of the torque controller
+    #[open_protocol_field(number = 3, length = 25)]
+    pub controller_name: String,
+
+    /// The Vehicle Identification Number (VIN) used in the tightening
+    #[open_protocol_field(number = 4, length = 25)]
+    pub vin_number: String,
+
+    /// The ID of the job performed
+    #[open_protocol_field(number = 5, length = 4)]
+    pub job_id: u16,
+
+    /// The parameter set ID used
+    #[open_protocol_field(number = 6, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 7, length = 2)]
+    pub strategy: u8,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 8, length = 5)]
+    pub strategy_options: u32,
+
+    /// Batch size for the tightening operation
+    #[open_protocol_field(number = 9, length = 4)]
+    pub batch_size: u16,
+
+    /// The current count of tightenings in the batch
+    #[open_protocol_field(number = 10, length = 4)]
+    pub batch_counter: u16,
+
+    /// The status of the tightening (0=NOK, 1=OK)
+    #[open_protocol_field(number = 11, length = 1)]
+    pub tightening_status: OkNok,
+
+    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
+    #[open_protocol_field(number = 12, length = 1)]
+    pub batch_status: BatchStatus,
+
+    /// The torque status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 13, length = 1)]
+    pub torque_status: LimitStatus,
+
+    /// The angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 14, length = 1)]
+    pub angle_status: LimitStatus,
+
+    /// The rundown angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 15, length = 1)]
+    pub rundown_angle_status: LimitStatus,
+
+    /// Current monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 16, length = 1)]
+    pub current_monitoring_status: LimitStatus,
+
+    /// Self-tap status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 17, length = 1)]
+    pub self_tap_status: LimitStatus,
+
+    /// Prevail torque monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 18, length = 1)]
+    pub prevail_torque_monitoring_status: LimitStatus,
+
+    /// Prevail torque compensate status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 19, length = 1)]
+    pub prevail_torque_compensate_status: LimitStatus,
+
+    /// Tightening error status
+    #[open_protocol_field(number = 20, length = 10)]
+    pub tightening_error_status: String,
+
+    /// Minimum torque limit
+    #[open_protocol_field(number = 21, length = 6)]
+    pub torque_min_limit: u32,
+
+    /// Maximum torque limit
+    #[open_protocol_field(number = 22, length = 6)]
+    pub torque_max_limit: u32,
+
+    /// Final torque target
+    #[open_protocol_field(number = 23, length = 6)]
+    pub torque_final_target: u32,
+
+    /// Achieved torque value
+    #[open_protocol_field(number = 24, length = 6)]
+    pub torque: u32,
+
+    /// Minimum angle limit
+    #[open_protocol_field(number = 25, length = 5)]
+    pub angle_min_limit: u16,
+
+    /// Maximum angle limit
+    #[open_protocol_field(number = 26, length = 5)]
+    pub angle_max_limit: u16,
+
+    /// Final angle target
+    #[open_protocol_field(number = 27, length = 5)]
+    pub angle_final_target: u16,
+
+    /// Achieved angle value
+    #[open_protocol_field(number = 28, length = 5)]
+    pub angle: u16,
+
+    /// Minimum rundown angle
+    #[open_protocol_field(number = 29, length = 5)]
+    pub rundown_angle_min: u16,
+
+    /// Maximum rundown angle
+    #[open_protocol_field(number = 30, length = 5)]
+    pub rundown_angle_max: u16,
+
+    /// Achieved rundown angle
+    #[open_protocol_field(number = 31, length = 5)]
+    pub rundown_angle: u16,
+
+    /// Minimum current monitoring value
+    #[open_protocol_field(number = 32, length = 3)]
+    pub current_monitoring_min: u16,
+
+    /// Maximum current monitoring value
+    #[open_protocol_field(number = 33, length = 3)]
+    pub current_monitoring_max: u16,
+
+    /// Achieved current monitoring value
+    #[open_protocol_field(number = 34, length = 3)]
+    pub current_monitoring_value: u16,
+
+    /// Minimum self-tap torque
+    #[open_protocol_field(number = 35, length = 6)]
+    pub self_tap_torque_min: u32,
+
+    /// Maximum self-tap torque
+    #[open_protocol_field(number = 36, length = 6)]
+    pub self_tap_torque_max: u32,
+
+    /// Achieved self-tap torque
+    #[open_protocol_field(number = 37, length = 6)]
+    pub self_tap_torque: u32,
+
+    /// Minimum prevail torque value
+    #[open_protocol_field(number = 38, length = 6)]
+    pub prevail_torque_min: u32,
+
+    /// Maximum prevail torque value
+    #[open_protocol_field(number = 39, length = 6)]
+    pub prevail_torque_max: u32,
+
+    /// Achieved prevail torque value
+    #[open_protocol_field(number = 40, length = 6)]
+    pub prevail_torque: u32,
+
+    /// The tightening ID, a unique identifier for this result
+    #[open_protocol_field(number = 41, length = 10)]
+    pub tightening_id: u32,
+
+    /// The job sequence number, a unique number for each job
+    #[open_protocol_field(number = 42, length = 5)]
+    pub job_sequence_number: u16,
+
+    /// The sync tightening ID
+    #[open_protocol_field(number = 43, length = 5)]
+    pub sync_tightening_id: u16,
+
+    /// The serial number of the tool
+    #[open_protocol_field(number = 44, length = 14)]
+    pub tool_serial_number: String,
+
+    /// Timestamp of the tightening
+    #[open_protocol_field(number = 45, length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// Timestamp of the last parameter set change
+    #[open_protocol_field(number = 46, length = 19)]
+    pub last_parameter_set_change: NaiveDateTime,
+
+    /// Name of the parameter set used
+    #[open_protocol_field(number = 47, length = 25)]
+    pub parameter_set_name: String,
+
+    /// The unit of torque values (1=Nm, 2=Lbf.ft, 3=Lbf.In, etc.)
+    #[open_protocol_field(number = 48, length = 1)]
+    pub torque_unit: TorqueUnit,
+
+    /// The result type (1=Tightening, 2=Loosening, etc.)
+    #[open_protocol_field(number = 49, length = 2)]
+    pub result_type: ResultType,
+
+    /// The second identifier result part
+    #[open_protocol_field(number = 50, length = 25)]
+    pub identifier_result_part_2: String,
+
+    /// The third identifier result part
+    #[open_protocol_field(number = 51, length = 25)]
+    pub identifier_result_part_3: String,
+
+    /// The fourth identifier result part
+    #[open_protocol_field(number = 52, length = 25)]
+    pub identifier_result_part_4: String,
+
+    /// Customer specific tightening error code
+    #[open_protocol_field(number = 53, length = 4)]
+    pub customer_tightening_error_code: String,
+
+    /// The prevail torque compensate value
+    #[open_protocol_field(number = 54, length = 6)]
+    pub prevail_torque_compensate_value: u32,
+
+    /// Tightening error status 2
+    #[open_protocol_field(number = 55, length = 10)]
+    pub tightening_error_status_2: String,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0061, revision = 7, direction = "to_integrator", ack = 62)]
+pub struct MID0061rev7 {
+    /// The cell ID of the tightening system
+    #[open_protocol_field(number = 1, length = 4)]
+    pub cell_id: u16,
+
+    /// The channel ID for the tightening
+    #[open_protocol_field(number = 2, length = 2)]
+    pub channel_id: u8,
+
+    /// The name of the torque controller
+    #[open_protocol_field(number = 3, length = 25)]
+    pub controller_name: String,
+
+    /// The Vehicle Identification Number (VIN) used in the tightening
+    #[open_protocol_field(number = 4, length = 25)]
+    pub vin_number: String,
+
+    /// The ID of the job performed
+    #[open_protocol_field(number = 5, length = 4)]
+    pub job_id: u16,
+
+    /// The parameter set ID used
+    #[open_protocol_field(number = 6, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 7, length = 2)]
+    pub strategy: u8,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 8, length = 5)]
+    pub strategy_options: u32,
+
+    /// Batch size for the tightening operation
+    #[open_protocol_field(number = 9, length = 4)]
+    pub batch_size: u16,
+
+    /// The current count of tightenings in the batch
+    #[open_protocol_field(number = 10, length = 4)]
+    pub batch_counter: u16,
+
+    /// The status of the tightening (0=NOK, 1=OK)
+    #[open_protocol_field(number = 11, length = 1)]
+    pub tightening_status: OkNok,
+
+    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
+    #[open_protocol_field(number = 12, length = 1)]
+    pub batch_status: BatchStatus,
+
+    /// The torque status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 13, length = 1)]
+    pub torque_status: LimitStatus,
+
+    /// The angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 14, length = 1)]
+    pub angle_status: LimitStatus,
+
+    /// The rundown angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 15, length = 1)]
+    pub rundown_angle_status: LimitStatus,
+
+    /// Current monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 16, length = 1)]
+    pub current_monitoring_status: LimitStatus,
+
+    /// Self-tap status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 17, length = 1)]
+    pub self_tap_status: LimitStatus,
+
+    /// Prevail torque monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 18, length = 1)]
+    pub prevail_torque_monitoring_status: LimitStatus,
+
+    /// Prevail torque compensate status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 19, length = 1)]
+    pub prevail_torque_compensate_status: LimitStatus,
+
+    /// Tightening error status
+    #[open_protocol_field(number = 20, length = 10)]
+    pub tightening_error_status: String,
+
+    /// Minimum torque limit
+    #[open_protocol_field(number = 21, length = 6)]
+    pub torque_min_limit: u32,
+
+    /// Maximum torque limit
+    #[open_protocol_field(number = 22, length = 6)]
+    pub torque_max_limit: u32,
+
+    /// Final torque target
+    #[open_protocol_field(number = 23, length = 6)]
+    pub torque_final_target: u32,
+
+    /// Achieved torque value
+    #[open_protocol_field(number = 24, length = 6)]
+    pub torque: u32,
+
+    /// Minimum angle limit
+    #[open_protocol_field(number = 25, length = 5)]
+    pub angle_min_limit: u16,
+
+    /// Maximum angle limit
+    #[open_protocol_field(number = 26, length = 5)]
+    pub angle_max_limit: u16,
+
+    /// Final angle target
+    #[open_protocol_field(number = 27, length = 5)]
+    pub angle_final_target: u16,
+
+    /// Achieved angle value
+    #[open_protocol_field(number = 28, length = 5)]
+    pub angle: u16,
+
+    /// Minimum rundown angle
+    #[open_protocol_field(number = 29, length = 5)]
+    pub rundown_angle_min: u16,
+
+    /// Maximum rundown angle
+    #[open_protocol_field(number = 30, length = 5)]
+    pub rundown_angle_max: u16,
+
+    /// Achieved rundown angle
+    #[open_protocol_field(number = 31, length = 5)]
+    pub rundown_angle: u16,
+
+    /// Minimum current monitoring value
+    #[open_protocol_field(number = 32, length = 3)]
+    pub current_monitoring_min: u16,
+
+    /// Maximum current monitoring value
+    #[open_protocol_field(number = 33, length = 3)]
+    pub current_monitoring_max: u16,
+
+    /// Achieved current monitoring value
+    #[open_protocol_field(number = 34, length = 3)]
+    pub current_monitoring_value: u16,
+
+    /// Minimum self-tap torque
+    #[open_protocol_field(number = 35, length = 6)]
+    pub self_tap_torque_min: u32,
+
+    /// Maximum self-tap torque
+    #[open_protocol_field(number = 36, length = 6)]
+    pub self_tap_torque_max: u32,
+
+    /// Achieved self-tap torque
+    #[open_protocol_field(number = 37, length = 6)]
+    pub self_tap_torque: u32,
+
+    /// Minimum prevail torque value
+    #[open_protocol_field(number = 38, length = 6)]
+    pub prevail_torque_min: u32,
+
+    /// Maximum prevail torque value
+    #[open_protocol_field(number = 39, length = 6)]
+    pub prevail_torque_max: u32,
+
+    /// Achieved prevail torque value
+    #[open_protocol_field(number = 40, length = 6)]
+    pub prevail_torque: u32,
+
+    /// The tightening ID, a unique identifier for this result
+    #[open_protocol_field(number = 41, length = 10)]
+    pub tightening_id: u32,
+
+    /// The job sequence number, a unique number for each job
+    #[open_protocol_field(number = 42, length = 5)]
+    pub job_sequence_number: u16,
+
+    /// The sync tightening ID
+    #[open_protocol_field(number = 43, length = 5)]
+    pub sync_tightening_id: u16,
+
+    /// The serial number of the tool
+    #[open_protocol_field(number = 44, length = 14)]
+    pub tool_serial_number: String,
+
+    /// Timestamp of the tightening
+    #[open_protocol_field(number = 45, length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// Timestamp of the last parameter set change
+    #[open_protocol_field(number = 46, length = 19)]
+    pub last_parameter_set_change: NaiveDateTime,
+
+    /// Name of the parameter set used
+    #[open_protocol_field(number = 47, length = 25)]
+    pub parameter_set_name: String,
+
+    /// The unit of torque values (1=Nm, 2=Lbf.ft, 3=Lbf.In, etc.)
+    #[open_protocol_field(number = 48, length = 1)]
+    pub torque_unit: TorqueUnit,
+
+    /// The result type (1=Tightening, 2=Loosening, etc.)
+    #[open_protocol_field(number = 49, length = 2)]
+    pub result_type: ResultType,
+
+    /// The second identifier result part
+    #[open_protocol_field(number = 50, length = 25)]
+    pub identifier_result_part_2: String,
+
+    /// The third identifier result part
+    #[open_protocol_field(number = 51, length = 25)]
+    pub identifier_result_part_3: String,
+
+    /// The fourth identifier result part
+    #[open_protocol_field(number = 52, length = 25)]
+    pub identifier_result_part_4: String,
+
+    /// Customer specific tightening error code
+    #[open_protocol_field(number = 53, length = 4)]
+    pub customer_tightening_error_code: String,
+
+    /// The prevail torque compensate value
+    #[open_protocol_field(number = 54, length = 6)]
+    pub prevail_torque_compensate_value: u32,
+
+    /// Tightening error status 2
+    #[open_protocol_field(number = 55, length = 10)]
+    pub tightening_error_status_2: String,
+
+    /// The compensated angle, in degrees with two decimals
+    #[open_protocol_field(number = 56, length = 7)]
+    pub compensated_angle: String,
+
+    /// The achieved angle, in degrees with two decimals
+    #[open_protocol_field(number = 57, length = 7)]
+    pub final_angle_decimal: String,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0061, revision = 998, direction = "to_integrator", ack = 62)]
+pub struct MID0061rev998 {
+    /// The cell ID of the tightening system
+    #[open_protocol_field(number = 1, length = 4)]
+    pub cell_id: u16,
+
+    /// The channel ID for the tightening
+    #[open_protocol_field(number = 2, length = 2)]
+    pub channel_id: u8,
+
+    /// The name of the torque controller
+    #[open_protocol_field(number = 3, length = 25)]
+    pub controller_name: String,
+
+    /// The Vehicle Identification Number (VIN) used in the tightening
+    #[open_protocol_field(number = 4, length = 25)]
+    pub vin_number: String,
+
+    /// The ID of the job performed
+    #[open_protocol_field(number = 5, length = 4)]
+    pub job_id: u16,
+
+    /// The parameter set ID used
+    #[open_protocol_field(number = 6, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 7, length = 2)]
+    pub strategy: u8,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 8, length = 5)]
+    pub strategy_options: u32,
+
+    /// Batch size for the tightening operation
+    #[open_protocol_field(number = 9, length = 4)]
+    pub batch_size: u16,
+
+    /// The current count of tightenings in the batch
+    #[open_protocol_field(number = 10, length = 4)]
+    pub batch_counter: u16,
+
+    /// The status of the tightening (0=NOK, 1=OK)
+    #[open_protocol_field(number = 11, length = 1)]
+    pub tightening_status: OkNok,
+
+    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
+    #[open_protocol_field(number = 12, length = 1)]
+    pub batch_status: BatchStatus,
+
+    /// The torque status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 13, length = 1)]
+    pub torque_status: LimitStatus,
+
+    /// The angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 14, length = 1)]
+    pub angle_status: LimitStatus,
+
+    /// The rundown angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 15, length = 1)]
+    pub rundown_angle_status: LimitStatus,
+
+    /// Current monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 16, length = 1)]
+    pub current_monitoring_status: LimitStatus,
+
+    /// Self-tap status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 17, length = 1)]
+    pub self_tap_status: LimitStatus,
+
+    /// Prevail torque monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 18, length = 1)]
+    pub prevail_torque_monitoring_status: LimitStatus,
+
+    /// Prevail torque compensate status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 19, length = 1)]
+    pub prevail_torque_compensate_status: LimitStatus,
+
+    /// Tightening error status
+    #[open_protocol_field(number = 20, length = 10)]
+    pub tightening_error_status: String,
+
+    /// Minimum torque limit
+    #[open_protocol_field(number = 21, length = 6)]
+    pub torque_min_limit: u32,
+
+    /// Maximum torque limit
+    #[open_protocol_field(number = 22, length = 6)]
+    pub torque_max_limit: u32,
+
+    /// Final torque target
+    #[open_protocol_field(number = 23, length = 6)]
+    pub torque_final_target: u32,
+
+    /// Achieved torque value
+    #[open_protocol_field(number = 24, length = 6)]
+    pub torque: u32,
+
+    /// Minimum angle limit
+    #[open_protocol_field(number = 25, length = 5)]
+    pub angle_min_limit: u16,
+
+    /// Maximum angle limit
+    #[open_protocol_field(number = 26, length = 5)]
+    pub angle_max_limit: u16,
+
+    /// Final angle target
+    #[open_protocol_field(number = 27, length = 5)]
+    pub angle_final_target: u16,
+
+    /// Achieved angle value
+    #[open_protocol_field(number = 28, length = 5)]
+    pub angle: u16,
+
+    /// Minimum rundown angle
+    #[open_protocol_field(number = 29, length = 5)]
+    pub rundown_angle_min: u16,
+
+    /// Maximum rundown angle
+    #[open_protocol_field(number = 30, length = 5)]
+    pub rundown_angle_max: u16,
+
+    /// Achieved rundown angle
+    #[open_protocol_field(number = 31, length = 5)]
+    pub rundown_angle: u16,
+
+    /// Minimum current monitoring value
+    #[open_protocol_field(number = 32, length = 3)]
+    pub current_monitoring_min: u16,
+
+    /// Maximum current monitoring value
+    #[open_protocol_field(number = 33, length = 3)]
+    pub current_monitoring_max: u16,
+
+    /// Achieved current monitoring value
+    #[open_protocol_field(number = 34, length = 3)]
+    pub current_monitoring_value: u16,
+
+    /// Minimum self-tap torque
+    #[open_protocol_field(number = 35, length = 6)]
+    pub self_tap_torque_min: u32,
+
+    /// Maximum self-tap torque
+    #[open_protocol_field(number = 36, length = 6)]
+    pub self_tap_torque_max: u32,
+
+    /// Achieved self-tap torque
+    #[open_protocol_field(number = 37, length = 6)]
+    pub self_tap_torque: u32,
+
+    /// Minimum prevail torque value
+    #[open_protocol_field(number = 38, length = 6)]
+    pub prevail_torque_min: u32,
+
+    /// Maximum prevail torque value
+    #[open_protocol_field(number = 39, length = 6)]
+    pub prevail_torque_max: u32,
+
+    /// Achieved prevail torque value
+    #[open_protocol_field(number = 40, length = 6)]
+    pub prevail_torque: u32,
+
+    /// The tightening ID, a unique identifier for this result
+    #[open_protocol_field(number = 41, length = 10)]
+    pub tightening_id: u32,
+
+    /// The job sequence number, a unique number for each job
+    #[open_protocol_field(number = 42, length = 5)]
+    pub job_sequence_number: u16,
+
+    /// The sync tightening ID
+    #[open_protocol_field(number = 43, length = 5)]
+    pub sync_tightening_id: u16,
+
+    /// The serial number of the tool
+    #[open_protocol_field(number = 44, length = 14)]
+    pub tool_serial_number: String,
+
+    /// Timestamp of the tightening
+    #[open_protocol_field(number = 45, length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// Timestamp of the last parameter set change
+    #[open_protocol_field(number = 46, length = 19)]
+    pub last_parameter_set_change: NaiveDateTime,
+
+    /// Name of the parameter set used
+    #[open_protocol_field(number = 47, length = 25)]
+    pub parameter_set_name: String,
+
+    /// The unit of torque values (1=Nm, 2=Lbf.ft, 3=Lbf.In, etc.)
+    #[open_protocol_field(number = 48, length = 1)]
+    pub torque_unit: TorqueUnit,
+
+    /// The result type (1=Tightening, 2=Loosening, etc.)
+    #[open_protocol_field(number = 49, length = 2)]
+    pub result_type: ResultType,
+
+    /// The second identifier result part
+    #[open_protocol_field(number = 50, length = 25)]
+    pub identifier_result_part_2: String,
+
+    /// The third identifier result part
+    #[open_protocol_field(number = 51, length = 25)]
+    pub identifier_result_part_3: String,
+
+    /// The fourth identifier result part
+    #[open_protocol_field(number = 52, length = 25)]
+    pub identifier_result_part_4: String,
+
+    /// Customer specific tightening error code
+    #[open_protocol_field(number = 53, length = 4)]
+    pub customer_tightening_error_code: String,
+
+    /// The prevail torque compensate value
+    #[open_protocol_field(number = 54, length = 6)]
+    pub prevail_torque_compensate_value: u32,
+
+    /// Tightening error status 2
+    #[open_protocol_field(number = 55, length = 10)]
+    pub tightening_error_status_2: String,
+
+    /// The compensated angle, in degrees with two decimals
+    #[open_protocol_field(number = 56, length = 7)]
+    pub compensated_angle: String,
+
+    /// The achieved angle, in degrees with two decimals
+    #[open_protocol_field(number = 57, length = 7)]
+    pub final_angle_decimal: String,
+
+    /// The number of variable data fields
+    #[open_protocol_field(number = 58, length = 3)]
+    pub number_of_data_fields: u16,
+
+    /// Variable step result and special value data fields (PIDs)
+    #[open_protocol_field(list, amount = "number_of_data_fields")]
+    pub data_fields: Vec<DataField>,
+}
+
+// MID0062 (Acknowledgment for MID0061 tightening results)
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0062, revision = 1, reply = "none")]
+pub struct MID0062rev1 {
+    // Acknowledgment for tightening result data
+    // No fields for this revision
+}
+
+// MID0063 (Unsubscribe from tightening results)
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0063, revision = 1)]
+pub struct MID0063rev1 {
+    // Unsubscribe from tightening result data
+    // No fields for this revision
+}
+
+// MID0064 (Old tightening result upload request)
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0064, revision = 1, reply = 65)]
+pub struct MID0064rev1 {
+    /// Request a specific tightening result by its ID
+    #[open_protocol_field(number = 1, length = 10)]
+    pub tightening_id: u32,
+}
+
+/// Requests revision 2 of the old tightening result.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0064, revision = 2, reply = 65)]
+pub struct MID0064rev2 {
+    /// Request a specific tightening result by its ID
+    #[open_protocol_field(number = 1, length = 10)]
+    pub tightening_id: u32,
+}
+
+/// Requests revision 3 of the old tightening result.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0064, revision = 3, reply = 65)]
+pub struct MID0064rev3 {
+    /// Request a specific tightening result by its ID
+    #[open_protocol_field(number = 1, length = 10)]
+    pub tightening_id: u32,
+}
+
+/// Requests revision 4 of the old tightening result.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0064, revision = 4, reply = 65)]
+pub struct MID0064rev4 {
+    /// Request a specific tightening result by its ID
+    #[open_protocol_field(number = 1, length = 10)]
+    pub tightening_id: u32,
+}
+
+/// Requests revision 5 of the old tightening result.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0064, revision = 5, reply = 65)]
+pub struct MID0064rev5 {
+    /// Request a specific tightening result by its ID
+    #[open_protocol_field(number = 1, length = 10)]
+    pub tightening_id: u32,
+}
+
+/// Requests revision 6 of the old tightening result.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0064, revision = 6, reply = 65)]
+pub struct MID0064rev6 {
+    /// Request a specific tightening result by its ID
+    #[open_protocol_field(number = 1, length = 10)]
+    pub tightening_id: u32,
+}
+
+/// Requests revision 7 of the old tightening result.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0064, revision = 7, reply = 65)]
+pub struct MID0064rev7 {
+    /// Request a specific tightening result by its ID
+    #[open_protocol_field(number = 1, length = 10)]
+    pub tightening_id: u32,
+}
+
+// MID0065 (Old tightening result upload reply)
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0065, revision = 1, direction = "to_integrator")]
+pub struct MID0065rev1 {
+    /// The tightening ID of the requested result
+    #[open_protocol_field(number = 1, length = 10)]
+    pub tightening_id: u32,
+
+    /// The Vehicle Identification Number (VIN) used in the tightening
+    #[open_protocol_field(number = 2, length = 25)]
+    pub vin_number: String,
+
+    /// The parameter set ID used
+    #[open_protocol_field(number = 3, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// The current batch counter
+    #[open_protocol_field(number = 4, length = 4)]
+    pub batch_counter: u16,
+
+    /// The status of the tightening (0=NOK, 1=OK)
+    #[open_protocol_field(number = 5, length = 1)]
+    pub tightening_status: OkNok,
+
+    /// The torque status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 6, length = 1)]
+    pub torque_status: LimitStatus,
+
+    /// The angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 7, length = 1)]
+    pub angle_status: LimitStatus,
+
+    /// The achieved torque value
+    #[open_protocol_field(number = 8, length = 6)]
+    pub torque: u32,
+
+    /// The achieved angle value
+    #[open_protocol_field(number = 9, length = 5)]
+    pub angle: u16,
+
+    /// Timestamp of the tightening
+    #[open_protocol_field(number = 10, length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
+    #[open_protocol_field(number = 11, length = 1)]
+    pub batch_status: BatchStatus,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0065, revision = 2, direction = "to_integrator")]
+pub struct MID0065rev2 {
+    /// The tightening ID of the requested result
+    #[open_protocol_field(number = 1, length = 10)]
+    pub tightening_id: u32,
+
+    /// The Vehicle Identification Number (VIN) used in the tightening
+    #[open_protocol_field(number = 2, length = 25)]
+    pub vin_number: String,
+
+    /// The parameter set ID used
+    #[open_protocol_field(number = 3, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 4, length = 2)]
+    pub strategy: u8,
+
+    /// Strategy options used in the tightening
+    #[open_protocol_field(number = 5, length = 5)]
+    pub strategy_options: u32,
+
+    /// The current batch counter
+    #[open_protocol_field(number = 6, length = 4)]
+    pub batch_counter: u16,
+
+    /// The status of the tightening (0=NOK, 1=OK)
+    #[open_protocol_field(number = 7, length = 1)]
+    pub tightening_status: OkNok,
+
+    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
+    #[open_protocol_field(number = 8, length = 1)]
+    pub batch_status: BatchStatus,
+
+    /// The torque status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 9, length = 1)]
+    pub torque_status: LimitStatus,
+
+    /// The angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 10, length = 1)]
+    pub angle_status: LimitStatus,
+
+    /// The rundown angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 11, length = 1)]
+    pub rundown_angle_status: LimitStatus,
+
+    /// Current monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 12, length = 1)]
+    pub current_monitoring_status: LimitStatus,
+
+    /// Self-tap status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 13, length = 1)]
+    pub self_tap_status: LimitStatus,
+
+    /// Prevail torque monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 14, length = 1)]
+    pub prevail_torque_monitoring_status: LimitStatus,
+
+    /// Prevail torque compensate status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 15, length = 1)]
+    pub prevail_torque_compensate_status: LimitStatus,
+
+    /// Tightening error status
+    #[open_protocol_field(number = 16, length = 10)]
+    pub tightening_error_status: String,
+
+    /// The achieved torque value
+    #[open_protocol_field(number = 17, length = 6)]
+    pub torque: u32,
+
+    /// The achieved angle value
+    #[open_protocol_field(number = 18, length = 5)]
+    pub angle: u16,
+
+    /// Achieved rundown angle
+    #[open_protocol_field(number = 19, length = 5)]
+    pub rundown_angle: u16,
+
+    /// Achieved current monitoring value
+    #[open_protocol_field(number = 20, length = 3)]
+    pub current_monitoring_value: u16,
+
+    /// Achieved self-tap torque
+    #[open_protocol_field(number = 21, length = 6)]
+    pub self_tap_torque: u32,
+
+    /// Achieved prevail torque value
+    #[open_protocol_field(number = 22, length = 6)]
+    pub prevail_torque: u32,
+
+    /// The job sequence number, a unique number for each job
+    #[open_protocol_field(number = 23, length = 5)]
+    pub job_sequence_number: u16,
+
+    /// The sync tightening ID
+    #[open_protocol_field(number = 24, length = 5)]
+    pub sync_tightening_id: u16,
+
+    /// The serial number of the tool
+    #[open_protocol_field(number = 25, length = 14)]
+    pub tool_serial_number: String,
+
+    /// Timestamp of the tightening
+    #[open_protocol_field(number = 26, length = 19)]
+    pub timestamp: NaiveDateTime,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0065, revision = 3, direction = "to_integrator")]
+pub struct MID0065rev3 {
+    /// The tightening ID of the requested result
+    #[open_protocol_field(number = 1, length = 10)]
+    pub tightening_id: u32,
+
+    /// The Vehicle Identification Number (VIN) used in the tightening
+    #[open_protocol_field(number = 2, length = 25)]
+    pub vin_number: String,
+
+    /// The parameter set ID used
+    #[open_protocol_field(number = 3, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 4, length = 2)]
+    pub strategy: u8,
+
+    /// Strategy options used in the tightening
+    #[open_protocol_field(number = 5, length = 5)]
+    pub strategy_options: u32,
+
+    /// The current batch counter
+    #[open_protocol_field(number = 6, length = 4)]
+    pub batch_counter: u16,
+
+    /// The status of the tightening (0=NOK, 1=OK)
+    #[open_protocol_field(number = 7, length = 1)]
+    pub tightening_status: OkNok,
+
+    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
+    #[open_protocol_field(number = 8, length = 1)]
+    pub batch_status: BatchStatus,
+
+    /// The torque status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 9, length = 1)]
+    pub torque_status: LimitStatus,
+
+    /// The angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 10, length = 1)]
+    pub angle_status: LimitStatus,
+
+    /// The rundown angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 11, length = 1)]
+    pub rundown_angle_status: LimitStatus,
+
+    /// Current monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 12, length = 1)]
+    pub current_monitoring_status: LimitStatus,
+
+    /// Self-tap status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 13, length = 1)]
+    pub self_tap_status: LimitStatus,
+
+    /// Prevail torque monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 14, length = 1)]
+    pub prevail_torque_monitoring_status: LimitStatus,
+
+    /// Prevail torque compensate status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 15, length = 1)]
+    pub prevail_torque_compensate_status: LimitStatus,
+
+    /// Tightening error status
+    #[open_protocol_field(number = 16, length = 10)]
+    pub tightening_error_status: String,
+
+    /// The achieved torque value
+    #[open_protocol_field(number = 17, length = 6)]
+    pub torque: u32,
+
+    /// The achieved angle value
+    #[open_protocol_field(number = 18, length = 5)]
+    pub angle: u16,
+
+    /// Achieved rundown angle
+    #[open_protocol_field(number = 19, length = 5)]
+    pub rundown_angle: u16,
+
+    /// Achieved current monitoring value
+    #[open_protocol_field(number = 20, length = 3)]
+    pub current_monitoring_value: u16,
+
+    /// Achieved self-tap torque
+    #[open_protocol_field(number = 21, length = 6)]
+    pub self_tap_torque: u32,
+
+    /// Achieved prevail torque value
+    #[open_protocol_field(number = 22, length = 6)]
+    pub prevail_torque: u32,
+
+    /// The job sequence number, a unique number for each job
+    #[open_protocol_field(number = 23, length = 5)]
+    pub job_sequence_number: u16,
+
+    /// The sync tightening ID
+    #[open_protocol_field(number = 24, length = 5)]
+    pub sync_tightening_id: u16,
+
+    /// The serial number of the tool
+    #[open_protocol_field(number = 25, length = 14)]
+    pub tool_serial_number: String,
+
+    /// Timestamp of the tightening
+    #[open_protocol_field(number = 26, length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// Name of the parameter set used
+    #[open_protocol_field(number = 27, length = 25)]
+    pub parameter_set_name: String,
+
+    /// The unit of torque values (1=Nm, 2=Lbf.ft, 3=Lbf.In, etc.)
+    #[open_protocol_field(number = 28, length = 1)]
+    pub torque_unit: TorqueUnit,
+
+    /// The result type (1=Tightening, 2=Loosening, etc.)
+    #[open_protocol_field(number = 29, length = 2)]
+    pub result_type: ResultType,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0065, revision = 4, direction = "to_integrator")]
+pub struct MID0065rev4 {
+    /// The tightening ID of the requested result
+    #[open_protocol_field(number = 1, length = 10)]
+    pub tightening_id: u32,
+
+    /// The Vehicle Identification Number (VIN) used in the tightening
+    #[open_protocol_field(number = 2, length = 25)]
+    pub vin_number: String,
+
+    /// The parameter set ID used
+    #[open_protocol_field(number = 3, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 4, length = 2)]
+    pub strategy: u8,
+
+    /// Strategy options used in the tightening
+    #[open_protocol_field(number = 5, length = 5)]
+    pub strategy_options: u32,
+
+    /// The current batch counter
+    #[open_protocol_field(number = 6, length = 4)]
+    pub batch_counter: u16,
+
+    /// The status of the tightening (0=NOK, 1=OK)
+    #[open_protocol_field(number = 7, length = 1)]
+    pub tightening_status: OkNok,
+
+    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
+    #[open_protocol_field(number = 8, length = 1)]
+    pub batch_status: BatchStatus,
+
+    /// The torque status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 9, length = 1)]
+    pub torque_status: LimitStatus,
+
+    /// The angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 10, length = 1)]
+    pub angle_status: LimitStatus,
+
+    /// The rundown angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 11, length = 1)]
+    pub rundown_angle_status: LimitStatus,
+
+    /// Current monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 12, length = 1)]
+    pub current_monitoring_status: LimitStatus,
+
+    /// Self-tap status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 13, length = 1)]
+    pub self_tap_status: LimitStatus,
+
+    /// Prevail torque monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 14, length = 1)]
+    pub prevail_torque_monitoring_status: LimitStatus,
+
+    /// Prevail torque compensate status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 15, length = 1)]
+    pub prevail_torque_compensate_status: LimitStatus,
+
+    /// Tightening error status
+    #[open_protocol_field(number = 16, length = 10)]
+    pub tightening_error_status: String,
+
+    /// The achieved torque value
+    #[open_protocol_field(number = 17, length = 6)]
+    pub torque: u32,
+
+    /// The achieved angle value
+    #[open_protocol_field(number = 18, length = 5)]
+    pub angle: u16,
+
+    /// Achieved rundown angle
+    #[open_protocol_field(number = 19, length = 5)]
+    pub rundown_angle: u16,
+
+    /// Achieved current monitoring value
+    #[open_protocol_field(number = 20, length = 3)]
+    pub current_monitoring_value: u16,
+
+    /// Achieved self-tap torque
+    #[open_protocol_field(number = 21, length = 6)]
+    pub self_tap_torque: u32,
+
+    /// Achieved prevail torque value
+    #[open_protocol_field(number = 22, length = 6)]
+    pub prevail_torque: u32,
+
+    /// The job sequence number, a unique number for each job
+    #[open_protocol_field(number = 23, length = 5)]
+    pub job_sequence_number: u16,
+
+    /// The sync tightening ID
+    #[open_protocol_field(number = 24, length = 5)]
+    pub sync_tightening_id: u16,
+
+    /// The serial number of the tool
+    #[open_protocol_field(number = 25, length = 14)]
+    pub tool_serial_number: String,
+
+    /// Timestamp of the tightening
+    #[open_protocol_field(number = 26, length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// Name of the parameter set used
+    #[open_protocol_field(number = 27, length = 25)]
+    pub parameter_set_name: String,
+
+    /// The unit of torque values (1=Nm, 2=Lbf.ft, 3=Lbf.In, etc.)
+    #[open_protocol_field(number = 28, length = 1)]
+    pub torque_unit: TorqueUnit,
+
+    /// The result type (1=Tightening, 2=Loosening, etc.)
+    #[open_protocol_field(number = 29, length = 2)]
+    pub result_type: ResultType,
+
+    /// The second identifier result part
+    #[open_protocol_field(number = 30, length = 25)]
+    pub identifier_result_part_2: String,
+
+    /// The third identifier result part
+    #[open_protocol_field(number = 31, length = 25)]
+    pub identifier_result_part_3: String,
+
+    /// The fourth identifier result part
+    #[open_protocol_field(number = 32, length = 25)]
+    pub identifier_result_part_4: String,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0065, revision = 5, direction = "to_integrator")]
+pub struct MID0065rev5 {
+    /// The tightening ID of the requested result
+    #[open_protocol_field(number = 1, length = 10)]
+    pub tightening_id: u32,
+
+    /// The Vehicle Identification Number (VIN) used in the tightening
+    #[open_protocol_field(number = 2, length = 25)]
+    pub vin_number: String,
+
+    /// The parameter set ID used
+    #[open_protocol_field(number = 3, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 4, length = 2)]
+    pub strategy: u8,
+
+    /// Strategy options used in the tightening
+    #[open_protocol_field(number = 5, length = 5)]
+    pub strategy_options: u32,
+
+    /// The current batch counter
+    #[open_protocol_field(number = 6, length = 4)]
+    pub batch_counter: u16,
+
+    /// The status of the tightening (0=NOK, 1=OK)
+    #[open_protocol_field(number = 7, length = 1)]
+    pub tightening_status: OkNok,
+
+    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
+    #[open_protocol_field(number = 8, length = 1)]
+    pub batch_status: BatchStatus,
+
+    /// The torque status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 9, length = 1)]
+    pub torque_status: LimitStatus,
+
+    /// The angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 10, length = 1)]
+    pub angle_status: LimitStatus,
+
+    /// The rundown angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 11, length = 1)]
+    pub rundown_angle_status: LimitStatus,
+
+    /// Current monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 12, length = 1)]
+    pub current_monitoring_status: LimitStatus,
+
+    /// Self-tap status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 13, length = 1)]
+    pub self_tap_status: LimitStatus,
+
+    /// Prevail torque monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 14, length = 1)]
+    pub prevail_torque_monitoring_status: LimitStatus,
+
+    /// Prevail torque compensate status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 15, length = 1)]
+    pub prevail_torque_compensate_status: LimitStatus,
+
+    /// Tightening error status
+    #[open_protocol_field(number = 16, length = 10)]
+    pub tightening_error_status: String,
+
+    /// The achieved torque value
+    #[open_protocol_field(number = 17, length = 6)]
+    pub torque: u32,
+
+    /// The achieved angle value
+    #[open_protocol_field(number = 18, length = 5)]
+    pub angle: u16,
+
+    /// Achieved rundown angle
+    #[open_protocol_field(number = 19, length = 5)]
+    pub rundown_angle: u16,
+
+    /// Achieved current monitoring value
+    #[open_protocol_field(number = 20, length = 3)]
+    pub current_monitoring_value: u16,
+
+    /// Achieved self-tap torque
+    #[open_protocol_field(number = 21, length = 6)]
+    pub self_tap_torque: u32,
+
+    /// Achieved prevail torque value
+    #[open_protocol_field(number = 22, length = 6)]
+    pub prevail_torque: u32,
+
+    /// The job sequence number, a unique number for each job
+    #[open_protocol_field(number = 23, length = 5)]
+    pub job_sequence_number: u16,
+
+    /// The sync tightening ID
+    #[open_protocol_field(number = 24, length = 5)]
+    pub sync_tightening_id: u16,
+
+    /// The serial number of the tool
+    #[open_protocol_field(number = 25, length = 14)]
+    pub tool_serial_number: String,
+
+    /// Timestamp of the tightening
+    #[open_protocol_field(number = 26, length = 19)]
+    pub timestamp: NaiveDateTime,
+
+    /// Name of the parameter set used
+    #[open_protocol_field(number = 27, length = 25)]
+    pub parameter_set_name: String,
+
+    /// The unit of torque values (1=Nm, 2=Lbf.ft, 3=Lbf.In, etc.)
+    #[open_protocol_field(number = 28, length = 1)]
+    pub torque_unit: TorqueUnit,
+
+    /// The result type (1=Tightening, 2=Loosening, etc.)
+    #[open_protocol_field(number = 29, length = 2)]
+    pub result_type: ResultType,
+
+    /// The second identifier result part
+    #[open_protocol_field(number = 30, length = 25)]
+    pub identifier_result_part_2: String,
+
+    /// The third identifier result part
+    #[open_protocol_field(number = 31, length = 25)]
+    pub identifier_result_part_3: String,
+
+    /// The fourth identifier result part
+    #[open_protocol_field(number = 32, length = 25)]
+    pub identifier_result_part_4: String,
+
+    /// Customer specific tightening error code
+    #[open_protocol_field(number = 33, length = 4)]
+    pub customer_tightening_error_code: String,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0065, revision = 6, direction = "to_integrator")]
+pub struct MID0065rev6 {
+    /// The tightening ID of the requested result
+    #[open_protocol_field(number = 1, length = 10)]
+    pub tightening_id: u32,
+
+    /// The Vehicle Identification Number (VIN) used in the tightening
+    #[open_protocol_field(number = 2, length = 25)]
+    pub vin_number: String,
+
+    /// The parameter set ID used
+    #[open_protocol_field(number = 3, length = 3)]
+    pub parameter_set_id: u16,
+
+    /// Strategy used in the tightening
+    #[open_protocol_field(number = 4, length = 2)]
+    pub strategy: u8,
+
+    /// Strategy options used in the tightening
+    #[open_protocol_field(number = 5, length = 5)]
+    pub strategy_options: u32,
+
+    /// The current batch counter
+    #[open_protocol_field(number = 6, length = 4)]
+    pub batch_counter: u16,
+
+    /// The status of the tightening (0=NOK, 1=OK)
+    #[open_protocol_field(number = 7, length = 1)]
+    pub tightening_status: OkNok,
+
+    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
+    #[open_protocol_field(number = 8, length = 1)]
+    pub batch_status: BatchStatus,
+
+    /// The torque status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 9, length = 1)]
+    pub torque_status: LimitStatus,
+
+    /// The angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 10, length = 1)]
+    pub angle_status: LimitStatus,
+
+    /// The rundown angle status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 11, length = 1)]
+    pub rundown_angle_status: LimitStatus,
+
+    /// Current monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 12, length = 1)]
+    pub current_monitoring_status: LimitStatus,
+
+    /// Self-tap status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 13, length = 1)]
+    pub self_tap_status: LimitStatus,
+
+    /// Prevail torque monitoring status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 14, length = 1)]
+    pub prevail_torque_monitoring_status: LimitStatus,
+
+    /// Prevail torque compensate status (0=Low, 1=OK, 2=High)
+    #[open_protocol_field(number = 15, length = 1)]
+    pub prevail_torque_compensate_status: LimitStatus,
+
+    /// Tightening error status
+    #[open_protocol_field(number = 16, length = 10)]
+    pub tightening_error_status: String,
+
+    /// The achieved torque value
+    #[open_protocol_field(number = 17, length = 6)]
+    pub torque: u32,
+
+    /// The achieved angle value
+    #[open_protocol_field(number = 18, length = 5)]
+    pub angle: u16,
 
-    /// The total number of tightenings required in the batch
-    #[open_protocol_field(number = 7, length = 4)]
-    pub batch_size: u16,
+    /// Achieved rundown angle
+    #[open_protocol_field(number = 19, length = 5)]
+    pub rundown_angle: u16,
 
-    /// The current batch counter
-    #[open_protocol_field(number = 8, length = 4)]
-    pub batch_counter: u16,
+    /// Achieved current monitoring value
+    #[open_protocol_field(number = 20, length = 3)]
+    pub current_monitoring_value: u16,
 
-    /// The status of the tightening (0=NOK, 1=OK)
-    #[open_protocol_field(number = 9, length = 1)]
-    pub tightening_status: u8,
+    /// Achieved self-tap torque
+    #[open_protocol_field(number = 21, length = 6)]
+    pub self_tap_torque: u32,
 
-    /// The torque status (0=Low, 1=OK, 2=High)
-    #[open_protocol_field(number = 10, length = 1)]
-    pub torque_status: u8,
+    /// Achieved prevail torque value
+    #[open_protocol_field(number = 22, length = 6)]
+    pub prevail_torque: u32,
 
-    /// The angle status (0=Low, 1=OK, 2=High)
-    #[open_protocol_field(number = 11, length = 1)]
-    pub angle_status: u8,
+    /// The job sequence number, a unique number for each job
+    #[open_protocol_field(number = 23, length = 5)]
+    pub job_sequence_number: u16,
 
-    /// The minimum torque limit
-    #[open_protocol_field(number = 12, length = 6)]
-    pub torque_min_limit: u32,
+    /// The sync tightening ID
+    #[open_protocol_field(number = 24, length = 5)]
+    pub sync_tightening_id: u16,
 
-    /// The maximum torque limit
-    #[open_protocol_field(number = 13, length = 6)]
-    pub torque_max_limit: u32,
+    /// The serial number of the tool
+    #[open_protocol_field(number = 25, length = 14)]
+    pub tool_serial_number: String,
 
-    /// The final torque target
-    #[open_protocol_field(number = 14, length = 6)]
-    pub torque_final_target: u32,
+    /// Timestamp of the tightening
+    #[open_protocol_field(number = 26, length = 19)]
+    pub timestamp: NaiveDateTime,
 
-    /// The achieved torque value
-    #[open_protocol_field(number = 15, length = 6)]
-    pub torque: u32,
+    /// Name of the parameter set used
+    #[open_protocol_field(number = 27, length = 25)]
+    pub parameter_set_name: String,
 
-    /// The minimum angle limit
-    #[open_protocol_field(number = 16, length = 5)]
-    pub angle_min_limit: u16,
+    /// The unit of torque values (1=Nm, 2=Lbf.ft, 3=Lbf.In, etc.)
+    #[open_protocol_field(number = 28, length = 1)]
+    pub torque_unit: TorqueUnit,
 
-    /// The maximum angle limit
-    #[open_protocol_field(number = 17, length = 5)]
-    pub angle_max_limit: u16,
+    /// The result type (1=Tightening, 2=Loosening, etc.)
+    #[open_protocol_field(number = 29, length = 2)]
+    pub result_type: ResultType,
 
-    /// The final angle target
-    #[open_protocol_field(number = 18, length = 5)]
-    pub angle_final_target: u16,
+    /// The second identifier result part
+    #[open_protocol_field(number = 30, length = 25)]
+    pub identifier_result_part_2: String,
 
-    /// The achieved angle value
-    #[open_protocol_field(number = 19, length = 5)]
-    pub angle: u16,
+    /// The third identifier result part
+    #[open_protocol_field(number = 31, length = 25)]
+    pub identifier_result_part_3: String,
 
-    /// Timestamp of the tightening
-    #[open_protocol_field(number = 20, length = 19)]
-    pub timestamp: DateTime<Local>,
+    /// The fourth identifier result part
+    #[open_protocol_field(number = 32, length = 25)]
+    pub identifier_result_part_4: String,
 
-    /// Timestamp of the last parameter set change
-    #[open_protocol_field(number = 21, length = 19)]
-    pub last_parameter_set_change: DateTime<Local>,
+    /// Customer specific tightening error code
+    #[open_protocol_field(number = 33, length = 4)]
+    pub customer_tightening_error_code: String,
 
-    /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
-    #[open_protocol_field(number = 22, length = 1)]
-    pub batch_status: u8,
+    /// The prevail torque compensate value
+    #[open_protocol_field(number = 34, length = 6)]
+    pub prevail_torque_compensate_value: u32,
 
-    /// The tightening ID, a unique identifier for each result
-    #[open_protocol_field(number = 23, length = 10)]
-    pub tightening_id: u32,
+    /// Tightening error status 2
+    #[open_protocol_field(number = 35, length = 10)]
+    pub tightening_error_status_2: String,
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0061, revision = 2)]
-pub struct MID0061rev2 {
-    /// The cell ID of the tightening system
-    #[open_protocol_field(number = 1, length = 4)]
-    pub cell_id: u16,
-
-    /// The channel ID for the tightening
-    #[open_protocol_field(number = 2, length = 2)]
-    pub channel_id: u8,
-
-    /// The name of the torque controller
-    #[open_protocol_field(number = 3, length = 25)]
-    pub controller_name: String,
+#[open_protocol_message(MID = 0065, revision = 7, direction = "to_integrator")]
+pub struct MID0065rev7 {
+    /// The tightening ID of the requested result
+    #[open_protocol_field(number = 1, length = 10)]
+    pub tightening_id: u32,
 
     /// The Vehicle Identification Number (VIN) used in the tightening
-    #[open_protocol_field(number = 4, length = 25)]
+    #[open_protocol_field(number = 2, length = 25)]
     pub vin_number: String,
 
-    /// The ID of the job performed
-    #[open_protocol_field(number = 5, length = 4)]
-    pub job_id: u16,
-
     /// The parameter set ID used
-    #[open_protocol_field(number = 6, length = 3)]
+    #[open_protocol_field(number = 3, length = 3)]
     pub parameter_set_id: u16,
 
     /// Strategy used in the tightening
-    #[open_protocol_field(number = 7, length = 2)]
+    #[open_protocol_field(number = 4, length = 2)]
     pub strategy: u8,
 
-    /// Strategy used in the tightening
-    #[open_protocol_field(number = 8, length = 5)]
+    /// Strategy options used in the tightening
+    #[open_protocol_field(number = 5, length = 5)]
     pub strategy_options: u32,
 
-    /// Batch size for the tightening operation
-    #[open_protocol_field(number = 9, length = 4)]
-    pub batch_size: u16,
-
-    /// The current count of tightenings in the batch
-    #[open_protocol_field(number = 10, length = 4)]
+    /// The current batch counter
+    #[open_protocol_field(number = 6, length = 4)]
     pub batch_counter: u16,
 
     /// The status of the tightening (0=NOK, 1=OK)
-    #[open_protocol_field(number = 11, length = 1)]
-    pub tightening_status: u8,
+    #[open_protocol_field(number = 7, length = 1)]
+    pub tightening_status: OkNok,
 
     /// The batch status (0=NOK, 1=OK, 2=Not used, 3=Running)
-    #[open_protocol_field(number = 12, length = 1)]
-    pub batch_status: u8,
+    #[open_protocol_field(number = 8, length = 1)]
+    pub batch_status: BatchStatus,
 
     /// The torque status (0=Low, 1=OK, 2=High)
-    #[open_protocol_field(number = 13, length = 1)]
-    pub torque_status: u8,
+    #[open_protocol_field(number = 9, length = 1)]
+    pub torque_status: LimitStatus,
 
     /// The angle status (0=Low, 1=OK, 2=High)
-    #[open_protocol_field(number = 14, length = 1)]
-    pub angle_status: u8,
+    #[open_protocol_field(number = 10, length = 1)]
+    pub angle_status: LimitStatus,
 
     /// The rundown angle status (0=Low, 1=OK, 2=High)
-    #[open_protocol_field(number = 15, length = 1)]
-    pub rundown_angle_status: u8,
+    #[open_protocol_field(number = 11, length = 1)]
+    pub rundown_angle_status: LimitStatus,
 
     /// Current monitoring status (0=Low, 1=OK, 2=High)
-    #[open_protocol_field(number = 16, length = 1)]
-    pub current_monitoring_status: u8,
+    #[open_protocol_field(number = 12, length = 1)]
+    pub current_monitoring_status: LimitStatus,
 
     /// Self-tap status (0=Low, 1=OK, 2=High)
-    #[open_protocol_field(number = 17, length = 1)]
-    pub self_tap_status: u8,
+    #[open_protocol_field(number = 13, length = 1)]
+    pub self_tap_status: LimitStatus,
 
     /// Prevail torque monitoring status (0=Low, 1=OK, 2=High)
-    #[open_protocol_field(number = 18, length = 1)]
-    pub prevail_torque_monitoring_status: u8,
+    #[open_protocol_field(number = 14, length = 1)]
+    pub prevail_torque_monitoring_status: LimitStatus,
 
     /// Prevail torque compensate status (0=Low, 1=OK, 2=High)
-    #[open_protocol_field(number = 19, length = 1)]
-    pub prevail_torque_compensate_status: u8,
+    #[open_protocol_field(number = 15, length = 1)]
+    pub prevail_torque_compensate_status: LimitStatus,
 
     /// Tightening error status
-    #[open_protocol_field(number = 20, length = 10)]
+    #[open_protocol_field(number = 16, length = 10)]
     pub tightening_error_status: String,
 
-    /// Minimum torque limit
-    #[open_protocol_field(number = 21, length = 6)]
-    pub torque_min_limit: u32,
-
-    /// Maximum torque limit
-    #[open_protocol_field(number = 22, length = 6)]
-    pub torque_max_limit: u32,
-
-    /// Final torque target
-    #[open_protocol_field(number = 23, length = 6)]
-    pub torque_final_target: u32,
-
-    /// Achieved torque value
-    #[open_protocol_field(number = 24, length = 6)]
+    /// The achieved torque value
+    #[open_protocol_field(number = 17, length = 6)]
     pub torque: u32,
 
-    /// Minimum angle limit
-    #[open_protocol_field(number = 25, length = 5)]
-    pub angle_min_limit: u16,
-
-    /// Maximum angle limit
-    #[open_protocol_field(number = 26, length = 5)]
-    pub angle_max_limit: u16,
-
-    /// Final angle target
-    #[open_protocol_field(number = 27, length = 5)]
-    pub angle_final_target: u16,
-
-    /// Achieved angle value
-    #[open_protocol_field(number = 28, length = 5)]
+    /// The achieved angle value
+    #[open_protocol_field(number = 18, length = 5)]
     pub angle: u16,
 
-    /// Minimum rundown angle
-    #[open_protocol_field(number = 29, length = 5)]
-    pub rundown_angle_min: u16,
-
-    /// Maximum rundown angle
-    #[open_protocol_field(number = 30, length = 5)]
-    pub rundown_angle_max: u16,
-
     /// Achieved rundown angle
-    #[open_protocol_field(number = 31, length = 5)]
+    #[open_protocol_field(number = 19, length = 5)]
     pub rundown_angle: u16,
 
-    /// Minimum current monitoring value
-    #[open_protocol_field(number = 32, length = 3)]
-    pub current_monitoring_min: u16,
-
-    /// Maximum current monitoring value
-    #[open_protocol_field(number = 33, length = 3)]
-    pub current_monitoring_max: u16,
-
     /// Achieved current monitoring value
-    #[open_protocol_field(number = 34, length = 3)]
+    #[open_protocol_field(number = 20, length = 3)]
     pub current_monitoring_value: u16,
 
-    /// Minimum self-tap torque
-    #[open_protocol_field(number = 35, length = 6)]
-    pub self_tap_torque_min: u32,
-
-    /// Maximum self-tap torque
-    #[open_protocol_field(number = 36, length = 6)]
-    pub self_tap_torque_max: u32,
-
     /// Achieved self-tap torque
-    #[open_protocol_field(number = 37, length = 6)]
+    #[open_protocol_field(number = 21, length = 6)]
     pub self_tap_torque: u32,
 
-    /// Minimum prevail torque value
-    #[open_protocol_field(number = 38, length = 6)]
-    pub prevail_torque_min: u32,
-
-    /// Maximum prevail torque value
-    #[open_protocol_field(number = 39, length = 6)]
-    pub prevail_torque_max: u32,
-
     /// Achieved prevail torque value
-    #[open_protocol_field(number = 40, length = 6)]
+    #[open_protocol_field(number = 22, length = 6)]
     pub prevail_torque: u32,
 
-    /// The tightening ID, a unique identifier for this result
-    #[open_protocol_field(number = 41, length = 10)]
-    pub tightening_id: u32,
-
     /// The job sequence number, a unique number for each job
-    #[open_protocol_field(number = 42, length = 5)]
+    #[open_protocol_field(number = 23, length = 5)]
     pub job_sequence_number: u16,
 
     /// The sync tightening ID
-    #[open_protocol_field(number = 43, length = 5)]
+    #[open_protocol_field(number = 24, length = 5)]
     pub sync_tightening_id: u16,
 
     /// The serial number of the tool
-    #[open_protocol_field(number = 44, length = 14)]
+    #[open_protocol_field(number = 25, length = 14)]
     pub tool_serial_number: String,
 
     /// Timestamp of the tightening
-    #[open_protocol_field(number = 45, length = 19)]
-    pub timestamp: DateTime<Local>,
-
-    /// Timestamp of the last parameter set change
-    #[open_protocol_field(number = 46, length = 19)]
-    pub last_parameter_set_change: DateTime<Local>,
-}
+    #[open_protocol_field(number = 26, length = 19)]
+    pub timestamp: NaiveDateTime,
 
-#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0061, revision = 3)]
-pub struct MID0061rev3 {
     /// Name of the parameter set used
-    #[open_protocol_field(number = 47, length = 25)]
+    #[open_protocol_field(number = 27, length = 25)]
     pub parameter_set_name: String,
 
     /// The unit of torque values (1=Nm, 2=Lbf.ft, 3=Lbf.In, etc.)
-    #[open_protocol_field(number = 48, length = 1)]
-    pub torque_unit: u8,
+    #[open_protocol_field(number = 28, length = 1)]
+    pub torque_unit: TorqueUnit,
 
     /// The result type (1=Tightening, 2=Loosening, etc.)
-    #[open_protocol_field(number = 49, length = 2)]
-    pub result_type: u8,
-}
+    #[open_protocol_field(number = 29, length = 2)]
+    pub result_type: ResultType,
 
-// MID0062 (Acknowledgment for MID0061 tightening results)
-#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0062, revision = 1)]
-pub struct MID0062rev1 {
-    // Acknowledgment for tightening result data
-    // No fields for this revision
-}
+    /// The second identifier result part
+    #[open_protocol_field(number = 30, length = 25)]
+    pub identifier_result_part_2: String,
 
-// MID0063 (Unsubscribe from tightening results)
-#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0063, revision = 1)]
-pub struct MID0063rev1 {
-    // Unsubscribe from tightening result data
-    // No fields for this revision
-}
+    /// The third identifier result part
+    #[open_protocol_field(number = 31, length = 25)]
+    pub identifier_result_part_3: String,
 
-// MID0064 (Old tightening result upload request)
-#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0064, revision = 1)]
-pub struct MID0064rev1 {
-    /// Request a specific tightening result by its ID
-    #[open_protocol_field(number = 1, length = 10)]
-    pub tightening_id: u32,
-}
+    /// The fourth identifier result part
+    #[open_protocol_field(number = 32, length = 25)]
+    pub identifier_result_part_4: String,
 
-// MID0065 (Old tightening result upload reply)
-#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0065, revision = 1)]
-pub struct MID0065rev1 {
-    // Reply with the details of an old tightening result
-    // Contains the same fields as MID0061 revision 1, plus optional extensions
+    /// Customer specific tightening error code
+    #[open_protocol_field(number = 33, length = 4)]
+    pub customer_tightening_error_code: String,
+
+    /// The prevail torque compensate value
+    #[open_protocol_field(number = 34, length = 6)]
+    pub prevail_torque_compensate_value: u32,
+
+    /// Tightening error status 2
+    #[open_protocol_field(number = 35, length = 10)]
+    pub tightening_error_status_2: String,
+
+    /// The compensated angle, in degrees with two decimals
+    #[open_protocol_field(number = 36, length = 7)]
+    pub compensated_angle: String,
+
+    /// The achieved angle, in degrees with two decimals
+    #[open_protocol_field(number = 37, length = 7)]
+    pub final_angle_decimal: String,
 }
 
 // MID0066 (Number of offline results)
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0066, revision = 1)]
+#[open_protocol_message(MID = 0066, revision = 1, direction = "to_integrator")]
 pub struct MID0066rev1 {
     /// Number of offline results stored in the system
     #[open_protocol_field(number = 1, length = 2)]
@@ -386,7 +2631,7 @@ pub struct MID0066rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0066, revision = 2)]
+#[open_protocol_message(MID = 0066, revision = 2, direction = "to_integrator")]
 pub struct MID0066rev2 {
     /// Number of offline results stored in the system
     #[open_protocol_field(number = 1, length = 3)]
@@ -399,7 +2644,7 @@ pub struct MID0066rev2 {
 
 // MID0067 (Tightening result list upload)
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0067, revision = 1)]
+#[open_protocol_message(MID = 0067, revision = 1, direction = "to_integrator")]
 pub struct MID0067rev1 {
     /// Start index of the requested tightening results
     #[open_protocol_field(number = 1, length = 10)]
@@ -412,7 +2657,7 @@ pub struct MID0067rev1 {
 
 /// 5.8.9 MID 0900 Trace curve data message
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0900, revision = 1)]
+#[open_protocol_message(MID = 0900, revision = 1, direction = "to_integrator")]
 pub struct MID0900rev1 {
     /// The unique identifier for the result data.
     #[open_protocol_field(length = 10)]
@@ -420,7 +2665,7 @@ pub struct MID0900rev1 {
 
     /// Timestamp of when the trace was recorded.
     #[open_protocol_field(length = 19)]
-    pub timestamp: DateTime<Local>,
+    pub timestamp: NaiveDateTime,
 
     /// The number of PIDs (parameter data fields).
     #[open_protocol_field(length = 3)]
@@ -456,7 +2701,7 @@ pub struct MID0900rev1 {
 
     /// Resolution fields for time intervals.
     #[open_protocol_field(list, amount = "number_of_resolution_fields")]
-    pub resolution_fields: Vec<DataField>,
+    pub resolution_fields: Vec<ResolutionField>,
 
     /// The number of trace samples included.
     #[open_protocol_field(length = 5)]
@@ -472,7 +2717,7 @@ pub struct MID0900rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0900, revision = 2)]
+#[open_protocol_message(MID = 0900, revision = 2, direction = "to_integrator")]
 pub struct MID0900rev2 {
     /// The unique identifier for the result data.
     #[open_protocol_field(length = 10)]
@@ -480,7 +2725,7 @@ pub struct MID0900rev2 {
 
     /// Timestamp of when the trace was recorded.
     #[open_protocol_field(length = 19)]
-    pub timestamp: DateTime<Local>,
+    pub timestamp: NaiveDateTime,
 
     /// The number of PIDs (parameter data fields).
     #[open_protocol_field(length = 3)]
@@ -521,7 +2766,7 @@ pub struct MID0900rev2 {
 
     /// Resolution fields for time intervals.
     #[open_protocol_field(list, amount = "number_of_resolution_fields")]
-    pub resolution_fields: Vec<DataField>,
+    pub resolution_fields: Vec<ResolutionField>,
 
     /// The number of trace samples included.
     #[open_protocol_field(length = 5)]
@@ -537,7 +2782,7 @@ pub struct MID0900rev2 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0900, revision = 3)]
+#[open_protocol_message(MID = 0900, revision = 3, direction = "to_integrator")]
 pub struct MID0900rev3 {
     /// The unique identifier for the result data.
     #[open_protocol_field(length = 10)]
@@ -545,7 +2790,7 @@ pub struct MID0900rev3 {
 
     /// Timestamp of when the trace was recorded.
     #[open_protocol_field(length = 19)]
-    pub timestamp: DateTime<Local>,
+    pub timestamp: NaiveDateTime,
 
     /// The user defined object ID
     #[open_protocol_field(length = 4)]
@@ -598,7 +2843,7 @@ pub struct MID0900rev3 {
 
     /// Resolution fields for time intervals.
     #[open_protocol_field(list, amount = "number_of_resolution_fields")]
-    pub resolution_fields: Vec<DataField>,
+    pub resolution_fields: Vec<ResolutionField>,
 
     /// The number of trace samples included.
     #[open_protocol_field(length = 5)]
@@ -613,7 +2858,8 @@ pub struct MID0900rev3 {
     pub trace_samples: Vec<TraceSample>,
 }
 
-#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0900, revision = 1, type = "request_extra_data")]
 pub struct MID0900RequestExtraData {
     /// The index of the requested tightening result. If equal to zero, response
     /// will contain the most recent result.
@@ -629,40 +2875,66 @@ pub struct MID0900RequestExtraData {
     pub tool_number: u16,
 }
 
-#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0900, revision = 1, type = "subscribe_extra_data")]
 pub struct MID0900SubscribeExtraData {
-    // TODO
+    /// The index of the first result to send. If equal to zero, only new results are sent.
+    #[open_protocol_field(length = 10)]
+    pub start_index: u64,
+
+    /// The number of trace types subscribed for.
+    #[open_protocol_field(length = 3)]
+    pub number_of_trace_types: u16,
+
+    /// The trace types subscribed for.
+    #[open_protocol_field(list, amount = "number_of_trace_types", length = 3)]
+    pub trace_types: Vec<TraceType>,
+
+    /// The number of PIDs to include in the trace data fields.
+    #[open_protocol_field(length = 3)]
+    pub number_of_pids: u16,
+
+    /// The PIDs to include in the trace data fields.
+    #[open_protocol_field(list, amount = "number_of_pids", length = 5)]
+    pub pids: Vec<u32>,
 }
 
-#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 0900, revision = 1, type = "unsubscribe_extra_data")]
 pub struct MID0900UnsubscribeExtraData {
-    // TODO
+    /// The number of trace types to unsubscribe from.
+    #[open_protocol_field(length = 3)]
+    pub number_of_trace_types: u16,
+
+    /// The trace types to unsubscribe from.
+    #[open_protocol_field(list, amount = "number_of_trace_types", length = 3)]
+    pub trace_types: Vec<TraceType>,
 }
 
 /// Subscribe, MID 0901 Trace plotting parameter
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0901, revision = 1)]
+#[open_protocol_message(MID = 0901, revision = 1, direction = "to_integrator")]
 pub struct MID0901rev1 {
     // TODO
 }
 
 /// Subscribe, MID 0901 Trace plotting parameter
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0901, revision = 2)]
+#[open_protocol_message(MID = 0901, revision = 2, direction = "to_integrator")]
 pub struct MID0901rev2 {
     // TODO
 }
 
 /// Subscribe, MID 0901 Trace plotting parameter
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0901, revision = 3)]
+#[open_protocol_message(MID = 0901, revision = 3, direction = "to_integrator")]
 pub struct MID0901rev3 {
     // TODO
 }
 
 /// 5.8.11 MID 0902 Tightening Result DB Info Upload
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 0902, revision = 1)]
+#[open_protocol_message(MID = 0902, revision = 1, direction = "to_integrator")]
 pub struct MID0902rev1 {
     /// The total number of tightening results that can be stored.
     #[open_protocol_field(length = 10)]
@@ -674,7 +2946,7 @@ pub struct MID0902rev1 {
 
     /// The start time of the oldest tightening result currently in the database.
     #[open_protocol_field(length = 19)]
-    pub oldest_time: DateTime<Local>,
+    pub oldest_time: NaiveDateTime,
 
     /// The sequence number of the newest tightening result currently in the database.
     #[open_protocol_field(length = 10)]
@@ -682,7 +2954,7 @@ pub struct MID0902rev1 {
 
     /// The start time of the newest tightening result currently in the database.
     #[open_protocol_field(length = 19)]
-    pub newest_time: DateTime<Local>,
+    pub newest_time: NaiveDateTime,
 
     /// The number of PIDs (parameter data fields).
     #[open_protocol_field(length = 3)]
@@ -692,3 +2964,139 @@ pub struct MID0902rev1 {
     #[open_protocol_field(list, amount = "number_of_pids")]
     pub data_fields: Vec<DataField>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use open_protocol_codec::{decode, encode};
+    use crate::enums::datatype::DataType;
+
+    #[test]
+    fn roundtrip_mid0061rev998() {
+        let timestamp = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap().and_hms_opt(3, 4, 5).unwrap();
+        let message = MID0061rev998 {
+            cell_id: 1,
+            channel_id: 1,
+            controller_name: "PF6000".into(),
+            parameter_set_id: 5,
+            tightening_status: OkNok::Ok,
+            torque: 1250,
+            angle: 180,
+            timestamp,
+            last_parameter_set_change: timestamp,
+            parameter_set_name: "PSET 5".into(),
+            torque_unit: TorqueUnit::NewtonMetre,
+            result_type: ResultType::Tightening,
+            final_angle_decimal: "0180.00".into(),
+            number_of_data_fields: 2,
+            data_fields: vec![
+                DataField {
+                    parameter_id: 2213,
+                    length: 6,
+                    data_type: DataType::UnsignedInteger,
+                    unit: 1,
+                    step_no: Some(1),
+                    data_value: "001250".into(),
+                },
+                DataField {
+                    parameter_id: 2214,
+                    length: 5,
+                    data_type: DataType::UnsignedInteger,
+                    unit: 50,
+                    step_no: Some(2),
+                    data_value: "00180".into(),
+                },
+            ],
+            ..Default::default()
+        };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert!(encoded.ends_with(
+            "570180.00\
+             58002\
+             02213006010010001001250\
+             0221400501050000200180"
+        ));
+        assert_eq!(decode::decode::<MID0061rev998>(encoded.as_bytes()), Ok(message));
+    }
+
+    #[test]
+    fn roundtrip_mid0065rev1() {
+        let message = MID0065rev1 {
+            tightening_id: 42,
+            vin_number: "VIN-0001".into(),
+            parameter_set_id: 1,
+            batch_counter: 3,
+            tightening_status: OkNok::Ok,
+            torque_status: LimitStatus::Ok,
+            angle_status: LimitStatus::Ok,
+            torque: 1250,
+            angle: 180,
+            timestamp: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap().and_hms_opt(3, 4, 5).unwrap(),
+            batch_status: BatchStatus::Ok,
+        };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(
+            encoded,
+            "010000000042\
+             02VIN-0001                 \
+             03001\
+             040003\
+             051\
+             061\
+             071\
+             08001250\
+             0900180\
+             102024-01-02:03:04:05\
+             111"
+        );
+        assert_eq!(decode::decode::<MID0065rev1>(encoded.as_bytes()), Ok(message));
+    }
+
+    #[test]
+    fn decode_mid0065rev1_invalid_torque_status() {
+        let encoded = "010000000042\
+                       02VIN-0001                 \
+                       03001\
+                       040003\
+                       051\
+                       065\
+                       071\
+                       08001250\
+                       0900180\
+                       102024-01-02:03:04:05\
+                       111";
+
+        assert!(matches!(
+            decode::decode::<MID0065rev1>(encoded.as_bytes()),
+            Err(decode::Error::InvalidEnumNumber(5, _))
+        ));
+    }
+
+    #[test]
+    fn roundtrip_mid0065rev7() {
+        let message = MID0065rev7 {
+            tightening_id: 7,
+            parameter_set_name: "PSET 1".into(),
+            torque_unit: TorqueUnit::NewtonMetre,
+            result_type: ResultType::Tightening,
+            identifier_result_part_2: "PART".into(),
+            customer_tightening_error_code: "E001".into(),
+            prevail_torque_compensate_value: 120,
+            compensated_angle: "0090.50".into(),
+            final_angle_decimal: "0181.25".into(),
+            timestamp: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap().and_hms_opt(3, 4, 5).unwrap(),
+            ..Default::default()
+        };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(encoded.len(), 353);
+        assert!(encoded.ends_with("3400012035          360090.50370181.25"));
+        assert_eq!(decode::decode::<MID0065rev7>(encoded.as_bytes()), Ok(message));
+    }
+}
@@ -1,8 +1,91 @@
-use chrono::{DateTime, Local};
+use chrono::NaiveDateTime;
 use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
 
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum DisableType {
+    /// Disable the tool for both tightening and loosening.
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Tool,
+    /// Disable loosening only; tightening stays enabled.
+    #[open_protocol_value(number = 1)]
+    Loosening,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum PairingHandlingType {
+    #[default]
+    #[open_protocol_value(number = 1)]
+    StartPairing,
+    #[open_protocol_value(number = 2)]
+    AbortOrDisconnect,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum PairingStatus {
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Unpaired,
+    #[open_protocol_value(number = 1)]
+    PairingInProgress,
+    #[open_protocol_value(number = 2)]
+    Paired,
+    #[open_protocol_value(number = 3)]
+    PairingAborted,
+    #[open_protocol_value(number = 4)]
+    PairingFailed,
+    #[open_protocol_value(other)]
+    Other(u16),
+}
+
+/// Open end settings of the tool, sent as three single digit fields.
+#[derive(Debug, Default, Clone, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub struct OpenEndData {
+    /// Whether the open end function is used
+    #[open_protocol_field(length = 1)]
+    pub use_open_end: bool,
+
+    /// The tightening direction (0=CW, 1=CCW)
+    #[open_protocol_field(length = 1)]
+    pub tightening_direction: u8,
+
+    /// The motor rotation (0=Normal, 1=Inverted)
+    #[open_protocol_field(length = 1)]
+    pub motor_rotation: u8,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 40, revision = 1, reply = 41)]
+pub struct MID0040rev1 {
+    // Request the tool data, no fields for this revision
+}
+
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 40, revision = 6)]
+#[open_protocol_message(MID = 40, revision = 2, reply = 41)]
+pub struct MID0040rev2 {
+    // Request the tool data, no fields for this revision
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 40, revision = 3, reply = 41)]
+pub struct MID0040rev3 {
+    // Request the tool data, no fields for this revision
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 40, revision = 4, reply = 41)]
+pub struct MID0040rev4 {
+    // Request the tool data, no fields for this revision
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 40, revision = 5, reply = 41)]
+pub struct MID0040rev5 {
+    // Request the tool data, no fields for this revision
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 40, revision = 6, reply = 41)]
 pub struct MID0040rev6 {
     /// The tool number for which the data is being requested
     #[open_protocol_field(length = 4)]
@@ -10,7 +93,7 @@ pub struct MID0040rev6 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 41, revision = 1)]
+#[open_protocol_message(MID = 41, revision = 1, direction = "to_integrator")]
 pub struct MID0041rev1 {
     /// The serial number of the tool
     #[open_protocol_field(length = 14)]
@@ -22,7 +105,7 @@ pub struct MID0041rev1 {
 
     /// The date of the last calibration of the tool
     #[open_protocol_field(length = 19)]
-    pub last_calibration_date: DateTime<Local>,
+    pub last_calibration_date: NaiveDateTime,
 
     /// The serial number of the controller or RBU type
     #[open_protocol_field(length = 10)]
@@ -30,7 +113,7 @@ pub struct MID0041rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 41, revision = 2)]
+#[open_protocol_message(MID = 41, revision = 2, direction = "to_integrator")]
 pub struct MID0041rev2 {
     /// The serial number of the tool
     #[open_protocol_field(length = 14)]
@@ -42,7 +125,43 @@ pub struct MID0041rev2 {
 
     /// The date of the last calibration of the tool
     #[open_protocol_field(length = 19)]
-    pub last_calibration_date: DateTime<Local>,
+    pub last_calibration_date: NaiveDateTime,
+
+    /// The serial number of the controller or RBU type
+    #[open_protocol_field(length = 10)]
+    pub controller_serial_number: String,
+
+    /// Calibration value of the tool
+    #[open_protocol_field(length = 6)]
+    pub calibration_value: u32,
+
+    /// Date of the last service for the tool
+    #[open_protocol_field(length = 19)]
+    pub last_service_date: NaiveDateTime,
+
+    /// Number of tightenings since the last service
+    #[open_protocol_field(length = 10)]
+    pub tightenings_since_service: u32,
+
+    /// Type of tool
+    #[open_protocol_field(length = 2)]
+    pub tool_type: u8,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 41, revision = 3, direction = "to_integrator")]
+pub struct MID0041rev3 {
+    /// The serial number of the tool
+    #[open_protocol_field(length = 14)]
+    pub tool_serial_number: String,
+
+    /// The number of tightenings performed with this tool
+    #[open_protocol_field(length = 10)]
+    pub number_of_tightenings: u32,
+
+    /// The date of the last calibration of the tool
+    #[open_protocol_field(length = 19)]
+    pub last_calibration_date: NaiveDateTime,
 
     /// The serial number of the controller or RBU type
     #[open_protocol_field(length = 10)]
@@ -54,7 +173,7 @@ pub struct MID0041rev2 {
 
     /// Date of the last service for the tool
     #[open_protocol_field(length = 19)]
-    pub last_service_date: DateTime<Local>,
+    pub last_service_date: NaiveDateTime,
 
     /// Number of tightenings since the last service
     #[open_protocol_field(length = 10)]
@@ -63,6 +182,142 @@ pub struct MID0041rev2 {
     /// Type of tool
     #[open_protocol_field(length = 2)]
     pub tool_type: u8,
+
+    /// Size of the motor of the tool
+    #[open_protocol_field(length = 2)]
+    pub motor_size: u8,
+
+    /// Open end settings of the tool
+    #[open_protocol_field(length = 3)]
+    pub open_end_data: OpenEndData,
+
+    /// The software version of the controller
+    #[open_protocol_field(length = 19)]
+    pub controller_software_version: String,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 41, revision = 4, direction = "to_integrator")]
+pub struct MID0041rev4 {
+    /// The serial number of the tool
+    #[open_protocol_field(length = 14)]
+    pub tool_serial_number: String,
+
+    /// The number of tightenings performed with this tool
+    #[open_protocol_field(length = 10)]
+    pub number_of_tightenings: u32,
+
+    /// The date of the last calibration of the tool
+    #[open_protocol_field(length = 19)]
+    pub last_calibration_date: NaiveDateTime,
+
+    /// The serial number of the controller or RBU type
+    #[open_protocol_field(length = 10)]
+    pub controller_serial_number: String,
+
+    /// Calibration value of the tool
+    #[open_protocol_field(length = 6)]
+    pub calibration_value: u32,
+
+    /// Date of the last service for the tool
+    #[open_protocol_field(length = 19)]
+    pub last_service_date: NaiveDateTime,
+
+    /// Number of tightenings since the last service
+    #[open_protocol_field(length = 10)]
+    pub tightenings_since_service: u32,
+
+    /// Type of tool
+    #[open_protocol_field(length = 2)]
+    pub tool_type: u8,
+
+    /// Size of the motor of the tool
+    #[open_protocol_field(length = 2)]
+    pub motor_size: u8,
+
+    /// Open end settings of the tool
+    #[open_protocol_field(length = 3)]
+    pub open_end_data: OpenEndData,
+
+    /// The software version of the controller
+    #[open_protocol_field(length = 19)]
+    pub controller_software_version: String,
+
+    /// The maximum torque of the tool
+    #[open_protocol_field(length = 6)]
+    pub tool_max_torque: u32,
+
+    /// The gear ratio of the tool, multiplied by 100
+    #[open_protocol_field(length = 6)]
+    pub gear_ratio: u32,
+
+    /// The full speed of the tool
+    #[open_protocol_field(length = 6)]
+    pub tool_full_speed: u32,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 41, revision = 5, direction = "to_integrator")]
+pub struct MID0041rev5 {
+    /// The serial number of the tool
+    #[open_protocol_field(length = 14)]
+    pub tool_serial_number: String,
+
+    /// The number of tightenings performed with this tool
+    #[open_protocol_field(length = 10)]
+    pub number_of_tightenings: u32,
+
+    /// The date of the last calibration of the tool
+    #[open_protocol_field(length = 19)]
+    pub last_calibration_date: NaiveDateTime,
+
+    /// The serial number of the controller or RBU type
+    #[open_protocol_field(length = 10)]
+    pub controller_serial_number: String,
+
+    /// Calibration value of the tool
+    #[open_protocol_field(length = 6)]
+    pub calibration_value: u32,
+
+    /// Date of the last service for the tool
+    #[open_protocol_field(length = 19)]
+    pub last_service_date: NaiveDateTime,
+
+    /// Number of tightenings since the last service
+    #[open_protocol_field(length = 10)]
+    pub tightenings_since_service: u32,
+
+    /// Type of tool
+    #[open_protocol_field(length = 2)]
+    pub tool_type: u8,
+
+    /// Size of the motor of the tool
+    #[open_protocol_field(length = 2)]
+    pub motor_size: u8,
+
+    /// Open end settings of the tool
+    #[open_protocol_field(length = 3)]
+    pub open_end_data: OpenEndData,
+
+    /// The software version of the controller
+    #[open_protocol_field(length = 19)]
+    pub controller_software_version: String,
+
+    /// The maximum torque of the tool
+    #[open_protocol_field(length = 6)]
+    pub tool_max_torque: u32,
+
+    /// The gear ratio of the tool, multiplied by 100
+    #[open_protocol_field(length = 6)]
+    pub gear_ratio: u32,
+
+    /// The full speed of the tool
+    #[open_protocol_field(length = 6)]
+    pub tool_full_speed: u32,
+
+    /// The primary tool number
+    #[open_protocol_field(length = 2)]
+    pub primary_tool: u8,
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
@@ -72,9 +327,9 @@ pub struct MID0042rev2 {
     #[open_protocol_field(length = 4)]
     pub tool_number: u16,
 
-    /// The type of disable action
+    /// Whether to disable the whole tool or only loosening
     #[open_protocol_field(length = 2)]
-    pub disable_type: u8,
+    pub disable_type: DisableType,
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
@@ -128,21 +383,69 @@ pub struct MID0046rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 47, revision = 1)]
+#[open_protocol_message(MID = 47, revision = 1, reply = 48)]
 pub struct MID0047rev1 {
     /// Type of pairing handling action
     #[open_protocol_field(length = 2)]
-    pub pairing_handling_type: u8,
+    pub pairing_handling_type: PairingHandlingType,
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 48, revision = 1)]
+#[open_protocol_message(MID = 48, revision = 1, direction = "to_integrator")]
 pub struct MID0048rev1 {
     /// Current status of the tool pairing
     #[open_protocol_field(length = 2)]
-    pub pairing_status: u8,
+    pub pairing_status: PairingStatus,
 
     /// Timestamp of the status change
     #[open_protocol_field(length = 19)]
-    pub timestamp: DateTime<Local>,
+    pub timestamp: NaiveDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use open_protocol_codec::{decode, encode};
+
+    #[test]
+    fn roundtrip_mid0041rev5() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap().and_hms_opt(3, 4, 5).unwrap();
+        let message = MID0041rev5 {
+            tool_serial_number: "T1234".into(),
+            number_of_tightenings: 1000,
+            last_calibration_date: date,
+            controller_serial_number: "C5678".into(),
+            calibration_value: 2500,
+            last_service_date: date,
+            tightenings_since_service: 10,
+            tool_type: 1,
+            motor_size: 2,
+            open_end_data: OpenEndData { use_open_end: true, tightening_direction: 1, motor_rotation: 0 },
+            controller_software_version: "PF6000 2.3".into(),
+            tool_max_torque: 5000,
+            gear_ratio: 1250,
+            tool_full_speed: 1200,
+            primary_tool: 1,
+        };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert!(encoded.ends_with("02110PF6000 2.3         00500000125000120001"));
+        assert_eq!(decode::decode::<MID0041rev5>(encoded.as_bytes()), Ok(message));
+    }
+
+    #[test]
+    fn encode_mid0042rev2_disable_loosening() {
+        let message = MID0042rev2 { tool_number: 1, disable_type: DisableType::Loosening };
+
+        assert_eq!(encode::encode(&message), Ok("000101".to_string()));
+    }
+
+    #[test]
+    fn decode_mid0048rev1() {
+        let decoded = decode::decode::<MID0048rev1>("022024-01-02:03:04:05".as_bytes()).unwrap();
+
+        assert_eq!(decoded.pairing_status, PairingStatus::Paired);
+    }
 }
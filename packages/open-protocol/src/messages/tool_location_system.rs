@@ -0,0 +1,86 @@
+use open_protocol_codec_proc_macro::{OpenProtocolDecode, OpenProtocolEncode, OpenProtocolMessage};
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode)]
+pub enum ToolStatus {
+    /// The tool is within its working zone and allowed to tighten.
+    #[default]
+    #[open_protocol_value(number = 0)]
+    Ok,
+    /// The tool is outside its working zone and must be inhibited.
+    #[open_protocol_value(number = 1)]
+    Inhibited,
+}
+
+/// A subscription for the tool tag ID read by the tool location system.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 261, revision = 1)]
+pub struct MID0261rev1 {
+    // No additional fields for this MID.
+}
+
+/// Sent each time a new tool tag ID is read, or as a reply to MID 0266 Tool tag ID request.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 262, revision = 1, direction = "to_integrator", ack = 263)]
+pub struct MID0262rev1 {
+    /// The tool tag ID, right-padded with spaces.
+    #[open_protocol_field(number = 1, length = 8)]
+    pub tool_tag_id: String,
+}
+
+/// Acknowledges receipt of MID 0262 Tool tag ID.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 263, revision = 1, reply = "none")]
+pub struct MID0263rev1 {
+    // No additional fields for this MID.
+}
+
+/// Cancels a previously subscribed tool tag ID.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 264, revision = 1)]
+pub struct MID0264rev1 {
+    // No additional fields for this MID.
+}
+
+/// Sent by the integrator to tell the controller which tool is in which zone, and whether it
+/// may tighten there.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 265, revision = 1)]
+pub struct MID0265rev1 {
+    /// The tool tag ID, right-padded with spaces.
+    #[open_protocol_field(number = 1, length = 8)]
+    pub tool_tag_id: String,
+
+    /// Whether the tool is allowed to tighten.
+    #[open_protocol_field(number = 2, length = 2)]
+    pub tool_status: ToolStatus,
+}
+
+/// Requests the current tool tag ID. The controller replies with MID 0262 Tool tag ID.
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 266, revision = 1, reply = 262)]
+pub struct MID0266rev1 {
+    // No additional fields for this MID.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use open_protocol_codec::{decode, encode};
+
+    #[test]
+    fn decode_mid0262rev1() {
+        let decoded = decode::decode::<MID0262rev1>("01TAG42   ".as_bytes());
+
+        assert_eq!(decoded, Ok(MID0262rev1 { tool_tag_id: "TAG42".into() }));
+    }
+
+    #[test]
+    fn roundtrip_mid0265rev1() {
+        let message = MID0265rev1 { tool_tag_id: "T0000001".into(), tool_status: ToolStatus::Inhibited };
+
+        let encoded = encode::encode(&message).unwrap();
+
+        assert_eq!(encoded, "01T00000010201");
+        assert_eq!(decode::decode::<MID0265rev1>(encoded.as_bytes()), Ok(message));
+    }
+}
@@ -9,14 +9,21 @@ pub struct MID0050rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 51, revision = 1)]
+#[open_protocol_message(MID = 51, revision = 1, subscribes = 52)]
 pub struct MID0051rev1 {
     // Subscription for VIN and other identifiers
     // No fields for this revision
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 52, revision = 1)]
+#[open_protocol_message(MID = 51, revision = 2, subscribes = 52)]
+pub struct MID0051rev2 {
+    // Subscription for revision 2 of the VIN and other identifiers
+    // No fields for this revision
+}
+
+#[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
+#[open_protocol_message(MID = 52, revision = 1, direction = "to_integrator", ack = 53)]
 pub struct MID0052rev1 {
     /// The current Vehicle Identification Number (VIN)
     #[open_protocol_field(length = 25)]
@@ -24,7 +31,7 @@ pub struct MID0052rev1 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 52, revision = 2)]
+#[open_protocol_message(MID = 52, revision = 2, direction = "to_integrator", ack = 53)]
 pub struct MID0052rev2 {
     /// The current Vehicle Identification Number (VIN)
     #[open_protocol_field(length = 25)]
@@ -36,7 +43,7 @@ pub struct MID0052rev2 {
 }
 
 #[derive(Debug, Default, Eq, PartialEq, OpenProtocolEncode, OpenProtocolDecode, OpenProtocolMessage)]
-#[open_protocol_message(MID = 53, revision = 1)]
+#[open_protocol_message(MID = 53, revision = 1, reply = "none")]
 pub struct MID0053rev1 {
     // Acknowledge receipt of the VIN number
     // No fields for this revision
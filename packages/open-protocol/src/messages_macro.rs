@@ -10,6 +10,17 @@ macro_rules! open_protocol_messages {
             }
 
             impl Message {
+                /// The revisions of `mid` this library can encode and decode, in ascending order.
+                pub fn revisions(mid: u16) -> Vec<u16> {
+                    let mut revisions: Vec<u16> = [$( ($mid, $rev) ),*]
+                        .into_iter()
+                        .filter(|(message_mid, _)| *message_mid == mid)
+                        .map(|(_, revision)| revision)
+                        .collect();
+                    revisions.sort_unstable();
+                    revisions
+                }
+
                 pub fn mid_revision(&self) -> (u16, u16) {
                     match self {
                         $(
@@ -18,6 +29,71 @@ macro_rules! open_protocol_messages {
                     }
                 }
 
+                pub fn direction(&self) -> ::open_protocol_codec::message::Direction {
+                    match self {
+                        $(
+                            Message::[<MID $mid rev $rev>](payload) => ::open_protocol_codec::message::Message::to_direction(payload),
+                        )*
+                    }
+                }
+
+                /// The MID the integrator acknowledges this message with.
+                pub fn ack_mid(&self) -> Option<u16> {
+                    match self {
+                        $(
+                            Message::[<MID $mid rev $rev>](payload) => ::open_protocol_codec::message::Message::to_ack_mid(payload),
+                        )*
+                    }
+                }
+
+                /// The MID the controller replies with when it accepts this message.
+                pub fn reply_mid(&self) -> Option<u16> {
+                    match self {
+                        $(
+                            Message::[<MID $mid rev $rev>](payload) => ::open_protocol_codec::message::Message::to_reply_mid(payload),
+                        )*
+                    }
+                }
+
+                /// The MID of the data this message subscribes to, whose revision it asks for.
+                pub fn subscribed_mid(&self) -> Option<u16> {
+                    match self {
+                        $(
+                            Message::[<MID $mid rev $rev>](payload) => ::open_protocol_codec::message::Message::to_subscribed_mid(payload),
+                        )*
+                    }
+                }
+
+                /// The fields of the message that are not at their default value, each encoded on
+                /// its own.
+                pub fn encode_set_fields(&self) -> ::open_protocol_codec::encode::Result<Vec<::open_protocol_codec::message::EncodedField>> {
+                    match self {
+                        $(
+                            Message::[<MID $mid rev $rev>](payload) => ::open_protocol_codec::message::Message::encode_set_fields(payload),
+                        )*
+                    }
+                }
+
+                /// Builds a revision of `mid` from the fields of another revision, see
+                /// [`Message::encode_set_fields`].
+                pub fn decode_fields(mid: u16, revision: u16, fields: &[::open_protocol_codec::message::EncodedField]) -> ::open_protocol_codec::decode::Result<Self> {
+                    Ok(match (mid, revision) {
+                        $(
+                            ($mid, $rev) => Message::[<MID $mid rev $rev>](<$msg as ::open_protocol_codec::message::Message>::decode_fields(fields)?),
+                        )*
+                        _ => return Err(::open_protocol_codec::decode::Error::InvalidMessage { mid, revision }),
+                    })
+                }
+
+                /// Converts the message to another revision of its MID, field by field. Fails
+                /// when a field that is set does not exist in, or does not fit, that revision.
+                pub fn convert(&self, revision: u16) -> Result<Self, ::open_protocol_codec::message::ConvertError> {
+                    let (mid, _) = self.mid_revision();
+                    let fields = self.encode_set_fields()?;
+
+                    Ok(Self::decode_fields(mid, revision, &fields)?)
+                }
+
                 pub fn encode_payload(&self, encoder: &mut ::open_protocol_codec::encode::Encoder) -> ::open_protocol_codec::encode::Result<()> {
                     match self {
                         $(
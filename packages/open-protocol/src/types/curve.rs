@@ -0,0 +1,295 @@
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::time::Duration;
+use crate::messages::tightening_result::TraceType;
+use crate::types::trace::Trace;
+use crate::Message;
+
+/// The values of the torque, angle and current traces at one point of the common time base.
+/// A value is `None` when its trace is missing or does not cover that time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlignedSample {
+    pub time: Duration,
+    pub torque: Option<f64>,
+    pub angle: Option<f64>,
+    pub current: Option<f64>,
+}
+
+/// All trace curves of one tightening, together with its result.
+#[derive(Debug, PartialEq)]
+pub struct TighteningCurve {
+    /// The result data ID shared by the traces and the result.
+    pub result_data_id: u32,
+    /// The traces of the tightening, one per trace type.
+    pub traces: HashMap<TraceType, Trace>,
+    /// The MID 0061 or MID 1201 result of the tightening.
+    pub result: Option<Message>,
+}
+
+impl TighteningCurve {
+    pub fn new(result_data_id: u32) -> Self {
+        Self { result_data_id, traces: HashMap::new(), result: None }
+    }
+
+    pub fn trace(&self, trace_type: TraceType) -> Option<&Trace> {
+        self.traces.get(&trace_type)
+    }
+
+    /// The sorted times of all samples of all traces.
+    pub fn time_base(&self) -> Vec<Duration> {
+        self.traces.values()
+            .flat_map(|trace| trace.samples.iter().map(|(time, _)| *time))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// The torque, angle and current traces on the common time base. Traces sampled at other
+    /// times are linearly interpolated.
+    pub fn aligned(&self) -> Vec<AlignedSample> {
+        self.time_base()
+            .into_iter()
+            .map(|time| AlignedSample {
+                time,
+                torque: self.value_at(TraceType::Torque, time),
+                angle: self.value_at(TraceType::Angle, time),
+                current: self.value_at(TraceType::Current, time),
+            })
+            .collect()
+    }
+
+    fn value_at(&self, trace_type: TraceType, time: Duration) -> Option<f64> {
+        let samples = &self.trace(trace_type)?.samples;
+        let after = samples.partition_point(|(sample_time, _)| *sample_time < time);
+        let (time_after, value_after) = *samples.get(after)?;
+
+        if time_after == time {
+            return Some(value_after);
+        }
+
+        let (time_before, value_before) = *samples.get(after.checked_sub(1)?)?;
+        let fraction = (time - time_before).as_secs_f64() / (time_after - time_before).as_secs_f64();
+        Some(value_before + (value_after - value_before) * fraction)
+    }
+
+    fn is_complete(&self, trace_types: &[TraceType], require_result: bool) -> bool {
+        trace_types.iter().all(|trace_type| self.traces.contains_key(trace_type))
+            && (!require_result || self.result.is_some())
+    }
+}
+
+/// The number of completed curves [`TighteningCurveAssembler`] remembers, to drop traces and
+/// results that arrive after their curve.
+pub const EMITTED_HISTORY: usize = 64;
+
+/// The number of incomplete curves [`TighteningCurveAssembler`] keeps. When another curve starts,
+/// the oldest incomplete curve is dropped.
+pub const PENDING_LIMIT: usize = 64;
+
+/// Groups MID 0900 traces, which arrive as a separate message per trace type, and the matching
+/// tightening result into one [`TighteningCurve`] per tightening.
+#[derive(Debug)]
+pub struct TighteningCurveAssembler {
+    trace_types: Vec<TraceType>,
+    require_result: bool,
+    pending: HashMap<u32, TighteningCurve>,
+    pending_order: VecDeque<u32>,
+    emitted: VecDeque<u32>,
+}
+
+impl TighteningCurveAssembler {
+    /// A curve is complete once a trace of each of `trace_types` has arrived, and the result too
+    /// if `require_result` is set.
+    pub fn new(trace_types: impl IntoIterator<Item = TraceType>, require_result: bool) -> Self {
+        Self {
+            trace_types: trace_types.into_iter().collect(),
+            require_result,
+            pending: HashMap::new(),
+            pending_order: VecDeque::with_capacity(PENDING_LIMIT),
+            emitted: VecDeque::with_capacity(EMITTED_HISTORY),
+        }
+    }
+
+    /// Adds a trace, returning the curve it belongs to if that is now complete. Convert MID 0900
+    /// messages with `Trace::try_from`. A trace for one of the last [`EMITTED_HISTORY`] completed
+    /// curves is dropped.
+    pub fn add_trace(&mut self, trace: Trace) -> Option<TighteningCurve> {
+        let result_data_id = trace.result_data_id;
+
+        self.pending_curve(result_data_id)?
+            .traces
+            .insert(trace.trace_type, trace);
+
+        self.take_if_complete(result_data_id)
+    }
+
+    /// Adds a MID 0061 or MID 1201 result, returning the curve it belongs to if that is now
+    /// complete. Any other message is ignored, see [`result_data_id`].
+    ///
+    /// Without `require_result`, a curve can be complete before its result arrives. A result for
+    /// one of the last [`EMITTED_HISTORY`] completed curves is dropped.
+    pub fn add_result(&mut self, message: Message) -> Option<TighteningCurve> {
+        let result_data_id = result_data_id(&message)?;
+
+        self.pending_curve(result_data_id)?.result = Some(message);
+
+        self.take_if_complete(result_data_id)
+    }
+
+    /// Removes a curve, complete or not, for example when its remaining traces will not arrive.
+    pub fn take(&mut self, result_data_id: u32) -> Option<TighteningCurve> {
+        self.pending_order.retain(|pending_id| *pending_id != result_data_id);
+        self.pending.remove(&result_data_id)
+    }
+
+    /// The result data IDs of the curves that are not complete yet.
+    pub fn pending(&self) -> impl Iterator<Item = u32> + '_ {
+        self.pending.keys().copied()
+    }
+
+    /// The incomplete curve of `result_data_id`, started if needed, or `None` if the curve was
+    /// already emitted.
+    fn pending_curve(&mut self, result_data_id: u32) -> Option<&mut TighteningCurve> {
+        if self.emitted.contains(&result_data_id) {
+            return None;
+        }
+
+        if !self.pending.contains_key(&result_data_id) {
+            if self.pending_order.len() == PENDING_LIMIT {
+                if let Some(oldest) = self.pending_order.pop_front() {
+                    self.pending.remove(&oldest);
+                }
+            }
+
+            self.pending_order.push_back(result_data_id);
+        }
+
+        Some(self.pending.entry(result_data_id).or_insert_with(|| TighteningCurve::new(result_data_id)))
+    }
+
+    fn take_if_complete(&mut self, result_data_id: u32) -> Option<TighteningCurve> {
+        let curve = self.pending.get(&result_data_id)?;
+
+        if !curve.is_complete(&self.trace_types, self.require_result) {
+            return None;
+        }
+
+        if self.emitted.len() == EMITTED_HISTORY {
+            self.emitted.pop_front();
+        }
+        self.emitted.push_back(result_data_id);
+        self.take(result_data_id)
+    }
+}
+
+/// The result data ID of a MID 0061 or MID 1201 result, or `None` for any other message.
+pub fn result_data_id(message: &Message) -> Option<u32> {
+    match message {
+        Message::MID0061rev1(result) => Some(result.tightening_id),
+        Message::MID0061rev2(result) => Some(result.tightening_id),
+        Message::MID0061rev3(result) => Some(result.tightening_id),
+        Message::MID0061rev4(result) => Some(result.tightening_id),
+        Message::MID0061rev5(result) => Some(result.tightening_id),
+        Message::MID0061rev6(result) => Some(result.tightening_id),
+        Message::MID0061rev7(result) => Some(result.tightening_id),
+        Message::MID0061rev998(result) => Some(result.tightening_id),
+        Message::MID1201rev1(result) => Some(result.result_data_id),
+        Message::MID1201rev2(result) => Some(result.result_data_id),
+        Message::MID1201rev3(result) => Some(result.result_data_id),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::tightening_result::MID0061rev2;
+
+    fn trace(trace_type: TraceType, interval_ms: u64, values: &[f64]) -> Trace {
+        Trace {
+            result_data_id: 42,
+            trace_type,
+            unit: 1,
+            samples: values.iter()
+                .enumerate()
+                .map(|(index, value)| (Duration::from_millis(index as u64 * interval_ms), *value))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn assemble_curve() {
+        let mut assembler = TighteningCurveAssembler::new([TraceType::Torque, TraceType::Angle], true);
+
+        assert_eq!(assembler.add_trace(trace(TraceType::Torque, 1, &[0.0, 1.0, 2.0])), None);
+        assert_eq!(assembler.add_trace(trace(TraceType::Angle, 2, &[0.0, 10.0])), None);
+
+        let result = Message::MID0061rev2(MID0061rev2 { tightening_id: 42, ..Default::default() });
+        let curve = assembler.add_result(result).unwrap();
+
+        assert_eq!(curve.result_data_id, 42);
+        assert!(curve.result.is_some());
+        assert_eq!(assembler.pending().count(), 0);
+        assert_eq!(curve.aligned(), vec![
+            AlignedSample { time: Duration::from_millis(0), torque: Some(0.0), angle: Some(0.0), current: None },
+            AlignedSample { time: Duration::from_millis(1), torque: Some(1.0), angle: Some(5.0), current: None },
+            AlignedSample { time: Duration::from_millis(2), torque: Some(2.0), angle: Some(10.0), current: None },
+        ]);
+    }
+
+    #[test]
+    fn add_result_ignores_other_messages() {
+        let mut assembler = TighteningCurveAssembler::new([TraceType::Torque], false);
+
+        assert_eq!(result_data_id(&Message::MID9999rev1(Default::default())), None);
+        assert_eq!(assembler.add_result(Message::MID9999rev1(Default::default())), None);
+        assert_eq!(assembler.pending().count(), 0);
+    }
+
+    #[test]
+    fn late_result_is_dropped() {
+        let mut assembler = TighteningCurveAssembler::new([TraceType::Torque], false);
+        let result = || Message::MID0061rev2(MID0061rev2 { tightening_id: 42, ..Default::default() });
+
+        let curve = assembler.add_trace(trace(TraceType::Torque, 1, &[0.0, 1.0])).unwrap();
+
+        assert_eq!(curve.result, None);
+        assert_eq!(assembler.add_result(result()), None);
+        assert_eq!(assembler.pending().count(), 0);
+    }
+
+    #[test]
+    fn early_result_is_kept() {
+        let mut assembler = TighteningCurveAssembler::new([TraceType::Torque], false);
+
+        assert_eq!(assembler.add_result(Message::MID0061rev2(MID0061rev2 { tightening_id: 42, ..Default::default() })), None);
+
+        let curve = assembler.add_trace(trace(TraceType::Torque, 1, &[0.0, 1.0])).unwrap();
+
+        assert!(curve.result.is_some());
+    }
+
+    #[test]
+    fn late_trace_is_dropped() {
+        let mut assembler = TighteningCurveAssembler::new([TraceType::Torque], false);
+
+        assert!(assembler.add_trace(trace(TraceType::Torque, 1, &[0.0, 1.0])).is_some());
+        assert_eq!(assembler.add_trace(trace(TraceType::Torque, 1, &[0.0, 1.0])), None);
+        assert_eq!(assembler.add_trace(trace(TraceType::Angle, 1, &[0.0, 5.0])), None);
+        assert_eq!(assembler.pending().count(), 0);
+    }
+
+    #[test]
+    fn oldest_pending_curve_is_dropped() {
+        let mut assembler = TighteningCurveAssembler::new([TraceType::Torque, TraceType::Angle], false);
+
+        for result_data_id in 0..=PENDING_LIMIT as u32 {
+            let trace = Trace { result_data_id, ..trace(TraceType::Torque, 1, &[0.0]) };
+            assert_eq!(assembler.add_trace(trace), None);
+        }
+
+        assert_eq!(assembler.pending().count(), PENDING_LIMIT);
+        assert_eq!(assembler.take(0), None);
+        assert!(assembler.take(1).is_some());
+        assert!(assembler.take(PENDING_LIMIT as u32).is_some());
+    }
+}
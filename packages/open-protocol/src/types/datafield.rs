@@ -1,12 +1,259 @@
-use chrono::{DateTime, Local};
+use chrono::NaiveDateTime;
 use open_protocol_codec::decode::{self, Decode, Decoder};
 use open_protocol_codec::encode::{self, Encode, Encoder};
 use crate::enums::datatype::DataType;
+use crate::enums::unit::Unit;
+use crate::types::pid;
+
+/// The number of characters of each element of a float, unsigned or signed integer array.
+pub const ARRAY_ELEMENT_LENGTH: usize = 8;
 
 pub trait ParseDataField<T> {
     fn parse(&self) -> decode::Result<T>;
 }
 
+/// A point of a plot, sent as a pair of float array elements in the order Y, X.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PlotPoint {
+    pub y: f64,
+    pub x: f64,
+}
+
+/// The typed value of a [`DataField`], one variant per [`DataType`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataValue {
+    UnsignedInteger(u64),
+    SignedInteger(i64),
+    Float(f64),
+    String(String),
+    Timestamp(NaiveDateTime),
+    Boolean(bool),
+    Hexadecimal(Vec<u8>),
+    PlotPointPL1(PlotPoint),
+    PlotPointPL2([PlotPoint; 2]),
+    PlotPointPL4([PlotPoint; 4]),
+    FloatArray(Vec<f64>),
+    UnsignedIntegerArray(Vec<u64>),
+    SignedIntegerArray(Vec<i64>),
+}
+
+impl DataValue {
+    pub fn data_type(&self) -> DataType {
+        match self {
+            DataValue::UnsignedInteger(_) => DataType::UnsignedInteger,
+            DataValue::SignedInteger(_) => DataType::SignedInteger,
+            DataValue::Float(_) => DataType::Float,
+            DataValue::String(_) => DataType::String,
+            DataValue::Timestamp(_) => DataType::Timestamp,
+            DataValue::Boolean(_) => DataType::Boolean,
+            DataValue::Hexadecimal(_) => DataType::Hexadecimal,
+            DataValue::PlotPointPL1(_) => DataType::PlotPointPL1,
+            DataValue::PlotPointPL2(_) => DataType::PlotPointPL2,
+            DataValue::PlotPointPL4(_) => DataType::PlotPointPL4,
+            DataValue::FloatArray(_) => DataType::FloatArray,
+            DataValue::UnsignedIntegerArray(_) => DataType::UnsignedIntegerArray,
+            DataValue::SignedIntegerArray(_) => DataType::SignedIntegerArray,
+        }
+    }
+
+    /// Parses the data value of a data field with the given data type.
+    pub fn parse(data_type: &DataType, value: &str) -> decode::Result<Self> {
+        Ok(match data_type {
+            DataType::UnsignedInteger => DataValue::UnsignedInteger(parse_number(value, 0)?),
+            DataType::SignedInteger => DataValue::SignedInteger(parse_number(value, 0)?),
+            DataType::Float => DataValue::Float(parse_number(value, 0)?),
+            DataType::String => DataValue::String(value.to_string()),
+            DataType::Timestamp => {
+                let mut decoder = Decoder::new(value.as_bytes());
+                DataValue::Timestamp(NaiveDateTime::decode_sized(&mut decoder, value.len())?)
+            },
+            DataType::Boolean => {
+                let mut decoder = Decoder::new(value.as_bytes());
+                DataValue::Boolean(bool::decode_sized(&mut decoder, value.len())?)
+            },
+            DataType::Hexadecimal => DataValue::Hexadecimal(parse_hexadecimal(value)?),
+            DataType::PlotPointPL1 => {
+                let [point] = parse_plot_points(value, "PlotPointPL1")?;
+                DataValue::PlotPointPL1(point)
+            },
+            DataType::PlotPointPL2 => DataValue::PlotPointPL2(parse_plot_points(value, "PlotPointPL2")?),
+            DataType::PlotPointPL4 => DataValue::PlotPointPL4(parse_plot_points(value, "PlotPointPL4")?),
+            DataType::FloatArray => DataValue::FloatArray(parse_array(value, "FloatArray")?),
+            DataType::UnsignedIntegerArray => DataValue::UnsignedIntegerArray(parse_array(value, "UnsignedIntegerArray")?),
+            DataType::SignedIntegerArray => DataValue::SignedIntegerArray(parse_array(value, "SignedIntegerArray")?),
+        })
+    }
+
+    /// Formats the value as it is sent in the data value of a data field.
+    pub fn format(&self) -> encode::Result<String> {
+        Ok(match self {
+            DataValue::UnsignedInteger(value) => value.to_string(),
+            DataValue::SignedInteger(value) => value.to_string(),
+            DataValue::Float(value) => check_finite(*value)?.to_string(),
+            DataValue::String(value) => value.clone(),
+            DataValue::Timestamp(value) => encode::encode(value)?,
+            DataValue::Boolean(value) => encode::encode(value)?,
+            DataValue::Hexadecimal(bytes) => bytes.iter().map(|byte| format!("{byte:02X}")).collect(),
+            DataValue::PlotPointPL1(point) => format_plot_points(std::slice::from_ref(point))?,
+            DataValue::PlotPointPL2(points) => format_plot_points(points)?,
+            DataValue::PlotPointPL4(points) => format_plot_points(points)?,
+            DataValue::FloatArray(values) => values.iter()
+                .map(|value| format_float_element(*value))
+                .collect::<encode::Result<String>>()?,
+            DataValue::UnsignedIntegerArray(values) => values.iter()
+                .map(|value| format_integer_element(*value as i128))
+                .collect::<encode::Result<String>>()?,
+            DataValue::SignedIntegerArray(values) => values.iter()
+                .map(|value| format_integer_element(*value as i128))
+                .collect::<encode::Result<String>>()?,
+        })
+    }
+}
+
+impl From<u64> for DataValue {
+    fn from(value: u64) -> Self {
+        DataValue::UnsignedInteger(value)
+    }
+}
+
+impl From<i64> for DataValue {
+    fn from(value: i64) -> Self {
+        DataValue::SignedInteger(value)
+    }
+}
+
+impl From<f64> for DataValue {
+    fn from(value: f64) -> Self {
+        DataValue::Float(value)
+    }
+}
+
+impl From<String> for DataValue {
+    fn from(value: String) -> Self {
+        DataValue::String(value)
+    }
+}
+
+impl From<&str> for DataValue {
+    fn from(value: &str) -> Self {
+        DataValue::String(value.to_string())
+    }
+}
+
+impl From<NaiveDateTime> for DataValue {
+    fn from(value: NaiveDateTime) -> Self {
+        DataValue::Timestamp(value)
+    }
+}
+
+impl From<bool> for DataValue {
+    fn from(value: bool) -> Self {
+        DataValue::Boolean(value)
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, offset: usize) -> decode::Result<T> {
+    value.trim()
+        .parse()
+        .map_err(|_| {
+            let (pos, c) = value.char_indices()
+                .find(|(_, c)| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | ' ')))
+                .unwrap_or((0, ' '));
+            decode::Error::InvalidCharacter(c, offset + pos)
+        })
+}
+
+fn parse_hexadecimal(value: &str) -> decode::Result<Vec<u8>> {
+    if !value.len().is_multiple_of(2) {
+        return Err(decode::Error::SizeMismatch { requested_size: value.len(), type_name: "Hexadecimal".into() });
+    }
+
+    let mut decoder = Decoder::new(value.as_bytes());
+    let mut bytes = Vec::with_capacity(value.len() / 2);
+
+    for _ in 0..value.len() / 2 {
+        let hi = parse_hex_digit(&mut decoder)?;
+        let lo = parse_hex_digit(&mut decoder)?;
+        bytes.push(hi * 16 + lo);
+    }
+
+    Ok(bytes)
+}
+
+fn parse_hex_digit(decoder: &mut Decoder) -> decode::Result<u8> {
+    let c = char::decode_sized(decoder, 1)?;
+    c.to_digit(16)
+        .map(|digit| digit as u8)
+        .ok_or(decode::Error::InvalidCharacter(c, decoder.pos() - 1))
+}
+
+fn check_finite(value: f64) -> encode::Result<f64> {
+    if !value.is_finite() {
+        return Err(encode::Error::NonFiniteNumber(value.to_string()));
+    }
+
+    Ok(value)
+}
+
+fn parse_array<T: std::str::FromStr>(value: &str, type_name: &str) -> decode::Result<Vec<T>> {
+    if !value.len().is_multiple_of(ARRAY_ELEMENT_LENGTH) {
+        return Err(decode::Error::SizeMismatch { requested_size: value.len(), type_name: type_name.into() });
+    }
+
+    (0..value.len())
+        .step_by(ARRAY_ELEMENT_LENGTH)
+        .map(|start| {
+            let element = value.get(start..start + ARRAY_ELEMENT_LENGTH)
+                .ok_or(decode::Error::SizeMismatch { requested_size: value.len(), type_name: type_name.into() })?;
+            parse_number(element, start)
+        })
+        .collect()
+}
+
+fn parse_plot_points<const N: usize>(value: &str, type_name: &str) -> decode::Result<[PlotPoint; N]> {
+    let values: Vec<f64> = parse_array(value, type_name)?;
+
+    if values.len() != 2 * N {
+        return Err(decode::Error::SizeMismatch { requested_size: value.len(), type_name: type_name.into() });
+    }
+
+    Ok(std::array::from_fn(|index| PlotPoint { y: values[2 * index], x: values[2 * index + 1] }))
+}
+
+fn format_plot_points(points: &[PlotPoint]) -> encode::Result<String> {
+    points.iter()
+        .flat_map(|point| [point.y, point.x])
+        .map(format_float_element)
+        .collect()
+}
+
+/// Formats a float array element with as many decimals as fit in 8 characters, padded with
+/// leading zeros after the sign. Large values are sent without decimal point.
+fn format_float_element(value: f64) -> encode::Result<String> {
+    let value = check_finite(value)?;
+    let sign = if value < 0.0 { "-" } else { "" };
+    let width = ARRAY_ELEMENT_LENGTH - sign.len();
+
+    (0..width - 1)
+        .rev()
+        .map(|decimals| format!("{:.*}", decimals, value.abs()))
+        .find(|digits| digits.len() <= width)
+        .map(|digits| format!("{sign}{digits:0>width$}"))
+        .ok_or(encode::Error::InvalidSize(ARRAY_ELEMENT_LENGTH))
+}
+
+fn format_integer_element(value: i128) -> encode::Result<String> {
+    let sign = if value < 0 { "-" } else { "" };
+    let width = ARRAY_ELEMENT_LENGTH - sign.len();
+    let digits = value.unsigned_abs().to_string();
+
+    if digits.len() > width {
+        return Err(encode::Error::InvalidSize(ARRAY_ELEMENT_LENGTH));
+    }
+
+    Ok(format!("{sign}{digits:0>width$}"))
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DataField {
     /// Parameter id (PID). 5 bytes, UI. The available PIDs may vary depending on the system type.
@@ -23,6 +270,36 @@ pub struct DataField {
     pub data_value: String,
 }
 
+impl DataField {
+    /// Creates a data field holding `value`, with its data type and length taken from the value.
+    pub fn new(parameter_id: u32, unit: u16, step_no: Option<u16>, value: impl Into<DataValue>) -> encode::Result<Self> {
+        let value = value.into();
+        let data_value = value.format()?;
+        let length = u16::try_from(data_value.len())
+            .ok()
+            .filter(|length| *length <= 999)
+            .ok_or(encode::Error::InvalidSize(data_value.len()))?;
+
+        Ok(Self {
+            parameter_id,
+            length,
+            data_type: value.data_type(),
+            unit,
+            step_no,
+            data_value,
+        })
+    }
+
+    /// The name of the PID, if it is in the [`pid`] registry.
+    pub fn name(&self) -> Option<String> {
+        pid::lookup(self.parameter_id).map(|definition| definition.name.into_owned())
+    }
+
+    pub fn unit_kind(&self) -> Unit {
+        Unit::from(self.unit)
+    }
+}
+
 impl Encode for DataField {
     fn encode(&self, encoder: &mut Encoder) -> encode::Result<()> {
         self.parameter_id.encode_sized(encoder, 5)?;
@@ -89,6 +366,24 @@ impl ParseDataField<u64> for DataField {
     }
 }
 
+impl ParseDataField<f64> for DataField {
+    fn parse(&self) -> decode::Result<f64> {
+        match self.data_type {
+            DataType::UnsignedInteger | DataType::SignedInteger | DataType::Float => {
+                parse_number(&self.data_value, 0)
+            },
+
+            _ => Err(decode::Error::NotImplemented),
+        }
+    }
+}
+
+impl ParseDataField<DataValue> for DataField {
+    fn parse(&self) -> decode::Result<DataValue> {
+        DataValue::parse(&self.data_type, &self.data_value)
+    }
+}
+
 impl ParseDataField<bool> for DataField {
     fn parse(&self) -> decode::Result<bool> {
         if self.data_type != DataType::Boolean {
@@ -110,35 +405,21 @@ impl ParseDataField<String> for DataField {
     }
 }
 
-impl ParseDataField<DateTime<Local>> for DataField {
-    fn parse(&self) -> decode::Result<DateTime<Local>> {
+impl ParseDataField<NaiveDateTime> for DataField {
+    fn parse(&self) -> decode::Result<NaiveDateTime> {
         if self.data_type != DataType::Timestamp {
             return Err(decode::Error::NotImplemented);
         }
 
         let mut decoder = Decoder::new(self.data_value.as_bytes());
-        DateTime::<Local>::decode_sized(&mut decoder, self.length as usize)
+        NaiveDateTime::decode_sized(&mut decoder, self.length as usize)
     }
 }
 
 impl ParseDataField<Vec<u8>> for DataField {
     fn parse(&self) -> decode::Result<Vec<u8>> {
         match self.data_type {
-            DataType::Hexadecimal => {
-                let mut decoder = Decoder::new(self.data_value.as_bytes());
-                let num_bytes = (self.length as usize) / 2;
-                let mut bytes = Vec::with_capacity(num_bytes);
-
-                for _ in 0..num_bytes {
-                    let c1 = char::decode_sized(&mut decoder, 1)?;
-                    let c2 = char::decode_sized(&mut decoder, 1)?;
-                    let hi = c1.to_digit(16).ok_or(decode::Error::NotImplemented)?;
-                    let lo = c2.to_digit(16).ok_or(decode::Error::NotImplemented)?;
-                    bytes.push((hi * 16 + lo) as u8);
-                }
-
-                Ok(bytes)
-            },
+            DataType::Hexadecimal => parse_hexadecimal(&self.data_value),
 
             _ => Err(decode::Error::NotImplemented),
         }
@@ -196,4 +477,91 @@ mod tests {
 
         assert_eq!(parsed, Ok(vec![0xDE, 0xAD, 0xBE, 0xEF]));
     }
+
+    #[test]
+    fn test_parse_invalid_bytes() {
+        assert_eq!(
+            DataValue::parse(&DataType::Hexadecimal, "DEADBEE"),
+            Err(decode::Error::SizeMismatch { requested_size: 7, type_name: "Hexadecimal".into() })
+        );
+        assert_eq!(DataValue::parse(&DataType::Hexadecimal, "DEADBXEF"), Err(decode::Error::InvalidCharacter('X', 5)));
+    }
+
+    #[test]
+    fn test_parse_float_array() {
+        let df = DataField {
+            parameter_id: 1,
+            length: 24,
+            data_type: DataType::FloatArray,
+            unit: 1,
+            step_no: None,
+            data_value: "-1234567001.1205-123.789".into()
+        };
+
+        let parsed: decode::Result<DataValue> = df.parse();
+
+        assert_eq!(parsed, Ok(DataValue::FloatArray(vec![-1234567.0, 1.1205, -123.789])));
+    }
+
+    #[test]
+    fn test_parse_plot_point_wrong_length() {
+        let parsed = DataValue::parse(&DataType::PlotPointPL2, "0001.0000002.0000");
+
+        assert!(matches!(parsed, Err(decode::Error::SizeMismatch { .. })));
+    }
+
+    #[test]
+    fn test_new_computes_length() {
+        let df = DataField::new(2213, 1, Some(2), DataValue::SignedIntegerArray(vec![-42, 200000])).unwrap();
+
+        assert_eq!(df.length, 16);
+        assert_eq!(df.data_type, DataType::SignedIntegerArray);
+        assert_eq!(df.data_value, "-000004200200000");
+        assert_eq!(encode::encode(&df).unwrap(), "02213016520010002-000004200200000");
+    }
+
+    #[test]
+    fn test_roundtrip_values() {
+        let values = [
+            DataValue::UnsignedInteger(1250),
+            DataValue::SignedInteger(-57),
+            DataValue::Float(12.12),
+            DataValue::String("Bolt 1".into()),
+            DataValue::Boolean(true),
+            DataValue::Hexadecimal(vec![0xA2, 0x4C, 0xD3]),
+            DataValue::PlotPointPL1(PlotPoint { y: 12.5, x: -3.0 }),
+            DataValue::FloatArray(vec![1.1205, -12345.6, 99999999.0]),
+            DataValue::UnsignedIntegerArray(vec![12345678, 1234]),
+        ];
+
+        for value in values {
+            let df = DataField::new(1, 1, None, value.clone()).unwrap();
+
+            assert_eq!(ParseDataField::<DataValue>::parse(&df), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_new_rejects_too_large_array_element() {
+        let df = DataField::new(1, 1, None, DataValue::UnsignedIntegerArray(vec![123456789]));
+
+        assert_eq!(df, Err(encode::Error::InvalidSize(ARRAY_ELEMENT_LENGTH)));
+    }
+
+    #[test]
+    fn test_new_rejects_non_finite_float() {
+        assert_eq!(DataField::new(1, 1, None, f64::NAN), Err(encode::Error::NonFiniteNumber("NaN".into())));
+        assert_eq!(
+            DataField::new(1, 1, None, DataValue::FloatArray(vec![1.0, f64::INFINITY])),
+            Err(encode::Error::NonFiniteNumber("inf".into()))
+        );
+    }
+
+    #[test]
+    fn test_name_and_unit_kind() {
+        let df = DataField::new(2213, 1, None, 12.5).unwrap();
+
+        assert_eq!(df.name().as_deref(), Some("Torque trace coefficient"));
+        assert_eq!(df.unit_kind(), Unit::NewtonMetre);
+    }
 }
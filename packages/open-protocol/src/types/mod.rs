@@ -1,2 +1,6 @@
 pub mod datafield;
-pub mod trace;
\ No newline at end of file
+pub mod trace;
+pub mod curve;
+pub mod pid;
+pub mod tightening_result;
+pub mod timestamp;
@@ -0,0 +1,210 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::sync::{LazyLock, RwLock};
+use crate::enums::datatype::DataType;
+use crate::enums::unit::Unit;
+use crate::messages::tightening_result::TraceType;
+
+pub const VIN_NUMBER: u32 = 1001;
+pub const IDENTIFIER_RESULT_PART_2: u32 = 1002;
+pub const IDENTIFIER_RESULT_PART_3: u32 = 1003;
+pub const IDENTIFIER_RESULT_PART_4: u32 = 1004;
+pub const JOB_ID: u32 = 1010;
+pub const PARAMETER_SET_ID: u32 = 1011;
+pub const PARAMETER_SET_NAME: u32 = 1012;
+pub const STRATEGY: u32 = 1013;
+pub const BATCH_SIZE: u32 = 1020;
+pub const BATCH_COUNTER: u32 = 1021;
+pub const TOOL_SERIAL_NUMBER: u32 = 1030;
+pub const CONTROLLER_NAME: u32 = 1031;
+pub const TORQUE: u32 = 2001;
+pub const TORQUE_MIN_LIMIT: u32 = 2002;
+pub const TORQUE_MAX_LIMIT: u32 = 2003;
+pub const TORQUE_FINAL_TARGET: u32 = 2004;
+pub const SELF_TAP_TORQUE: u32 = 2005;
+pub const PREVAIL_TORQUE: u32 = 2006;
+pub const ANGLE: u32 = 2011;
+pub const ANGLE_MIN_LIMIT: u32 = 2012;
+pub const ANGLE_MAX_LIMIT: u32 = 2013;
+pub const ANGLE_FINAL_TARGET: u32 = 2014;
+pub const RUNDOWN_ANGLE: u32 = 2015;
+pub const CURRENT_MONITORING_VALUE: u32 = 2021;
+pub const TORQUE_TRACE_COEFFICIENT: u32 = 2213;
+pub const ANGLE_TRACE_COEFFICIENT: u32 = 2214;
+pub const CURRENT_TRACE_COEFFICIENT: u32 = 2215;
+pub const GRADIENT_TRACE_COEFFICIENT: u32 = 2216;
+pub const STROKE_TRACE_COEFFICIENT: u32 = 2217;
+pub const FORCE_TRACE_COEFFICIENT: u32 = 2218;
+
+/// What a parameter ID (PID) of a data field stands for.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PidDefinition {
+    pub name: Cow<'static, str>,
+    /// The data type the value is expected to be sent as.
+    pub data_type: DataType,
+    /// The unit the value is expected to be sent in, `None` for values without a unit.
+    pub unit: Option<Unit>,
+    /// The trace type whose samples this PID is the coefficient of, when sent in the parameter
+    /// fields of MID 0900.
+    pub trace_coefficient: Option<TraceType>,
+}
+
+impl PidDefinition {
+    pub fn new(name: impl Into<Cow<'static, str>>, data_type: DataType, unit: impl Into<Option<Unit>>) -> Self {
+        Self { name: name.into(), data_type, unit: unit.into(), trace_coefficient: None }
+    }
+
+    /// Marks the PID as the coefficient of the samples of `trace_type`.
+    pub fn with_trace_coefficient(mut self, trace_type: TraceType) -> Self {
+        self.trace_coefficient = Some(trace_type);
+        self
+    }
+}
+
+/// A builtin PID with its name, data type, unit and the trace type it is the coefficient of.
+type BuiltinPid = (u32, &'static str, DataType, Option<Unit>, Option<TraceType>);
+
+/// The PIDs known without any registration: the values MID 0061 revision 998 and MID 1201 send
+/// in their data fields and the trace coefficients MID 0900 sends in its parameter fields. A
+/// trace coefficient has no unit of its own, the samples take the unit of the trace.
+const BUILTIN_PIDS: &[BuiltinPid] = &[
+    (VIN_NUMBER, "VIN number", DataType::String, None, None),
+    (IDENTIFIER_RESULT_PART_2, "Identifier result part 2", DataType::String, None, None),
+    (IDENTIFIER_RESULT_PART_3, "Identifier result part 3", DataType::String, None, None),
+    (IDENTIFIER_RESULT_PART_4, "Identifier result part 4", DataType::String, None, None),
+    (JOB_ID, "Job ID", DataType::UnsignedInteger, None, None),
+    (PARAMETER_SET_ID, "Parameter set ID", DataType::UnsignedInteger, None, None),
+    (PARAMETER_SET_NAME, "Parameter set name", DataType::String, None, None),
+    (STRATEGY, "Strategy", DataType::UnsignedInteger, None, None),
+    (BATCH_SIZE, "Batch size", DataType::UnsignedInteger, None, None),
+    (BATCH_COUNTER, "Batch counter", DataType::UnsignedInteger, None, None),
+    (TOOL_SERIAL_NUMBER, "Tool serial number", DataType::String, None, None),
+    (CONTROLLER_NAME, "Controller name", DataType::String, None, None),
+    (TORQUE, "Torque", DataType::Float, Some(Unit::NewtonMetre), None),
+    (TORQUE_MIN_LIMIT, "Torque min limit", DataType::Float, Some(Unit::NewtonMetre), None),
+    (TORQUE_MAX_LIMIT, "Torque max limit", DataType::Float, Some(Unit::NewtonMetre), None),
+    (TORQUE_FINAL_TARGET, "Torque final target", DataType::Float, Some(Unit::NewtonMetre), None),
+    (SELF_TAP_TORQUE, "Self tap torque", DataType::Float, Some(Unit::NewtonMetre), None),
+    (PREVAIL_TORQUE, "Prevail torque", DataType::Float, Some(Unit::NewtonMetre), None),
+    (ANGLE, "Angle", DataType::Float, Some(Unit::Degree), None),
+    (ANGLE_MIN_LIMIT, "Angle min limit", DataType::Float, Some(Unit::Degree), None),
+    (ANGLE_MAX_LIMIT, "Angle max limit", DataType::Float, Some(Unit::Degree), None),
+    (ANGLE_FINAL_TARGET, "Angle final target", DataType::Float, Some(Unit::Degree), None),
+    (RUNDOWN_ANGLE, "Rundown angle", DataType::Float, Some(Unit::Degree), None),
+    (CURRENT_MONITORING_VALUE, "Current monitoring value", DataType::UnsignedInteger, Some(Unit::Percent), None),
+    (TORQUE_TRACE_COEFFICIENT, "Torque trace coefficient", DataType::Float, None, Some(TraceType::Torque)),
+    (ANGLE_TRACE_COEFFICIENT, "Angle trace coefficient", DataType::Float, None, Some(TraceType::Angle)),
+    (CURRENT_TRACE_COEFFICIENT, "Current trace coefficient", DataType::Float, None, Some(TraceType::Current)),
+    (GRADIENT_TRACE_COEFFICIENT, "Gradient trace coefficient", DataType::Float, None, Some(TraceType::Gradient)),
+    (STROKE_TRACE_COEFFICIENT, "Stroke trace coefficient", DataType::Float, None, Some(TraceType::Stroke)),
+    (FORCE_TRACE_COEFFICIENT, "Force trace coefficient", DataType::Float, None, Some(TraceType::Force)),
+];
+
+/// A set of PID definitions. The functions of this module use a process wide registry that
+/// starts with the builtin PIDs.
+#[derive(Debug, Clone, Default)]
+pub struct PidRegistry {
+    pids: BTreeMap<u32, PidDefinition>,
+}
+
+impl PidRegistry {
+    /// A registry with the builtin PIDs.
+    pub fn builtin() -> Self {
+        let pids = BUILTIN_PIDS.iter()
+            .map(|(pid, name, data_type, unit, trace_coefficient)| {
+                let definition = PidDefinition { trace_coefficient: *trace_coefficient, ..PidDefinition::new(*name, data_type.clone(), *unit) };
+                (*pid, definition)
+            })
+            .collect();
+
+        Self { pids }
+    }
+
+    pub fn lookup(&self, parameter_id: u32) -> Option<&PidDefinition> {
+        self.pids.get(&parameter_id)
+    }
+
+    /// The PID of the coefficient of the samples of `trace_type`, the lowest one if several
+    /// PIDs are marked as its coefficient.
+    pub fn trace_coefficient(&self, trace_type: TraceType) -> Option<u32> {
+        self.pids.iter()
+            .find(|(_, definition)| definition.trace_coefficient == Some(trace_type))
+            .map(|(parameter_id, _)| *parameter_id)
+    }
+
+    /// Adds a PID, or replaces a known one. Returns the definition it replaced.
+    pub fn register(&mut self, parameter_id: u32, definition: PidDefinition) -> Option<PidDefinition> {
+        self.pids.insert(parameter_id, definition)
+    }
+}
+
+static REGISTRY: LazyLock<RwLock<PidRegistry>> = LazyLock::new(|| RwLock::new(PidRegistry::builtin()));
+
+/// Looks up a PID in the registry.
+pub fn lookup(parameter_id: u32) -> Option<PidDefinition> {
+    REGISTRY.read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .lookup(parameter_id)
+        .cloned()
+}
+
+/// The PID of the coefficient of the samples of `trace_type` in the registry, see
+/// [`PidRegistry::trace_coefficient`].
+pub fn trace_coefficient(trace_type: TraceType) -> Option<u32> {
+    REGISTRY.read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .trace_coefficient(trace_type)
+}
+
+/// Adds a controller specific PID to the registry, or replaces a known one. Returns the
+/// definition it replaced.
+pub fn register(parameter_id: u32, definition: PidDefinition) -> Option<PidDefinition> {
+    REGISTRY.write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .register(parameter_id, definition)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_builtin() {
+        assert_eq!(lookup(TORQUE_FINAL_TARGET).map(|definition| definition.name), Some("Torque final target".into()));
+        assert_eq!(lookup(TORQUE_FINAL_TARGET).and_then(|definition| definition.unit), Some(Unit::NewtonMetre));
+        assert_eq!(lookup(2213).map(|definition| definition.name), Some("Torque trace coefficient".into()));
+        assert_eq!(lookup(99990), None);
+    }
+
+    #[test]
+    fn builtin_trace_coefficients() {
+        assert_eq!(trace_coefficient(TraceType::Torque), Some(2213));
+        assert_eq!(trace_coefficient(TraceType::Angle), Some(2214));
+        assert_eq!(trace_coefficient(TraceType::Current), Some(2215));
+        assert_eq!(trace_coefficient(TraceType::Gradient), Some(2216));
+        assert_eq!(trace_coefficient(TraceType::Stroke), Some(2217));
+        assert_eq!(trace_coefficient(TraceType::Force), Some(2218));
+    }
+
+    #[test]
+    fn register_controller_specific() {
+        let mut registry = PidRegistry::builtin();
+        let definition = PidDefinition::new("Clamp force", DataType::UnsignedInteger, Unit::Other(300));
+
+        assert_eq!(registry.register(99991, definition.clone()), None);
+        assert_eq!(registry.lookup(99991), Some(&definition));
+        assert_eq!(lookup(99991), None);
+    }
+
+    #[test]
+    fn lowest_trace_coefficient_wins() {
+        let mut registry = PidRegistry::builtin();
+        let definition = PidDefinition::new("Force trace coefficient", DataType::Float, None)
+            .with_trace_coefficient(TraceType::Force);
+
+        registry.register(99992, definition.clone());
+        registry.register(2000, definition);
+
+        assert_eq!(registry.trace_coefficient(TraceType::Force), Some(2000));
+    }
+}
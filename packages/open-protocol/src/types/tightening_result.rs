@@ -0,0 +1,768 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use crate::enums::status::{BatchStatus, LimitStatus, OkNok, ResultType};
+use crate::enums::unit::Unit;
+use crate::messages::result::{MID1201rev1, MID1201rev2, MID1201rev3, OperationObject};
+use crate::messages::tightening_result::{
+    MID0061rev1,
+    MID0061rev2,
+    MID0061rev3,
+    MID0061rev4,
+    MID0061rev5,
+    MID0061rev6,
+    MID0061rev7,
+    MID0061rev998,
+    MID0065rev1,
+    MID0065rev2,
+    MID0065rev3,
+    MID0065rev4,
+    MID0065rev5,
+    MID0065rev6,
+    MID0065rev7,
+};
+use crate::types::datafield::{DataField, ParseDataField};
+use crate::types::pid;
+use crate::types::timestamp::{ControllerTimezone, TimestampError};
+use crate::Message;
+
+/// A tightening result independent of the MID and revision it was received with. Fields that
+/// the received revision does not carry are `None`. Values keep the scaling of the messages, the
+/// data fields of MID 1201 are scaled like MID 0061: torques multiplied by 100, angles in whole
+/// degrees.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct TighteningResult {
+    /// The tightening ID, or the result data ID for MID 1201.
+    pub tightening_id: u32,
+    pub timestamp: NaiveDateTime,
+    pub vin_number: Option<String>,
+    pub cell_id: Option<u16>,
+    pub channel_id: Option<u8>,
+    pub controller_name: Option<String>,
+    pub job_id: Option<u16>,
+    pub parameter_set_id: Option<u16>,
+    pub parameter_set_name: Option<String>,
+    pub strategy: Option<u8>,
+    pub batch_size: Option<u16>,
+    pub batch_counter: Option<u16>,
+    pub batch_status: Option<BatchStatus>,
+    pub tightening_status: Option<OkNok>,
+    pub torque_status: Option<LimitStatus>,
+    pub angle_status: Option<LimitStatus>,
+    pub result_type: Option<ResultType>,
+    pub torque_unit: Option<Unit>,
+    pub torque: Option<u32>,
+    pub torque_min_limit: Option<u32>,
+    pub torque_max_limit: Option<u32>,
+    pub torque_final_target: Option<u32>,
+    pub angle: Option<u16>,
+    pub angle_min_limit: Option<u16>,
+    pub angle_max_limit: Option<u16>,
+    pub angle_final_target: Option<u16>,
+    pub rundown_angle: Option<u16>,
+    pub current_monitoring_value: Option<u16>,
+    pub self_tap_torque: Option<u32>,
+    pub prevail_torque: Option<u32>,
+    pub tightening_error_status: Option<String>,
+    pub tool_serial_number: Option<String>,
+    /// Data fields (PIDs) of MID 0061 revision 998 and the overall data fields of MID 1201.
+    pub data_fields: Vec<DataField>,
+}
+
+impl TighteningResult {
+    /// The normalized result of a MID 0061, MID 0065 or MID 1201 message, or `None` for any
+    /// other message.
+    pub fn from_message(message: &Message) -> Option<Self> {
+        Some(match message {
+            Message::MID0061rev1(result) => result.into(),
+            Message::MID0061rev2(result) => result.into(),
+            Message::MID0061rev3(result) => result.into(),
+            Message::MID0061rev4(result) => result.into(),
+            Message::MID0061rev5(result) => result.into(),
+            Message::MID0061rev6(result) => result.into(),
+            Message::MID0061rev7(result) => result.into(),
+            Message::MID0061rev998(result) => result.into(),
+            Message::MID0065rev1(result) => result.into(),
+            Message::MID0065rev2(result) => result.into(),
+            Message::MID0065rev3(result) => result.into(),
+            Message::MID0065rev4(result) => result.into(),
+            Message::MID0065rev5(result) => result.into(),
+            Message::MID0065rev6(result) => result.into(),
+            Message::MID0065rev7(result) => result.into(),
+            Message::MID1201rev1(result) => result.into(),
+            Message::MID1201rev2(result) => result.into(),
+            Message::MID1201rev3(result) => result.into(),
+            _ => return None,
+        })
+    }
+
+    /// The timestamp in UTC, given the timezone of the controller clock it was sent by.
+    pub fn timestamp_utc(&self, timezone: &ControllerTimezone) -> Result<DateTime<Utc>, TimestampError> {
+        timezone.to_utc(self.timestamp)
+    }
+}
+
+impl From<&MID0061rev1> for TighteningResult {
+    fn from(value: &MID0061rev1) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            cell_id: Some(value.cell_id),
+            channel_id: Some(value.channel_id),
+            controller_name: Some(value.controller_name.clone()),
+            job_id: Some(u16::from(value.job_id)),
+            parameter_set_id: Some(value.parameter_set_id),
+            batch_size: Some(value.batch_size),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            torque: Some(value.torque),
+            torque_min_limit: Some(value.torque_min_limit),
+            torque_max_limit: Some(value.torque_max_limit),
+            torque_final_target: Some(value.torque_final_target),
+            angle: Some(value.angle),
+            angle_min_limit: Some(value.angle_min_limit),
+            angle_max_limit: Some(value.angle_max_limit),
+            angle_final_target: Some(value.angle_final_target),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MID0061rev2> for TighteningResult {
+    fn from(value: &MID0061rev2) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            cell_id: Some(value.cell_id),
+            channel_id: Some(value.channel_id),
+            controller_name: Some(value.controller_name.clone()),
+            job_id: Some(value.job_id),
+            parameter_set_id: Some(value.parameter_set_id),
+            strategy: Some(value.strategy),
+            batch_size: Some(value.batch_size),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            torque: Some(value.torque),
+            torque_min_limit: Some(value.torque_min_limit),
+            torque_max_limit: Some(value.torque_max_limit),
+            torque_final_target: Some(value.torque_final_target),
+            angle: Some(value.angle),
+            angle_min_limit: Some(value.angle_min_limit),
+            angle_max_limit: Some(value.angle_max_limit),
+            angle_final_target: Some(value.angle_final_target),
+            rundown_angle: Some(value.rundown_angle),
+            current_monitoring_value: Some(value.current_monitoring_value),
+            self_tap_torque: Some(value.self_tap_torque),
+            prevail_torque: Some(value.prevail_torque),
+            tightening_error_status: Some(value.tightening_error_status.clone()),
+            tool_serial_number: Some(value.tool_serial_number.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MID0061rev3> for TighteningResult {
+    fn from(value: &MID0061rev3) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            cell_id: Some(value.cell_id),
+            channel_id: Some(value.channel_id),
+            controller_name: Some(value.controller_name.clone()),
+            job_id: Some(value.job_id),
+            parameter_set_id: Some(value.parameter_set_id),
+            parameter_set_name: Some(value.parameter_set_name.clone()),
+            strategy: Some(value.strategy),
+            batch_size: Some(value.batch_size),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            result_type: Some(value.result_type),
+            torque_unit: Some(value.torque_unit.into()),
+            torque: Some(value.torque),
+            torque_min_limit: Some(value.torque_min_limit),
+            torque_max_limit: Some(value.torque_max_limit),
+            torque_final_target: Some(value.torque_final_target),
+            angle: Some(value.angle),
+            angle_min_limit: Some(value.angle_min_limit),
+            angle_max_limit: Some(value.angle_max_limit),
+            angle_final_target: Some(value.angle_final_target),
+            rundown_angle: Some(value.rundown_angle),
+            current_monitoring_value: Some(value.current_monitoring_value),
+            self_tap_torque: Some(value.self_tap_torque),
+            prevail_torque: Some(value.prevail_torque),
+            tightening_error_status: Some(value.tightening_error_status.clone()),
+            tool_serial_number: Some(value.tool_serial_number.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MID0061rev4> for TighteningResult {
+    fn from(value: &MID0061rev4) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            cell_id: Some(value.cell_id),
+            channel_id: Some(value.channel_id),
+            controller_name: Some(value.controller_name.clone()),
+            job_id: Some(value.job_id),
+            parameter_set_id: Some(value.parameter_set_id),
+            parameter_set_name: Some(value.parameter_set_name.clone()),
+            strategy: Some(value.strategy),
+            batch_size: Some(value.batch_size),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            result_type: Some(value.result_type),
+            torque_unit: Some(value.torque_unit.into()),
+            torque: Some(value.torque),
+            torque_min_limit: Some(value.torque_min_limit),
+            torque_max_limit: Some(value.torque_max_limit),
+            torque_final_target: Some(value.torque_final_target),
+            angle: Some(value.angle),
+            angle_min_limit: Some(value.angle_min_limit),
+            angle_max_limit: Some(value.angle_max_limit),
+            angle_final_target: Some(value.angle_final_target),
+            rundown_angle: Some(value.rundown_angle),
+            current_monitoring_value: Some(value.current_monitoring_value),
+            self_tap_torque: Some(value.self_tap_torque),
+            prevail_torque: Some(value.prevail_torque),
+            tightening_error_status: Some(value.tightening_error_status.clone()),
+            tool_serial_number: Some(value.tool_serial_number.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MID0061rev5> for TighteningResult {
+    fn from(value: &MID0061rev5) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            cell_id: Some(value.cell_id),
+            channel_id: Some(value.channel_id),
+            controller_name: Some(value.controller_name.clone()),
+            job_id: Some(value.job_id),
+            parameter_set_id: Some(value.parameter_set_id),
+            parameter_set_name: Some(value.parameter_set_name.clone()),
+            strategy: Some(value.strategy),
+            batch_size: Some(value.batch_size),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            result_type: Some(value.result_type),
+            torque_unit: Some(value.torque_unit.into()),
+            torque: Some(value.torque),
+            torque_min_limit: Some(value.torque_min_limit),
+            torque_max_limit: Some(value.torque_max_limit),
+            torque_final_target: Some(value.torque_final_target),
+            angle: Some(value.angle),
+            angle_min_limit: Some(value.angle_min_limit),
+            angle_max_limit: Some(value.angle_max_limit),
+            angle_final_target: Some(value.angle_final_target),
+            rundown_angle: Some(value.rundown_angle),
+            current_monitoring_value: Some(value.current_monitoring_value),
+            self_tap_torque: Some(value.self_tap_torque),
+            prevail_torque: Some(value.prevail_torque),
+            tightening_error_status: Some(value.tightening_error_status.clone()),
+            tool_serial_number: Some(value.tool_serial_number.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MID0061rev6> for TighteningResult {
+    fn from(value: &MID0061rev6) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            cell_id: Some(value.cell_id),
+            channel_id: Some(value.channel_id),
+            controller_name: Some(value.controller_name.clone()),
+            job_id: Some(value.job_id),
+            parameter_set_id: Some(value.parameter_set_id),
+            parameter_set_name: Some(value.parameter_set_name.clone()),
+            strategy: Some(value.strategy),
+            batch_size: Some(value.batch_size),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            result_type: Some(value.result_type),
+            torque_unit: Some(value.torque_unit.into()),
+            torque: Some(value.torque),
+            torque_min_limit: Some(value.torque_min_limit),
+            torque_max_limit: Some(value.torque_max_limit),
+            torque_final_target: Some(value.torque_final_target),
+            angle: Some(value.angle),
+            angle_min_limit: Some(value.angle_min_limit),
+            angle_max_limit: Some(value.angle_max_limit),
+            angle_final_target: Some(value.angle_final_target),
+            rundown_angle: Some(value.rundown_angle),
+            current_monitoring_value: Some(value.current_monitoring_value),
+            self_tap_torque: Some(value.self_tap_torque),
+            prevail_torque: Some(value.prevail_torque),
+            tightening_error_status: Some(value.tightening_error_status.clone()),
+            tool_serial_number: Some(value.tool_serial_number.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MID0061rev7> for TighteningResult {
+    fn from(value: &MID0061rev7) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            cell_id: Some(value.cell_id),
+            channel_id: Some(value.channel_id),
+            controller_name: Some(value.controller_name.clone()),
+            job_id: Some(value.job_id),
+            parameter_set_id: Some(value.parameter_set_id),
+            parameter_set_name: Some(value.parameter_set_name.clone()),
+            strategy: Some(value.strategy),
+            batch_size: Some(value.batch_size),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            result_type: Some(value.result_type),
+            torque_unit: Some(value.torque_unit.into()),
+            torque: Some(value.torque),
+            torque_min_limit: Some(value.torque_min_limit),
+            torque_max_limit: Some(value.torque_max_limit),
+            torque_final_target: Some(value.torque_final_target),
+            angle: Some(value.angle),
+            angle_min_limit: Some(value.angle_min_limit),
+            angle_max_limit: Some(value.angle_max_limit),
+            angle_final_target: Some(value.angle_final_target),
+            rundown_angle: Some(value.rundown_angle),
+            current_monitoring_value: Some(value.current_monitoring_value),
+            self_tap_torque: Some(value.self_tap_torque),
+            prevail_torque: Some(value.prevail_torque),
+            tightening_error_status: Some(value.tightening_error_status.clone()),
+            tool_serial_number: Some(value.tool_serial_number.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MID0061rev998> for TighteningResult {
+    fn from(value: &MID0061rev998) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            cell_id: Some(value.cell_id),
+            channel_id: Some(value.channel_id),
+            controller_name: Some(value.controller_name.clone()),
+            job_id: Some(value.job_id),
+            parameter_set_id: Some(value.parameter_set_id),
+            parameter_set_name: Some(value.parameter_set_name.clone()),
+            strategy: Some(value.strategy),
+            batch_size: Some(value.batch_size),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            result_type: Some(value.result_type),
+            torque_unit: Some(value.torque_unit.into()),
+            torque: Some(value.torque),
+            torque_min_limit: Some(value.torque_min_limit),
+            torque_max_limit: Some(value.torque_max_limit),
+            torque_final_target: Some(value.torque_final_target),
+            angle: Some(value.angle),
+            angle_min_limit: Some(value.angle_min_limit),
+            angle_max_limit: Some(value.angle_max_limit),
+            angle_final_target: Some(value.angle_final_target),
+            rundown_angle: Some(value.rundown_angle),
+            current_monitoring_value: Some(value.current_monitoring_value),
+            self_tap_torque: Some(value.self_tap_torque),
+            prevail_torque: Some(value.prevail_torque),
+            tightening_error_status: Some(value.tightening_error_status.clone()),
+            tool_serial_number: Some(value.tool_serial_number.clone()),
+            data_fields: value.data_fields.clone(),
+        }
+    }
+}
+
+impl From<&MID0065rev1> for TighteningResult {
+    fn from(value: &MID0065rev1) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            parameter_set_id: Some(value.parameter_set_id),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            torque: Some(value.torque),
+            angle: Some(value.angle),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MID0065rev2> for TighteningResult {
+    fn from(value: &MID0065rev2) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            parameter_set_id: Some(value.parameter_set_id),
+            strategy: Some(value.strategy),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            torque: Some(value.torque),
+            angle: Some(value.angle),
+            rundown_angle: Some(value.rundown_angle),
+            current_monitoring_value: Some(value.current_monitoring_value),
+            self_tap_torque: Some(value.self_tap_torque),
+            prevail_torque: Some(value.prevail_torque),
+            tightening_error_status: Some(value.tightening_error_status.clone()),
+            tool_serial_number: Some(value.tool_serial_number.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MID0065rev3> for TighteningResult {
+    fn from(value: &MID0065rev3) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            parameter_set_id: Some(value.parameter_set_id),
+            parameter_set_name: Some(value.parameter_set_name.clone()),
+            strategy: Some(value.strategy),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            result_type: Some(value.result_type),
+            torque_unit: Some(value.torque_unit.into()),
+            torque: Some(value.torque),
+            angle: Some(value.angle),
+            rundown_angle: Some(value.rundown_angle),
+            current_monitoring_value: Some(value.current_monitoring_value),
+            self_tap_torque: Some(value.self_tap_torque),
+            prevail_torque: Some(value.prevail_torque),
+            tightening_error_status: Some(value.tightening_error_status.clone()),
+            tool_serial_number: Some(value.tool_serial_number.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MID0065rev4> for TighteningResult {
+    fn from(value: &MID0065rev4) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            parameter_set_id: Some(value.parameter_set_id),
+            parameter_set_name: Some(value.parameter_set_name.clone()),
+            strategy: Some(value.strategy),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            result_type: Some(value.result_type),
+            torque_unit: Some(value.torque_unit.into()),
+            torque: Some(value.torque),
+            angle: Some(value.angle),
+            rundown_angle: Some(value.rundown_angle),
+            current_monitoring_value: Some(value.current_monitoring_value),
+            self_tap_torque: Some(value.self_tap_torque),
+            prevail_torque: Some(value.prevail_torque),
+            tightening_error_status: Some(value.tightening_error_status.clone()),
+            tool_serial_number: Some(value.tool_serial_number.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MID0065rev5> for TighteningResult {
+    fn from(value: &MID0065rev5) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            parameter_set_id: Some(value.parameter_set_id),
+            parameter_set_name: Some(value.parameter_set_name.clone()),
+            strategy: Some(value.strategy),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            result_type: Some(value.result_type),
+            torque_unit: Some(value.torque_unit.into()),
+            torque: Some(value.torque),
+            angle: Some(value.angle),
+            rundown_angle: Some(value.rundown_angle),
+            current_monitoring_value: Some(value.current_monitoring_value),
+            self_tap_torque: Some(value.self_tap_torque),
+            prevail_torque: Some(value.prevail_torque),
+            tightening_error_status: Some(value.tightening_error_status.clone()),
+            tool_serial_number: Some(value.tool_serial_number.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MID0065rev6> for TighteningResult {
+    fn from(value: &MID0065rev6) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            parameter_set_id: Some(value.parameter_set_id),
+            parameter_set_name: Some(value.parameter_set_name.clone()),
+            strategy: Some(value.strategy),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            result_type: Some(value.result_type),
+            torque_unit: Some(value.torque_unit.into()),
+            torque: Some(value.torque),
+            angle: Some(value.angle),
+            rundown_angle: Some(value.rundown_angle),
+            current_monitoring_value: Some(value.current_monitoring_value),
+            self_tap_torque: Some(value.self_tap_torque),
+            prevail_torque: Some(value.prevail_torque),
+            tightening_error_status: Some(value.tightening_error_status.clone()),
+            tool_serial_number: Some(value.tool_serial_number.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MID0065rev7> for TighteningResult {
+    fn from(value: &MID0065rev7) -> Self {
+        Self {
+            tightening_id: value.tightening_id,
+            timestamp: value.timestamp,
+            vin_number: Some(value.vin_number.clone()),
+            parameter_set_id: Some(value.parameter_set_id),
+            parameter_set_name: Some(value.parameter_set_name.clone()),
+            strategy: Some(value.strategy),
+            batch_counter: Some(value.batch_counter),
+            batch_status: Some(value.batch_status),
+            tightening_status: Some(value.tightening_status),
+            torque_status: Some(value.torque_status),
+            angle_status: Some(value.angle_status),
+            result_type: Some(value.result_type),
+            torque_unit: Some(value.torque_unit.into()),
+            torque: Some(value.torque),
+            angle: Some(value.angle),
+            rundown_angle: Some(value.rundown_angle),
+            current_monitoring_value: Some(value.current_monitoring_value),
+            self_tap_torque: Some(value.self_tap_torque),
+            prevail_torque: Some(value.prevail_torque),
+            tightening_error_status: Some(value.tightening_error_status.clone()),
+            tool_serial_number: Some(value.tool_serial_number.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+/// All objects of an operation result OK means the operation is OK.
+fn operation_status(objects: &[OperationObject]) -> Option<OkNok> {
+    if objects.is_empty() {
+        return None;
+    }
+
+    Some(if objects.iter().all(|object| object.status.is_ok()) { OkNok::Ok } else { OkNok::Nok })
+}
+
+/// The overall (not step) data field of a PID.
+fn data_field(data_fields: &[DataField], parameter_id: u32) -> Option<&DataField> {
+    data_fields.iter().find(|field| field.parameter_id == parameter_id && field.step_no.is_none())
+}
+
+/// The value of the overall data field of a PID, if it is sent and can be parsed as `T`.
+fn data_value<T>(data_fields: &[DataField], parameter_id: u32) -> Option<T> where DataField: ParseDataField<T> {
+    data_field(data_fields, parameter_id)?.parse().ok()
+}
+
+/// The value of the overall data field of a PID, multiplied by 100 like the torques of MID 0061.
+fn hundredths(data_fields: &[DataField], parameter_id: u32) -> Option<u32> {
+    let value = data_value::<f64>(data_fields, parameter_id)? * 100.0;
+    (0.0..=u32::MAX as f64).contains(&value).then(|| value.round() as u32)
+}
+
+/// The value of the overall data field of a PID, rounded to whole degrees like the angles of
+/// MID 0061.
+fn degrees(data_fields: &[DataField], parameter_id: u32) -> Option<u16> {
+    let value = data_value::<f64>(data_fields, parameter_id)?;
+    (0.0..=u16::MAX as f64).contains(&value).then(|| value.round() as u16)
+}
+
+impl TighteningResult {
+    /// The result of the overall data fields of MID 1201, read from the PIDs in [`pid`].
+    fn from_data_fields(result_data_id: u32, timestamp: NaiveDateTime, data_fields: &[DataField]) -> Self {
+        Self {
+            tightening_id: result_data_id,
+            timestamp,
+            vin_number: data_value(data_fields, pid::VIN_NUMBER),
+            controller_name: data_value(data_fields, pid::CONTROLLER_NAME),
+            job_id: data_value(data_fields, pid::JOB_ID),
+            parameter_set_id: data_value(data_fields, pid::PARAMETER_SET_ID),
+            parameter_set_name: data_value(data_fields, pid::PARAMETER_SET_NAME),
+            strategy: data_value::<u16>(data_fields, pid::STRATEGY).and_then(|strategy| u8::try_from(strategy).ok()),
+            batch_size: data_value(data_fields, pid::BATCH_SIZE),
+            batch_counter: data_value(data_fields, pid::BATCH_COUNTER),
+            torque_unit: data_field(data_fields, pid::TORQUE).map(DataField::unit_kind),
+            torque: hundredths(data_fields, pid::TORQUE),
+            torque_min_limit: hundredths(data_fields, pid::TORQUE_MIN_LIMIT),
+            torque_max_limit: hundredths(data_fields, pid::TORQUE_MAX_LIMIT),
+            torque_final_target: hundredths(data_fields, pid::TORQUE_FINAL_TARGET),
+            angle: degrees(data_fields, pid::ANGLE),
+            angle_min_limit: degrees(data_fields, pid::ANGLE_MIN_LIMIT),
+            angle_max_limit: degrees(data_fields, pid::ANGLE_MAX_LIMIT),
+            angle_final_target: degrees(data_fields, pid::ANGLE_FINAL_TARGET),
+            rundown_angle: degrees(data_fields, pid::RUNDOWN_ANGLE),
+            current_monitoring_value: data_value(data_fields, pid::CURRENT_MONITORING_VALUE),
+            self_tap_torque: hundredths(data_fields, pid::SELF_TAP_TORQUE),
+            prevail_torque: hundredths(data_fields, pid::PREVAIL_TORQUE),
+            tool_serial_number: data_value(data_fields, pid::TOOL_SERIAL_NUMBER),
+            data_fields: data_fields.to_vec(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MID1201rev1> for TighteningResult {
+    fn from(value: &MID1201rev1) -> Self {
+        Self {
+            tightening_status: operation_status(&value.objects),
+            ..Self::from_data_fields(value.result_data_id, value.timestamp, &value.data_fields)
+        }
+    }
+}
+
+impl From<&MID1201rev2> for TighteningResult {
+    fn from(value: &MID1201rev2) -> Self {
+        Self {
+            tightening_status: operation_status(&value.objects),
+            result_type: Some(value.result_type),
+            ..Self::from_data_fields(value.result_data_id, value.timestamp, &value.data_fields)
+        }
+    }
+}
+
+impl From<&MID1201rev3> for TighteningResult {
+    fn from(value: &MID1201rev3) -> Self {
+        Self {
+            tightening_status: operation_status(&value.objects),
+            result_type: Some(value.result_type),
+            ..Self::from_data_fields(value.result_data_id, value.timestamp, &value.data_fields)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeDelta};
+    use crate::types::timestamp::Tz;
+
+    #[test]
+    fn from_mid0061rev1() {
+        let message = MID0061rev1 { tightening_id: 42, job_id: 3, torque: 1250, ..Default::default() };
+
+        let result = TighteningResult::from(&message);
+
+        assert_eq!(result.tightening_id, 42);
+        assert_eq!(result.job_id, Some(3));
+        assert_eq!(result.torque, Some(1250));
+        assert_eq!(result.parameter_set_name, None);
+        assert_eq!(result.result_type, None);
+    }
+
+    #[test]
+    fn timestamp_utc() {
+        let timestamp = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap().and_hms_opt(8, 30, 0).unwrap();
+        let result = TighteningResult { timestamp, ..Default::default() };
+        let timezone = ControllerTimezone::new(Tz::Europe__Amsterdam);
+
+        assert_eq!(result.timestamp_utc(&timezone).map(|utc| utc.naive_utc()), Ok(timestamp - TimeDelta::hours(1)));
+    }
+
+    #[test]
+    fn from_mid0065rev3() {
+        let message = MID0065rev3 { tightening_id: 7, result_type: ResultType::Loosening, ..Default::default() };
+
+        let result = TighteningResult::from_message(&Message::MID0065rev3(message)).unwrap();
+
+        assert_eq!(result.result_type, Some(ResultType::Loosening));
+        assert_eq!(result.torque_unit, Some(Unit::NewtonMetre));
+        assert_eq!(result.cell_id, None);
+    }
+
+    #[test]
+    fn from_mid1201rev2() {
+        let message = MID1201rev2 {
+            result_data_id: 9,
+            result_type: ResultType::Loosening,
+            number_of_objects: 2,
+            objects: vec![
+                OperationObject { object_id: 1, status: OkNok::Ok, ..Default::default() },
+                OperationObject { object_id: 2, status: OkNok::Nok, ..Default::default() },
+            ],
+            number_of_data_fields: 5,
+            data_fields: vec![
+                DataField::new(pid::TORQUE, 2, None, 12.5).unwrap(),
+                DataField::new(pid::TORQUE, 2, Some(1), 5.0).unwrap(),
+                DataField::new(pid::ANGLE, 50, None, 180.6).unwrap(),
+                DataField::new(pid::PARAMETER_SET_ID, 0, None, 3u64).unwrap(),
+                DataField::new(pid::VIN_NUMBER, 0, None, "VIN123".to_string()).unwrap(),
+            ],
+            ..Default::default()
+        };
+
+        let result = TighteningResult::from(&message);
+
+        assert_eq!(result.tightening_id, 9);
+        assert_eq!(result.result_type, Some(ResultType::Loosening));
+        assert_eq!(result.tightening_status, Some(OkNok::Nok));
+        assert_eq!(result.torque, Some(1250));
+        assert_eq!(result.torque_unit, Some(Unit::FootPoundForce));
+        assert_eq!(result.angle, Some(181));
+        assert_eq!(result.parameter_set_id, Some(3));
+        assert_eq!(result.vin_number, Some("VIN123".into()));
+        assert_eq!(result.torque_final_target, None);
+        assert_eq!(result.data_fields.len(), 5);
+    }
+}
@@ -0,0 +1,140 @@
+use chrono::{DateTime, MappedLocalTime, NaiveDateTime, Offset, TimeDelta, TimeZone, Utc};
+use thiserror::Error;
+
+pub use chrono_tz::Tz;
+
+/// What to do with a controller time that occurs twice, when the clocks are turned back.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum AmbiguousTime {
+    /// Use the first occurrence, before the clocks are turned back.
+    #[default]
+    Earliest,
+    /// Use the second occurrence, after the clocks are turned back.
+    Latest,
+    Reject,
+}
+
+/// What to do with a controller time that does not occur, when the clocks are turned forward.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum NonexistentTime {
+    /// Use the offset from before the clocks were turned forward, which moves the time forward
+    /// by the length of the gap.
+    #[default]
+    ShiftForward,
+    Reject,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum TimestampError {
+    #[error("Timestamp {timestamp} is ambiguous, it is either {earliest} or {latest}")]
+    Ambiguous { timestamp: NaiveDateTime, earliest: DateTime<Utc>, latest: DateTime<Utc> },
+    #[error("Timestamp {0} does not exist in the controller timezone")]
+    Nonexistent(NaiveDateTime),
+}
+
+/// The timezone of the controller clock. Timestamps are sent without an offset, so they are
+/// decoded as [`NaiveDateTime`] and converted to UTC with the timezone of the connection.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ControllerTimezone {
+    pub tz: Tz,
+    pub ambiguous: AmbiguousTime,
+    pub nonexistent: NonexistentTime,
+}
+
+impl Default for ControllerTimezone {
+    fn default() -> Self {
+        Self::new(Tz::UTC)
+    }
+}
+
+impl ControllerTimezone {
+    pub fn new(tz: Tz) -> Self {
+        Self { tz, ambiguous: AmbiguousTime::default(), nonexistent: NonexistentTime::default() }
+    }
+
+    pub fn with_ambiguous(mut self, ambiguous: AmbiguousTime) -> Self {
+        self.ambiguous = ambiguous;
+        self
+    }
+
+    pub fn with_nonexistent(mut self, nonexistent: NonexistentTime) -> Self {
+        self.nonexistent = nonexistent;
+        self
+    }
+
+    /// Converts a timestamp received from the controller to UTC.
+    pub fn to_utc(&self, timestamp: NaiveDateTime) -> Result<DateTime<Utc>, TimestampError> {
+        match self.tz.from_local_datetime(&timestamp) {
+            MappedLocalTime::Single(time) => Ok(time.to_utc()),
+            MappedLocalTime::Ambiguous(earliest, latest) => match self.ambiguous {
+                AmbiguousTime::Earliest => Ok(earliest.to_utc()),
+                AmbiguousTime::Latest => Ok(latest.to_utc()),
+                AmbiguousTime::Reject => Err(TimestampError::Ambiguous {
+                    timestamp,
+                    earliest: earliest.to_utc(),
+                    latest: latest.to_utc(),
+                }),
+            },
+            MappedLocalTime::None => match self.nonexistent {
+                NonexistentTime::ShiftForward => {
+                    // Gaps are far shorter than a day, so a day earlier has the offset from before the gap.
+                    let offset = self.tz.offset_from_utc_datetime(&(timestamp - TimeDelta::days(1))).fix();
+                    Ok((timestamp - offset).and_utc())
+                }
+                NonexistentTime::Reject => Err(TimestampError::Nonexistent(timestamp)),
+            },
+        }
+    }
+
+    /// Converts a timestamp to the controller clock, to send it to the controller.
+    pub fn from_utc(&self, timestamp: DateTime<Utc>) -> NaiveDateTime {
+        timestamp.with_timezone(&self.tz).naive_local()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn timestamp(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap().and_hms_opt(hour, min, sec).unwrap()
+    }
+
+    #[test]
+    fn to_utc() {
+        let timezone = ControllerTimezone::new(Tz::Europe__Amsterdam);
+        let expected = timestamp(2024, 6, 1, 10, 0, 0).and_utc();
+
+        assert_eq!(timezone.to_utc(timestamp(2024, 6, 1, 12, 0, 0)), Ok(expected));
+        assert_eq!(timezone.from_utc(expected), timestamp(2024, 6, 1, 12, 0, 0));
+        assert_eq!(ControllerTimezone::default().to_utc(expected.naive_utc()), Ok(expected));
+    }
+
+    #[test]
+    fn ambiguous_time() {
+        let timezone = ControllerTimezone::new(Tz::Europe__Amsterdam);
+        let local = timestamp(2024, 10, 27, 2, 30, 0);
+        let earliest = timestamp(2024, 10, 27, 0, 30, 0).and_utc();
+        let latest = timestamp(2024, 10, 27, 1, 30, 0).and_utc();
+
+        assert_eq!(timezone.to_utc(local), Ok(earliest));
+        assert_eq!(timezone.with_ambiguous(AmbiguousTime::Latest).to_utc(local), Ok(latest));
+        assert_eq!(
+            timezone.with_ambiguous(AmbiguousTime::Reject).to_utc(local),
+            Err(TimestampError::Ambiguous { timestamp: local, earliest, latest })
+        );
+    }
+
+    #[test]
+    fn nonexistent_time() {
+        let timezone = ControllerTimezone::new(Tz::Europe__Amsterdam);
+        let local = timestamp(2024, 3, 31, 2, 30, 0);
+
+        assert_eq!(timezone.to_utc(local), Ok(timestamp(2024, 3, 31, 1, 30, 0).and_utc()));
+        assert_eq!(
+            timezone.with_nonexistent(NonexistentTime::Reject).to_utc(local),
+            Err(TimestampError::Nonexistent(local))
+        );
+    }
+}
@@ -1,6 +1,12 @@
 use std::ops::Deref;
+use std::time::Duration;
 use open_protocol_codec::decode::{self, Decode, Decoder};
 use open_protocol_codec::encode::{self, Encode, Encoder};
+use crate::enums::datatype::DataType;
+use crate::enums::unit::Unit;
+use crate::messages::tightening_result::{MID0900rev1, MID0900rev2, MID0900rev3, TraceType};
+use crate::types::datafield::{DataField, ParseDataField};
+use crate::types::pid;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TraceSample(u16);
@@ -63,3 +69,302 @@ impl Deref for TraceSample {
         &self.0
     }
 }
+
+/// The time interval between trace samples, for the samples with an index between `first_index`
+/// and `last_index` (both inclusive, starting at 1). A trace can have multiple resolution fields
+/// when the sample rate changes during the tightening.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolutionField {
+    /// First index. 5 bytes, UI.
+    pub first_index: u32,
+    /// Last index. 5 bytes, UI.
+    pub last_index: u32,
+    /// Length. 3 bytes, UI. Length of the time value.
+    pub length: u16,
+    /// Data Type. 2 bytes, UI. Data type of the time value.
+    pub data_type: DataType,
+    /// Unit. 3 bytes, UI. Unit of the time value.
+    pub unit: u16,
+    /// The time between two samples.
+    pub time_value: String,
+}
+
+impl ResolutionField {
+    /// The time between two samples in this segment, if the unit is a known time unit.
+    pub fn interval(&self) -> Option<Duration> {
+        let seconds_per_unit = match Unit::from(self.unit) {
+            Unit::Second => 1.0,
+            Unit::Millisecond => 0.001,
+            Unit::Minute => 60.0,
+            _ => return None,
+        };
+
+        let value: f64 = self.time_value.trim().parse().ok()?;
+        Duration::try_from_secs_f64(value * seconds_per_unit).ok()
+    }
+
+    fn contains(&self, index: u32) -> bool {
+        (self.first_index..=self.last_index).contains(&index)
+    }
+}
+
+impl Encode for ResolutionField {
+    fn encode(&self, encoder: &mut Encoder) -> encode::Result<()> {
+        self.first_index.encode_sized(encoder, 5)?;
+        self.last_index.encode_sized(encoder, 5)?;
+        self.length.encode_sized(encoder, 3)?;
+        self.data_type.encode_sized(encoder, 2)?;
+        self.unit.encode_sized(encoder, 3)?;
+        self.time_value.encode_sized(encoder, self.length as usize)?;
+        Ok(())
+    }
+}
+
+impl Decode for ResolutionField {
+    fn decode(decoder: &mut Decoder) -> decode::Result<Self> {
+        let first_index = u32::decode_sized(decoder, 5)?;
+        let last_index = u32::decode_sized(decoder, 5)?;
+        let length = u16::decode_sized(decoder, 3)?;
+        let data_type = DataType::decode_sized(decoder, 2)?;
+        let unit = u16::decode_sized(decoder, 3)?;
+        let time_value = String::decode_sized(decoder, length as usize)?;
+
+        Ok(Self { first_index, last_index, length, data_type, unit, time_value })
+    }
+}
+
+/// A trace curve of a tightening, converted to physical values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trace {
+    /// The result data ID of the tightening the trace belongs to.
+    pub result_data_id: u32,
+    /// What the trace measures.
+    pub trace_type: TraceType,
+    /// The unit of the values of the trace.
+    pub unit: u16,
+    /// The samples, as the time since the first sample and the physical value.
+    pub samples: Vec<(Duration, f64)>,
+}
+
+impl Trace {
+    /// Converts raw samples to physical values. Each sample is a signed value multiplied by the
+    /// coefficient of the trace type, see [`pid::trace_coefficient`]. The time between samples
+    /// comes from the resolution field covering the sample, every sample must be covered by one.
+    fn new(
+        result_data_id: u32,
+        trace_type: TraceType,
+        unit: u16,
+        parameter_fields: &[DataField],
+        resolution_fields: &[ResolutionField],
+        trace_samples: &[TraceSample],
+    ) -> decode::Result<Self> {
+        let coefficient_pid = pid::trace_coefficient(trace_type)
+            .ok_or_else(|| decode::Error::MissingField(format!("coefficient of the {:?} trace", trace_type)))?;
+        let coefficient = parameter_fields.iter()
+            .find(|field| field.parameter_id == coefficient_pid)
+            .ok_or_else(|| decode::Error::MissingField(format!("coefficient PID {:05}", coefficient_pid)))
+            .and_then(ParseDataField::<f64>::parse)?;
+
+        let mut time = Duration::ZERO;
+        let samples = trace_samples.iter()
+            .enumerate()
+            .map(|(index, sample)| {
+                let index = index as u32 + 1;
+                let interval = resolution_fields.iter()
+                    .find(|field| field.contains(index))
+                    .and_then(ResolutionField::interval)
+                    .ok_or_else(|| decode::Error::MissingField(format!("time resolution of trace sample {}", index)))?;
+
+                if index > 1 {
+                    time += interval;
+                }
+
+                Ok((time, (sample.number() as i16) as f64 * coefficient))
+            })
+            .collect::<decode::Result<_>>()?;
+
+        Ok(Self { result_data_id, trace_type, unit, samples })
+    }
+
+    /// The duration of the trace, from the first to the last sample.
+    pub fn duration(&self) -> Duration {
+        self.samples.last().map(|(time, _)| *time).unwrap_or_default()
+    }
+
+    /// The physical values, without their time.
+    pub fn values(&self) -> impl Iterator<Item = f64> + '_ {
+        self.samples.iter().map(|(_, value)| *value)
+    }
+}
+
+impl TryFrom<&MID0900rev1> for Trace {
+    type Error = decode::Error;
+
+    fn try_from(value: &MID0900rev1) -> decode::Result<Self> {
+        Self::new(value.result_data_id, value.trace_type, value.unit, &value.parameter_fields, &value.resolution_fields, &value.trace_samples)
+    }
+}
+
+impl TryFrom<&MID0900rev2> for Trace {
+    type Error = decode::Error;
+
+    fn try_from(value: &MID0900rev2) -> decode::Result<Self> {
+        Self::new(value.result_data_id, value.trace_type, value.unit, &value.parameter_fields, &value.resolution_fields, &value.trace_samples)
+    }
+}
+
+impl TryFrom<&MID0900rev3> for Trace {
+    type Error = decode::Error;
+
+    fn try_from(value: &MID0900rev3) -> decode::Result<Self> {
+        Self::new(value.result_data_id, value.trace_type, value.unit, &value.parameter_fields, &value.resolution_fields, &value.trace_samples)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use open_protocol_codec::decode;
+
+    fn resolution(first_index: u32, last_index: u32, time_value: &str) -> ResolutionField {
+        ResolutionField {
+            first_index,
+            last_index,
+            length: time_value.len() as u16,
+            data_type: DataType::Float,
+            unit: 201,
+            time_value: time_value.into(),
+        }
+    }
+
+    #[test]
+    fn decode_resolution_field() {
+        let decoded = decode::decode::<ResolutionField>("0000100100005032010.500".as_bytes());
+
+        assert_eq!(decoded, Ok(resolution(1, 100, "0.500")));
+        assert_eq!(decoded.unwrap().interval(), Some(Duration::from_micros(500)));
+    }
+
+    fn coefficient(parameter_id: u32, value: &str) -> DataField {
+        DataField {
+            parameter_id,
+            length: value.len() as u16,
+            data_type: DataType::Float,
+            unit: 1,
+            step_no: None,
+            data_value: value.into(),
+        }
+    }
+
+    #[test]
+    fn trace_from_mid0900rev1() {
+        let message = MID0900rev1 {
+            result_data_id: 42,
+            trace_type: TraceType::Torque,
+            unit: 1,
+            number_of_parameter_fields: 2,
+            parameter_fields: vec![coefficient(2214, "0.5"), coefficient(2213, "0.01")],
+            number_of_resolution_fields: 2,
+            resolution_fields: vec![resolution(1, 2, "1"), resolution(3, 4, "2")],
+            number_of_trace_samples: 4,
+            trace_samples: vec![100.into(), 250.into(), 0xFF9C.into(), 1000.into()],
+            ..Default::default()
+        };
+
+        let trace = Trace::try_from(&message).unwrap();
+
+        assert_eq!(trace.trace_type, TraceType::Torque);
+        assert_eq!(trace.samples, vec![
+            (Duration::ZERO, 1.0),
+            (Duration::from_millis(1), 2.5),
+            (Duration::from_millis(3), -1.0),
+            (Duration::from_millis(5), 10.0),
+        ]);
+        assert_eq!(trace.duration(), Duration::from_millis(5));
+    }
+
+    /// Converts the samples 10 and 20 of a trace that carries a different coefficient for
+    /// every trace type.
+    fn scaled_values(trace_type: TraceType) -> decode::Result<Vec<f64>> {
+        let message = MID0900rev1 {
+            trace_type,
+            number_of_parameter_fields: 6,
+            parameter_fields: vec![
+                coefficient(2213, "1"),
+                coefficient(2214, "2"),
+                coefficient(2215, "3"),
+                coefficient(2216, "4"),
+                coefficient(2217, "5"),
+                coefficient(2218, "6"),
+            ],
+            number_of_resolution_fields: 1,
+            resolution_fields: vec![resolution(1, 2, "1")],
+            number_of_trace_samples: 2,
+            trace_samples: vec![10.into(), 20.into()],
+            ..Default::default()
+        };
+
+        Trace::try_from(&message).map(|trace| trace.values().collect())
+    }
+
+    #[test]
+    fn torque_trace_uses_torque_coefficient() {
+        assert_eq!(scaled_values(TraceType::Torque), Ok(vec![10.0, 20.0]));
+    }
+
+    #[test]
+    fn angle_trace_uses_angle_coefficient() {
+        assert_eq!(scaled_values(TraceType::Angle), Ok(vec![20.0, 40.0]));
+    }
+
+    #[test]
+    fn current_trace_uses_current_coefficient() {
+        assert_eq!(scaled_values(TraceType::Current), Ok(vec![30.0, 60.0]));
+    }
+
+    #[test]
+    fn gradient_trace_uses_gradient_coefficient() {
+        assert_eq!(scaled_values(TraceType::Gradient), Ok(vec![40.0, 80.0]));
+    }
+
+    #[test]
+    fn stroke_trace_uses_stroke_coefficient() {
+        assert_eq!(scaled_values(TraceType::Stroke), Ok(vec![50.0, 100.0]));
+    }
+
+    #[test]
+    fn force_trace_uses_force_coefficient() {
+        assert_eq!(scaled_values(TraceType::Force), Ok(vec![60.0, 120.0]));
+    }
+
+    #[test]
+    fn missing_coefficient() {
+        let message = MID0900rev1 {
+            trace_type: TraceType::Angle,
+            number_of_parameter_fields: 1,
+            parameter_fields: vec![coefficient(2213, "0.01")],
+            ..Default::default()
+        };
+
+        assert_eq!(Trace::try_from(&message), Err(decode::Error::MissingField("coefficient PID 02214".into())));
+    }
+
+    #[test]
+    fn sample_without_resolution() {
+        let message = MID0900rev1 {
+            trace_type: TraceType::Torque,
+            number_of_parameter_fields: 1,
+            parameter_fields: vec![coefficient(2213, "0.01")],
+            number_of_resolution_fields: 1,
+            resolution_fields: vec![resolution(2, 3, "1")],
+            number_of_trace_samples: 3,
+            trace_samples: vec![10.into(), 20.into(), 30.into()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Trace::try_from(&message),
+            Err(decode::Error::MissingField("time resolution of trace sample 1".into()))
+        );
+    }
+}